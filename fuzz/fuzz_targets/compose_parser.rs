@@ -0,0 +1,14 @@
+#![no_main]
+
+use docker_review::analyzer::Analyzer;
+use docker_review::parser::ComposeParser;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(content) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    let _ = ComposeParser::parse_content(content);
+    let _ = Analyzer::new().analyze_compose_str(content);
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+use docker_review::analyzer::Analyzer;
+use docker_review::parser::DockerfileParser;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(content) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    let parser = DockerfileParser::parse_content(content);
+    for instruction in &parser.instructions {
+        instruction.line_at(instruction.arguments.len());
+    }
+
+    // Rules slice and regex-match instruction arguments, so run them too
+    let _ = Analyzer::new().analyze_dockerfile_str(content);
+});
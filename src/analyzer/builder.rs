@@ -0,0 +1,160 @@
+use super::{default_compose_rules, default_dockerfile_rules, Analyzer};
+use crate::config::Config;
+use crate::rules::compose::PortBindingRule;
+use crate::rules::dockerfile::LongRunChainRule;
+use crate::rules::{Rule, Severity};
+use crate::scoring::{ScoringConfig, ScoringWeights};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::AtomicUsize;
+
+/// Configures an `Analyzer` programmatically
+#[derive(Debug, Clone, Default)]
+pub struct AnalyzerBuilder {
+    disabled_rules: HashSet<String>,
+    only_rules: Option<HashSet<String>>,
+    severity_overrides: HashMap<String, Severity>,
+    scoring: ScoringConfig,
+    message_templates: HashMap<String, String>,
+    profiles: Option<Vec<String>>,
+    max_run_steps: Option<usize>,
+    sensitive_ports: Option<Vec<u16>>,
+}
+
+impl AnalyzerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skip a rule entirely (e.g., "DF009")
+    pub fn disable_rule(mut self, rule_id: &str) -> Self {
+        self.disabled_rules.insert(rule_id.to_uppercase());
+        self
+    }
+
+    /// Skip several rules at once
+    pub fn disable_rules<I, S>(mut self, rule_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for id in rule_ids {
+            self.disabled_rules.insert(id.as_ref().to_uppercase());
+        }
+        self
+    }
+
+    /// Run only these rules; all others are skipped
+    pub fn only_rules<I, S>(mut self, rule_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.only_rules = Some(rule_ids.into_iter().map(|id| id.as_ref().to_uppercase()).collect());
+        self
+    }
+
+    /// Report a rule's issues at a different severity
+    pub fn severity_override(mut self, rule_id: &str, severity: Severity) -> Self {
+        self.severity_overrides.insert(rule_id.to_uppercase(), severity);
+        self
+    }
+
+    /// Weights used to combine category scores into the overall score
+    pub fn scoring_weights(mut self, weights: ScoringWeights) -> Self {
+        self.scoring.weights = weights;
+        self
+    }
+
+    /// Category weights and per-severity penalties used for scores
+    pub fn scoring_config(mut self, config: ScoringConfig) -> Self {
+        self.scoring = config;
+        self
+    }
+
+    /// Replace a rule's message with a template such as "Image {image} has no tag".
+    /// Placeholders are filled from the issue's params; `{message}` is the built-in text.
+    pub fn message_template(mut self, rule_id: &str, template: impl Into<String>) -> Self {
+        self.message_templates.insert(rule_id.to_uppercase(), template.into());
+        self
+    }
+
+    /// Set several message templates at once, keyed by rule id
+    pub fn message_templates<I, K, V>(mut self, templates: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        for (rule_id, template) in templates {
+            self.message_templates.insert(rule_id.as_ref().to_uppercase(), template.into());
+        }
+        self
+    }
+
+    /// Only analyze compose services in these profiles, plus services without profiles
+    pub fn profiles<I, S>(mut self, profiles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.profiles = Some(profiles.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Commands a RUN may chain with `&&` before DF040 reports it
+    pub fn max_run_steps(mut self, max_steps: usize) -> Self {
+        self.max_run_steps = Some(max_steps);
+        self
+    }
+
+    /// Container ports DC009 reports when published on all interfaces, replacing the defaults
+    pub fn sensitive_ports<I: IntoIterator<Item = u16>>(mut self, ports: I) -> Self {
+        self.sensitive_ports = Some(ports.into_iter().collect());
+        self
+    }
+
+    /// Apply a project configuration's disabled rules, severity overrides and thresholds
+    pub fn config(mut self, config: &Config) -> Self {
+        self = self.disable_rules(&config.disabled_rules);
+        if let Some(max_steps) = config.max_run_steps {
+            self = self.max_run_steps(max_steps);
+        }
+        if let Some(ports) = &config.sensitive_ports {
+            self = self.sensitive_ports(ports.iter().copied());
+        }
+        for (rule_id, severity) in &config.severity_overrides {
+            self = self.severity_override(rule_id, *severity);
+        }
+        self
+    }
+
+    pub fn build(self) -> Analyzer {
+        let mut dockerfile_rules = default_dockerfile_rules();
+        if let Some(max_steps) = self.max_run_steps {
+            let long_run_chain = LongRunChainRule::new(max_steps);
+            if let Some(rule) = dockerfile_rules.iter_mut().find(|rule| rule.id() == long_run_chain.id()) {
+                *rule = Box::new(long_run_chain);
+            }
+        }
+
+        let mut compose_rules = default_compose_rules();
+        if let Some(ports) = self.sensitive_ports {
+            let port_binding = PortBindingRule::new(ports);
+            if let Some(rule) = compose_rules.iter_mut().find(|rule| rule.id() == port_binding.id()) {
+                *rule = Box::new(port_binding);
+            }
+        }
+
+        Analyzer {
+            dockerfile_rules,
+            compose_rules,
+            disabled_rules: self.disabled_rules,
+            only_rules: self.only_rules,
+            severity_overrides: self.severity_overrides,
+            scoring: self.scoring,
+            message_templates: self.message_templates,
+            profiles: self.profiles,
+            rules_evaluated: AtomicUsize::new(0),
+        }
+    }
+}
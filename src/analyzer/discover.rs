@@ -0,0 +1,137 @@
+//! Finds Dockerfiles and compose files under a directory for `--recursive`.
+
+use crate::parser::{is_excluded, parse_dockerignore};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Whether a file name looks like a Dockerfile (`Dockerfile`, `Dockerfile.prod`, `api.dockerfile`)
+pub fn is_dockerfile_name(file_name: &str) -> bool {
+    file_name == "Dockerfile"
+        || file_name.starts_with("Dockerfile.")
+        || file_name.to_ascii_lowercase().ends_with("dockerfile")
+}
+
+/// Whether a file name looks like a compose file (`docker-compose.yml`, `compose.prod.yaml`)
+pub fn is_compose_name(file_name: &str) -> bool {
+    file_name.contains("compose") && (file_name.ends_with(".yml") || file_name.ends_with(".yaml"))
+}
+
+/// Every Dockerfile and compose file below `root`, sorted by path.
+///
+/// Hidden directories (such as `.git`) are skipped, paths matched by the root
+/// `.gitignore` are left out, and each directory is visited once even when
+/// symlinks point back up the tree.
+pub fn discover_files(root: &Path) -> Vec<PathBuf> {
+    let ignore_patterns = gitignore_patterns(root);
+    let mut visited = HashSet::new();
+    let mut found = Vec::new();
+    walk(root, root, &ignore_patterns, &mut visited, &mut found);
+    found.sort();
+    found
+}
+
+fn walk(root: &Path, dir: &Path, ignore_patterns: &[String], visited: &mut HashSet<PathBuf>, found: &mut Vec<PathBuf>) {
+    // Canonical paths catch symlink loops
+    let Ok(canonical) = dir.canonicalize() else {
+        return;
+    };
+    if !visited.insert(canonical) {
+        return;
+    }
+
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut entries: Vec<PathBuf> = entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+    entries.sort();
+
+    for path in entries {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let relative = path.strip_prefix(root).unwrap_or(&path).to_string_lossy().replace('\\', "/");
+        if is_excluded(ignore_patterns, &relative) {
+            continue;
+        }
+
+        if path.is_dir() {
+            if !name.starts_with('.') {
+                walk(root, &path, ignore_patterns, visited, found);
+            }
+        } else if is_dockerfile_name(name) || is_compose_name(name) {
+            found.push(path);
+        }
+    }
+}
+
+/// Root `.gitignore` patterns rewritten into the `.dockerignore` syntax understood
+/// by `is_excluded`: unanchored patterns match at any depth.
+fn gitignore_patterns(root: &Path) -> Vec<String> {
+    let patterns = parse_dockerignore(root.join(".gitignore")).unwrap_or_default();
+    patterns
+        .into_iter()
+        .map(|pattern| {
+            let (negation, pattern) = match pattern.strip_prefix('!') {
+                Some(rest) => ("!", rest.to_string()),
+                None => ("", pattern),
+            };
+            let pattern = pattern.trim_end_matches('/');
+            let anchored = pattern.starts_with('/') || pattern.contains('/');
+            if anchored {
+                format!("{}{}", negation, pattern.trim_start_matches('/'))
+            } else {
+                format!("{}**/{}", negation, pattern)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn touch(root: &Path, relative: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "FROM alpine:3.18\n").unwrap();
+    }
+
+    #[test]
+    fn test_file_name_patterns() {
+        assert!(is_dockerfile_name("Dockerfile"));
+        assert!(is_dockerfile_name("Dockerfile.prod"));
+        assert!(is_dockerfile_name("api.dockerfile"));
+        assert!(!is_dockerfile_name("Dockerfile-notes.md"));
+        assert!(is_compose_name("docker-compose.override.yml"));
+        assert!(is_compose_name("compose.yaml"));
+        assert!(!is_compose_name("compose.json"));
+    }
+
+    #[test]
+    fn test_skips_hidden_and_gitignored_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        touch(root, "api/Dockerfile");
+        touch(root, ".git/Dockerfile");
+        touch(root, "vendor/lib/Dockerfile");
+        touch(root, "build/Dockerfile");
+        touch(root, "docs/README.md");
+        fs::write(root.join(".gitignore"), "vendor/\n/build\n").unwrap();
+
+        let found = discover_files(root);
+        assert_eq!(found, vec![root.join("api/Dockerfile")]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_loop_is_visited_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        touch(root, "app/Dockerfile");
+        std::os::unix::fs::symlink(root, root.join("app/loop")).unwrap();
+
+        let found = discover_files(root);
+        assert_eq!(found, vec![root.join("app/Dockerfile")]);
+    }
+}
@@ -0,0 +1,140 @@
+//! Extracts Dockerfiles written inline in CI YAML (GitHub Actions, GitLab CI),
+//! e.g. `cat <<EOF > Dockerfile` heredocs inside `run: |` blocks.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_yaml::Value;
+
+static HEREDOC_START: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"<<(-?)\s*(['"]?)([A-Za-z_][A-Za-z0-9_]*)['"]?"#).unwrap()
+});
+
+/// A Dockerfile found inside a YAML document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedDockerfile {
+    /// Path to the YAML value holding the Dockerfile (e.g. `jobs.build.steps[1].run`),
+    /// with `#n` appended when one value holds several
+    pub location: String,
+    pub content: String,
+}
+
+/// Find Dockerfile-looking blocks in every string value of a YAML document
+pub fn extract_embedded_dockerfiles(yaml: &str) -> Result<Vec<EmbeddedDockerfile>, serde_yaml::Error> {
+    let document: Value = serde_yaml::from_str(yaml)?;
+    let mut found = Vec::new();
+    walk(&document, String::new(), &mut found);
+    Ok(found)
+}
+
+fn walk(value: &Value, location: String, found: &mut Vec<EmbeddedDockerfile>) {
+    match value {
+        Value::Mapping(map) => {
+            for (key, child) in map {
+                let key = match key {
+                    Value::String(s) => s.clone(),
+                    other => serde_yaml::to_string(other).unwrap_or_default().trim().to_string(),
+                };
+                let child_location = if location.is_empty() { key } else { format!("{}.{}", location, key) };
+                walk(child, child_location, found);
+            }
+        }
+        Value::Sequence(items) => {
+            for (i, child) in items.iter().enumerate() {
+                walk(child, format!("{}[{}]", location, i), found);
+            }
+        }
+        Value::Tagged(tagged) => walk(&tagged.value, location, found),
+        Value::String(text) => {
+            let blocks = dockerfile_blocks(text);
+            let several = blocks.len() > 1;
+            for (i, content) in blocks.into_iter().enumerate() {
+                let location = if several { format!("{}#{}", location, i + 1) } else { location.clone() };
+                found.push(EmbeddedDockerfile { location, content });
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Heredoc bodies that look like Dockerfiles, or the whole text if it is one
+fn dockerfile_blocks(text: &str) -> Vec<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut blocks = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let Some(captures) = HEREDOC_START.captures(lines[i]) else {
+            i += 1;
+            continue;
+        };
+        let strip_tabs = &captures[1] == "-";
+        let terminator = &captures[3];
+
+        let mut body = Vec::new();
+        i += 1;
+        while i < lines.len() && lines[i].trim() != terminator {
+            body.push(if strip_tabs { lines[i].trim_start_matches('\t') } else { lines[i] });
+            i += 1;
+        }
+        i += 1;
+
+        let body = body.join("\n");
+        if looks_like_dockerfile(&body) {
+            blocks.push(body);
+        }
+    }
+
+    if blocks.is_empty() && looks_like_dockerfile(text) {
+        blocks.push(text.to_string());
+    }
+    blocks
+}
+
+/// The first instruction (after comments and ARGs) is FROM
+fn looks_like_dockerfile(text: &str) -> bool {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .find(|line| !line.to_uppercase().starts_with("ARG "))
+        .is_some_and(|line| line.to_uppercase().starts_with("FROM "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extracts_heredoc_from_run_block() {
+        let workflow = r#"
+jobs:
+  build:
+    steps:
+      - uses: actions/checkout@v4
+      - run: |
+          cat <<'EOF' > Dockerfile
+          FROM node:latest
+          CMD ["node", "app.js"]
+          EOF
+          docker build .
+"#;
+        let found = extract_embedded_dockerfiles(workflow).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].location, "jobs.build.steps[1].run");
+        assert_eq!(found[0].content, "FROM node:latest\nCMD [\"node\", \"app.js\"]");
+    }
+
+    #[test]
+    fn test_ignores_scripts_and_other_heredocs() {
+        let workflow = r#"
+build:
+  script:
+    - |
+      cat <<EOF > config.ini
+      [server]
+      port = 8080
+      EOF
+    - echo "FROM here on we deploy"
+"#;
+        assert!(extract_embedded_dockerfiles(workflow).unwrap().is_empty());
+    }
+}
@@ -1,12 +1,24 @@
-use crate::parser::{DockerfileParser, ComposeParser};
+use crate::parser::{DockerfileParser, ComposeParser, ComposeFile, ComposeParseError, LimitError, ParseLimits};
 use crate::rules::{Issue, Severity};
 use crate::rules::dockerfile::*;
 use crate::rules::compose::*;
-use crate::scoring::{Scores, calculate_scores};
+use crate::scoring::{Scores, ScoringConfig, calculate_scores_with_config};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use thiserror::Error;
 
+mod builder;
+mod discover;
+mod embedded;
+mod stats;
+
+pub use builder::AnalyzerBuilder;
+pub use discover::{discover_files, is_compose_name, is_dockerfile_name};
+pub use embedded::{extract_embedded_dockerfiles, EmbeddedDockerfile};
+pub use stats::{ScanStats, SeverityCounts};
+
 #[derive(Error, Debug)]
 pub enum AnalyzerError {
     #[error("File not found: {0}")]
@@ -14,23 +26,279 @@ pub enum AnalyzerError {
     #[error("Failed to read file: {0}")]
     IoError(#[from] std::io::Error),
     #[error("Failed to parse compose file: {0}")]
-    ComposeParseError(String),
+    ComposeParseError(#[from] ComposeParseError),
     #[error("Unknown file type: {0}")]
     UnknownFileType(String),
+    #[error("Input rejected: {0}")]
+    LimitExceeded(#[from] LimitError),
+    #[error("Failed to parse YAML file: {0}")]
+    YamlParseError(String),
+    #[error("Invalid message templates: {0}")]
+    TemplateError(String),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// The result of analyzing one file. Serialized reports can be reloaded with
+/// serde; fields may be added in minor releases, so construct it with `new`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Report {
     pub file_path: String,
     pub issues: Vec<Issue>,
     pub scores: Scores,
+    /// Issues hidden by a baseline, kept so SARIF output can mark them suppressed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppressed: Vec<Issue>,
 }
 
-pub struct Analyzer;
+impl Report {
+    pub fn new(file_path: impl Into<String>, issues: Vec<Issue>, scores: Scores) -> Self {
+        Self {
+            file_path: file_path.into(),
+            issues,
+            scores,
+            suppressed: Vec::new(),
+        }
+    }
+
+    /// Issues grouped by rule id, each group in report order
+    pub fn issues_by_rule(&self) -> HashMap<&str, Vec<&Issue>> {
+        let mut groups: HashMap<&str, Vec<&Issue>> = HashMap::new();
+        for issue in &self.issues {
+            groups.entry(issue.rule_id.as_str()).or_default().push(issue);
+        }
+        groups
+    }
+
+    /// Issues grouped by severity; severities without issues are absent
+    pub fn issues_by_severity(&self) -> HashMap<Severity, Vec<&Issue>> {
+        let mut groups: HashMap<Severity, Vec<&Issue>> = HashMap::new();
+        for issue in &self.issues {
+            groups.entry(issue.severity).or_default().push(issue);
+        }
+        groups
+    }
+
+    /// Number of issues at `severity` or above
+    pub fn count_at_least(&self, severity: Severity) -> usize {
+        self.issues.iter().filter(|issue| issue.severity >= severity).count()
+    }
+
+    /// Sort issues by severity, rule, line and message so output is byte-stable
+    /// across runs (compose services are otherwise visited in hash order).
+    pub fn sort_deterministic(&mut self) {
+        for issues in [&mut self.issues, &mut self.suppressed] {
+            issues.sort_by(|a, b| {
+                b.severity.cmp(&a.severity)
+                    .then_with(|| a.rule_id.cmp(&b.rule_id))
+                    .then_with(|| a.line_number.cmp(&b.line_number))
+                    .then_with(|| a.message.cmp(&b.message))
+            });
+        }
+    }
+
+    /// Rewrite `file_path` relative to `base` (with `/` separators) so reports
+    /// from different checkouts of the same project are comparable
+    pub fn relativize(&mut self, base: &Path) {
+        if let Some(relative) = relative_path(Path::new(&self.file_path), base) {
+            self.file_path = relative;
+        }
+    }
+}
+
+/// `path` relative to `base`, with `/` separators. `None` when they share no
+/// root (e.g. different drives).
+pub fn relative_path(path: &Path, base: &Path) -> Option<String> {
+    let file = absolute(path);
+    let base = absolute(base);
+
+    let file_parts: Vec<Component> = file.components().collect();
+    let base_parts: Vec<Component> = base.components().collect();
+    let common = file_parts.iter().zip(&base_parts).take_while(|(a, b)| a == b).count();
+    if common == 0 {
+        return None;
+    }
+
+    let mut parts: Vec<String> = vec!["..".to_string(); base_parts.len() - common];
+    parts.extend(file_parts[common..].iter().map(|c| c.as_os_str().to_string_lossy().into_owned()));
+    Some(if parts.is_empty() { ".".to_string() } else { parts.join("/") })
+}
+
+/// `path` made absolute against the current directory, with `.` and `..` resolved
+/// lexically (the file may not exist, e.g. `workflow.yml:jobs.build`)
+fn absolute(path: &Path) -> PathBuf {
+    let joined = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir().unwrap_or_default().join(path)
+    };
+    let mut normalized = PathBuf::new();
+    for component in joined.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Dockerfile rules run by default, in reporting order
+pub fn default_dockerfile_rules() -> Vec<Box<dyn DockerfileRule>> {
+    vec![
+        Box::new(LatestTagRule),
+        Box::new(RootUserRule),
+        Box::new(NoDockerignoreRule),
+        Box::new(LayerOrderRule),
+        Box::new(HealthcheckRule),
+        Box::new(SecretsInEnvRule),
+        Box::new(VersionPinningRule),
+        Box::new(MultistageRule),
+        Box::new(LargeBaseImageRule),
+        Box::new(CurlBashRule),
+        Box::new(LayerOptimizationRule),
+        Box::new(TempFilesRule),
+        Box::new(OciLabelsRule),
+        Box::new(MixedPackageManagersRule),
+        Box::new(DigestWithoutTagRule),
+        Box::new(AppVolumeRule),
+        Box::new(UnverifiedDownloadRule),
+        Box::new(ShellEntrypointCmdRule),
+        Box::new(ExposedPortMismatchRule),
+        Box::new(RuntimeInstallRule),
+        Box::new(BuildTimestampRule),
+        Box::new(HealthcheckLocalhostRule),
+        Box::new(GitDirectoryRule),
+        Box::new(CopyBeforeWorkdirRule),
+        Box::new(CacheBustRule),
+        Box::new(AddVsCopyRule),
+        Box::new(NonRootPrivilegedPortRule),
+        Box::new(AptUpgradeRule),
+        Box::new(WorkdirRule),
+        Box::new(DuplicateCmdRule),
+        Box::new(ShellFormRule),
+        Box::new(WorldWritableRule),
+        Box::new(SudoRule),
+        Box::new(PackageCacheRule),
+        Box::new(DependencyDirectoryRule),
+        Box::new(ExposePortRule),
+        Box::new(RecursiveCopyRule),
+        Box::new(LongRunChainRule::default()),
+        Box::new(BuildSecretArgRule),
+        Box::new(PrivilegedPortRule),
+        Box::new(GitInImageRule),
+    ]
+}
+
+/// Compose rules run by default, in reporting order
+pub fn default_compose_rules() -> Vec<Box<dyn ComposeRule>> {
+    vec![
+        Box::new(RestartPolicyRule),
+        Box::new(PrivilegedRule),
+        Box::new(ResourceLimitsRule),
+        Box::new(ComposeLatestTagRule),
+        Box::new(HardcodedSecretsRule),
+        Box::new(HostNetworkPortsRule),
+        Box::new(VolumesFromRule),
+        Box::new(AdminPortsRule),
+        Box::new(UnusedResourcesRule),
+        Box::new(UnpublishedEntrypointRule),
+        Box::new(ImplicitNetworkRule),
+        Box::new(ComposeHealthcheckLocalhostRule),
+        Box::new(DevEnvironmentRule),
+        Box::new(HostNamespacesRule),
+        Box::new(BuildContextEscapeRule),
+        Box::new(CapabilityConflictRule),
+        Box::new(PortSyntaxRule),
+        Box::new(DuplicateEnvironmentRule),
+        Box::new(DockerSockRule),
+        Box::new(HealthcheckDisabledRule),
+        Box::new(HostNetworkRule),
+        Box::new(DangerousCapabilitiesRule),
+        Box::new(UnbuiltImageRule),
+        Box::new(ComposeHealthcheckRule),
+        Box::new(PortBindingRule::default()),
+    ]
+}
+
+pub struct Analyzer {
+    dockerfile_rules: Vec<Box<dyn DockerfileRule>>,
+    compose_rules: Vec<Box<dyn ComposeRule>>,
+    disabled_rules: HashSet<String>,
+    /// When set, the only rules that run
+    only_rules: Option<HashSet<String>>,
+    severity_overrides: HashMap<String, Severity>,
+    scoring: ScoringConfig,
+    /// Custom messages keyed by rule id
+    message_templates: HashMap<String, String>,
+    /// Active compose profiles; `None` analyzes every service
+    profiles: Option<Vec<String>>,
+    /// Rule checks run so far, across all analyzed files
+    rules_evaluated: AtomicUsize,
+}
 
 impl Analyzer {
     pub fn new() -> Self {
-        Self
+        AnalyzerBuilder::new().build()
+    }
+
+    pub fn builder() -> AnalyzerBuilder {
+        AnalyzerBuilder::new()
+    }
+
+    /// An analyzer that runs only the given rules, with default settings
+    pub fn with_rules(dockerfile_rules: Vec<Box<dyn DockerfileRule>>, compose_rules: Vec<Box<dyn ComposeRule>>) -> Self {
+        let mut analyzer = Self::new();
+        analyzer.dockerfile_rules = dockerfile_rules;
+        analyzer.compose_rules = compose_rules;
+        analyzer
+    }
+
+    /// Run `rule` on Dockerfiles after the rules already registered
+    pub fn add_dockerfile_rule(mut self, rule: impl DockerfileRule + 'static) -> Self {
+        self.dockerfile_rules.push(Box::new(rule));
+        self
+    }
+
+    /// Run `rule` on compose files after the rules already registered
+    pub fn add_compose_rule(mut self, rule: impl ComposeRule + 'static) -> Self {
+        self.compose_rules.push(Box::new(rule));
+        self
+    }
+
+    fn is_enabled(&self, rule_id: &str) -> bool {
+        !self.disabled_rules.contains(rule_id)
+            && self.only_rules.as_ref().is_none_or(|only| only.contains(rule_id))
+    }
+
+    /// Number of rule checks run by this analyzer so far
+    pub fn rules_evaluated(&self) -> usize {
+        self.rules_evaluated.load(Ordering::Relaxed)
+    }
+
+    /// Score a set of issues with this analyzer's weights
+    pub fn score(&self, issues: &[Issue]) -> Scores {
+        calculate_scores_with_config(issues, &self.scoring)
+    }
+
+    /// Apply severity overrides and message templates, sort and score the collected issues
+    fn build_report(&self, path: &Path, mut issues: Vec<Issue>) -> Report {
+        for issue in &mut issues {
+            if let Some(severity) = self.severity_overrides.get(&issue.rule_id) {
+                issue.severity = *severity;
+            }
+            if let Some(template) = self.message_templates.get(&issue.rule_id) {
+                issue.apply_template(template);
+            }
+        }
+
+        // Sort by severity (critical first)
+        issues.sort_by_key(|issue| std::cmp::Reverse(issue.severity));
+
+        let scores = self.score(&issues);
+
+        Report::new(path.display().to_string(), issues, scores)
     }
 
     pub fn analyze<P: AsRef<Path>>(&self, path: P) -> Result<Report, AnalyzerError> {
@@ -44,21 +312,11 @@ impl Analyzer {
         if path.is_dir() {
             // Look for Dockerfile or docker-compose.yml in directory
             let dockerfile = path.join("Dockerfile");
-            let compose = path.join("docker-compose.yml");
-            let compose_alt = path.join("docker-compose.yaml");
-            let compose_short = path.join("compose.yml");
-            let compose_short_alt = path.join("compose.yaml");
 
             if dockerfile.exists() {
                 return self.analyze_dockerfile(&dockerfile, Some(path));
-            } else if compose.exists() {
+            } else if let Some(compose) = find_compose_file(path) {
                 return self.analyze_compose(&compose);
-            } else if compose_alt.exists() {
-                return self.analyze_compose(&compose_alt);
-            } else if compose_short.exists() {
-                return self.analyze_compose(&compose_short);
-            } else if compose_short_alt.exists() {
-                return self.analyze_compose(&compose_short_alt);
             } else {
                 return Err(AnalyzerError::FileNotFound(
                     "No Dockerfile or docker-compose.yml found in directory".to_string()
@@ -70,12 +328,13 @@ impl Analyzer {
             .and_then(|n| n.to_str())
             .unwrap_or("");
 
-        if file_name == "Dockerfile" || file_name.starts_with("Dockerfile.") || file_name.ends_with("_dockerfile") || file_name.ends_with("dockerfile") {
+        if is_dockerfile_name(file_name) {
             self.analyze_dockerfile(path, path.parent())
-        } else if file_name.contains("compose") && (file_name.ends_with(".yml") || file_name.ends_with(".yaml")) {
+        } else if is_compose_name(file_name) {
             self.analyze_compose(path)
         } else {
             // Try to detect from content
+            ParseLimits::default().check_file(path)?;
             let content = std::fs::read_to_string(path)?;
             if content.contains("FROM ") && (content.contains("RUN ") || content.contains("COPY ") || content.contains("CMD ")) {
                 self.analyze_dockerfile(path, path.parent())
@@ -87,66 +346,336 @@ impl Analyzer {
         }
     }
 
+    /// Analyze every Dockerfile and compose file under `root` (see `discover_files`).
+    /// A file that fails to analyze does not stop the others; its error takes its place.
+    pub fn analyze_recursive<P: AsRef<Path>>(&self, root: P) -> Result<Vec<Result<Report, AnalyzerError>>, AnalyzerError> {
+        let root = root.as_ref();
+        if !root.is_dir() {
+            return Err(AnalyzerError::FileNotFound(root.display().to_string()));
+        }
+        Ok(discover_files(root).iter().map(|path| self.analyze(path)).collect())
+    }
+
     fn analyze_dockerfile<P: AsRef<Path>>(&self, path: P, context_dir: Option<&Path>) -> Result<Report, AnalyzerError> {
         let path = path.as_ref();
         let parser = DockerfileParser::parse(path)?;
-        
+        Ok(self.check_dockerfile(path, &parser, context_dir))
+    }
+
+    /// Analyze Dockerfile content held in memory. There is no build context,
+    /// so context-dependent checks such as `.dockerignore` are skipped.
+    pub fn analyze_dockerfile_str(&self, content: &str) -> Result<Report, AnalyzerError> {
+        let parser = DockerfileParser::parse_content_with_limits(content, &ParseLimits::default())?;
+        Ok(self.check_dockerfile(Path::new("Dockerfile"), &parser, None))
+    }
+
+    /// Analyze every Dockerfile written inline in a CI YAML file (see
+    /// `extract_embedded_dockerfiles`). Each report's path is `<file>:<yaml path>`
+    /// and its line numbers are relative to the embedded block.
+    pub fn analyze_embedded<P: AsRef<Path>>(&self, path: P) -> Result<Vec<Report>, AnalyzerError> {
+        let path = path.as_ref();
+        if !path.is_file() {
+            return Err(AnalyzerError::FileNotFound(path.display().to_string()));
+        }
+        ParseLimits::default().check_file(path)?;
+        let content = std::fs::read_to_string(path)?;
+        let embedded = extract_embedded_dockerfiles(&content)
+            .map_err(|e| AnalyzerError::YamlParseError(e.to_string()))?;
+
+        let mut reports = Vec::new();
+        for dockerfile in embedded {
+            let parser = DockerfileParser::parse_content_with_limits(&dockerfile.content, &ParseLimits::default())?;
+            let location = PathBuf::from(format!("{}:{}", path.display(), dockerfile.location));
+            reports.push(self.check_dockerfile(&location, &parser, None));
+        }
+        Ok(reports)
+    }
+
+    fn check_dockerfile(&self, path: &Path, parser: &DockerfileParser, context_dir: Option<&Path>) -> Report {
         let mut issues = Vec::new();
-        
-        // Run all Dockerfile rules directly
-        issues.extend(LatestTagRule.check(&parser, context_dir));
-        issues.extend(RootUserRule.check(&parser, context_dir));
-        issues.extend(NoDockerignoreRule.check(&parser, context_dir));
-        issues.extend(LayerOrderRule.check(&parser, context_dir));
-        issues.extend(HealthcheckRule.check(&parser, context_dir));
-        issues.extend(SecretsInEnvRule.check(&parser, context_dir));
-        issues.extend(VersionPinningRule.check(&parser, context_dir));
-        issues.extend(MultistageRule.check(&parser, context_dir));
-        issues.extend(LargeBaseImageRule.check(&parser, context_dir));
-        issues.extend(CurlBashRule.check(&parser, context_dir));
-        issues.extend(LayerOptimizationRule.check(&parser, context_dir));
 
-        // Sort by severity (critical first)
-        issues.sort_by(|a, b| b.severity.cmp(&a.severity));
+        // Run all enabled Dockerfile rules
+        for rule in &self.dockerfile_rules {
+            if self.is_enabled(rule.id()) {
+                issues.extend(rule.check(parser, context_dir));
+                self.rules_evaluated.fetch_add(1, Ordering::Relaxed);
+            }
+        }
 
-        let scores = calculate_scores(&issues);
+        self.build_report(path, issues)
+    }
 
-        Ok(Report {
-            file_path: path.display().to_string(),
-            issues,
-            scores,
-        })
+    /// Parse the compose file at `path`, or the first compose file found in a directory
+    pub fn load_compose<P: AsRef<Path>>(&self, path: P) -> Result<ComposeFile, AnalyzerError> {
+        let path = path.as_ref();
+        let compose_path = if path.is_dir() {
+            find_compose_file(path).ok_or_else(|| {
+                AnalyzerError::FileNotFound("No docker-compose.yml found in directory".to_string())
+            })?
+        } else if path.exists() {
+            path.to_path_buf()
+        } else {
+            return Err(AnalyzerError::FileNotFound(path.display().to_string()));
+        };
+
+        Ok(self.active_services(ComposeParser::parse(&compose_path)?))
+    }
+
+    /// The compose file with services outside the active profiles removed
+    fn active_services(&self, compose: ComposeFile) -> ComposeFile {
+        match &self.profiles {
+            Some(profiles) => compose.with_profiles(profiles),
+            None => compose,
+        }
     }
 
     fn analyze_compose<P: AsRef<Path>>(&self, path: P) -> Result<Report, AnalyzerError> {
         let path = path.as_ref();
-        let compose = ComposeParser::parse(path)
-            .map_err(|e| AnalyzerError::ComposeParseError(e.to_string()))?;
-        
-        let mut issues = Vec::new();
-        
-        // Run all Compose rules directly
-        issues.extend(RestartPolicyRule.check(&compose));
-        issues.extend(PrivilegedRule.check(&compose));
-        issues.extend(ResourceLimitsRule.check(&compose));
-        issues.extend(ComposeLatestTagRule.check(&compose));
-        issues.extend(HardcodedSecretsRule.check(&compose));
+        let compose = self.active_services(ComposeParser::parse(path)?);
+        Ok(self.check_compose(path, &compose))
+    }
 
-        // Sort by severity (critical first)
-        issues.sort_by(|a, b| b.severity.cmp(&a.severity));
+    /// Analyze docker-compose content held in memory
+    pub fn analyze_compose_str(&self, content: &str) -> Result<Report, AnalyzerError> {
+        ParseLimits::default().check_content(content)?;
+        let compose = self.active_services(ComposeParser::parse_content(content)?);
+        Ok(self.check_compose(Path::new("docker-compose.yml"), &compose))
+    }
+
+    fn check_compose(&self, path: &Path, compose: &ComposeFile) -> Report {
+        let mut issues = Vec::new();
 
-        let scores = calculate_scores(&issues);
+        // Run all enabled Compose rules
+        for rule in &self.compose_rules {
+            if self.is_enabled(rule.id()) {
+                issues.extend(rule.check(compose));
+                self.rules_evaluated.fetch_add(1, Ordering::Relaxed);
+            }
+        }
 
-        Ok(Report {
-            file_path: path.display().to_string(),
-            issues,
-            scores,
-        })
+        self.build_report(path, issues)
     }
 }
 
+/// First compose file present in `dir`
+fn find_compose_file(dir: &Path) -> Option<PathBuf> {
+    ["docker-compose.yml", "docker-compose.yaml", "compose.yml", "compose.yaml"]
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|candidate| candidate.exists())
+}
+
+/// Load message templates from a YAML or JSON map of rule id to template
+pub fn load_message_templates<P: AsRef<Path>>(path: P) -> Result<HashMap<String, String>, AnalyzerError> {
+    let content = std::fs::read_to_string(path)?;
+    serde_yaml::from_str(&content).map_err(|e| AnalyzerError::TemplateError(e.to_string()))
+}
+
 impl Default for Analyzer {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+    }
+
+    fn mixed_report() -> Report {
+        Analyzer::new()
+            .analyze_dockerfile_str("FROM ubuntu\nENV PASSWORD=a\nENV API_KEY=b\nRUN apt-get install -y curl\n")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_issues_by_rule() {
+        let report = mixed_report();
+        let by_rule = report.issues_by_rule();
+        assert_eq!(by_rule["DF006"].len(), 2);
+        assert_eq!(by_rule["DF006"][0].line_number, Some(2));
+        assert_eq!(by_rule.values().map(Vec::len).sum::<usize>(), report.issues.len());
+    }
+
+    #[test]
+    fn test_issues_by_severity() {
+        let report = mixed_report();
+        let by_severity = report.issues_by_severity();
+        assert!(by_severity[&Severity::Critical].iter().any(|i| i.rule_id == "DF001"));
+        assert!(by_severity[&Severity::Warning].iter().all(|i| i.severity == Severity::Warning));
+        assert_eq!(by_severity.values().map(Vec::len).sum::<usize>(), report.issues.len());
+    }
+
+    #[test]
+    fn test_count_at_least() {
+        let report = mixed_report();
+        let count = |severity| report.issues.iter().filter(|i| i.severity == severity).count();
+        let critical = count(Severity::Critical);
+        assert!(critical > 0);
+        assert_eq!(report.count_at_least(Severity::Critical), critical);
+        assert_eq!(report.count_at_least(Severity::Warning), critical + count(Severity::Warning));
+        assert_eq!(report.count_at_least(Severity::Suggestion), report.issues.len());
+    }
+
+    #[test]
+    fn test_relativize_file_path() {
+        let mut report = Report::new("/work/repo/services/api/Dockerfile", vec![], calculate_scores_with_config(&[], &ScoringConfig::default()));
+        report.relativize(Path::new("/work/repo"));
+        assert_eq!(report.file_path, "services/api/Dockerfile");
+
+        report.file_path = "/work/repo/./ci.yml:jobs.build".to_string();
+        report.relativize(Path::new("/work/repo/services/../docs"));
+        assert_eq!(report.file_path, "../ci.yml:jobs.build");
+    }
+
+    #[test]
+    fn test_default_analyzer_runs_all_rules() {
+        let report = Analyzer::new().analyze(fixture("bad_dockerfile")).unwrap();
+        assert!(report.issues.iter().any(|i| i.rule_id == "DF009"));
+    }
+
+    #[test]
+    fn test_builder_disables_rule() {
+        let analyzer = Analyzer::builder().disable_rule("DF009").build();
+        let report = analyzer.analyze(fixture("bad_dockerfile")).unwrap();
+        assert!(!report.issues.is_empty());
+        assert!(!report.issues.iter().any(|i| i.rule_id == "DF009"));
+    }
+
+    #[test]
+    fn test_builder_overrides_severity() {
+        let analyzer = Analyzer::builder()
+            .severity_override("DF001", Severity::Suggestion)
+            .build();
+        let report = analyzer.analyze(fixture("bad_dockerfile")).unwrap();
+        let df001 = report.issues.iter().find(|i| i.rule_id == "DF001").unwrap();
+        assert_eq!(df001.severity, Severity::Suggestion);
+    }
+
+    #[test]
+    fn test_counts_rules_evaluated() {
+        let analyzer = Analyzer::builder().disable_rule("DF009").build();
+        analyzer.analyze(fixture("bad_dockerfile")).unwrap();
+        assert_eq!(analyzer.rules_evaluated(), default_dockerfile_rules().len() - 1);
+    }
+
+    #[test]
+    fn test_with_rules_runs_only_given_rules() {
+        let analyzer = Analyzer::with_rules(vec![Box::new(LatestTagRule)], Vec::new());
+        let report = analyzer.analyze(fixture("bad_dockerfile")).unwrap();
+        assert!(!report.issues.is_empty());
+        assert!(report.issues.iter().all(|i| i.rule_id == "DF001"));
+        assert_eq!(analyzer.rules_evaluated(), 1);
+
+        let analyzer = analyzer.add_dockerfile_rule(RootUserRule);
+        let report = analyzer.analyze(fixture("bad_dockerfile")).unwrap();
+        assert!(report.issues.iter().any(|i| i.rule_id == "DF002"));
+    }
+
+    #[test]
+    fn test_separate_cleanup_layer_lowers_performance() {
+        let content = "FROM debian:12.5\nRUN apt-get update && apt-get install -y curl\nRUN rm -rf /var/lib/apt/lists/*\n";
+        let report = Analyzer::new().analyze_dockerfile_str(content).unwrap();
+        let df011 = report.issues.iter().find(|i| i.rule_id == "DF011").unwrap();
+        assert_eq!(df011.line_number, Some(3));
+        let contributions = crate::scoring::category_contributions(&report.issues);
+        assert!(contributions[&crate::scoring::Category::Performance].contains(&"DF011".to_string()));
+    }
+
+    #[test]
+    fn test_only_rules_skip_the_rest() {
+        let analyzer = Analyzer::builder().only_rules(["df001"]).build();
+        let report = analyzer.analyze(fixture("bad_dockerfile")).unwrap();
+        assert!(!report.issues.is_empty());
+        assert!(report.issues.iter().all(|i| i.rule_id == "DF001"));
+        assert_eq!(analyzer.rules_evaluated(), 1);
+    }
+
+    #[test]
+    fn test_analyze_in_memory_content() {
+        let analyzer = Analyzer::new();
+        let report = analyzer.analyze_dockerfile_str("FROM ubuntu:latest\nRUN apt-get update\n").unwrap();
+        assert_eq!(report.file_path, "Dockerfile");
+        assert!(report.issues.iter().any(|i| i.rule_id == "DF001"));
+        assert!(!report.issues.iter().any(|i| i.rule_id == "DF003"));
+
+        let report = analyzer.analyze_compose_str("services:\n  web:\n    image: nginx:latest\n").unwrap();
+        assert!(report.issues.iter().any(|i| i.rule_id == "DC004"));
+
+        assert!(matches!(
+            analyzer.analyze_compose_str("services: [oops"),
+            Err(AnalyzerError::ComposeParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_message_template_overrides_df001() {
+        let analyzer = Analyzer::builder()
+            .message_template("DF001", "Das Image {image} verwendet das Tag 'latest'")
+            .build();
+        let report = analyzer.analyze_dockerfile_str("FROM ubuntu:latest\nUSER app\n").unwrap();
+        let df001 = report.issues.iter().find(|i| i.rule_id == "DF001").unwrap();
+        assert_eq!(df001.message, "Das Image ubuntu:latest verwendet das Tag 'latest'");
+        assert_eq!(df001.params.get("image").map(String::as_str), Some("ubuntu:latest"));
+
+        // Rules without a template keep their built-in message
+        let report = Analyzer::new().analyze_dockerfile_str("FROM ubuntu:latest\n").unwrap();
+        let df001 = report.issues.iter().find(|i| i.rule_id == "DF001").unwrap();
+        assert_eq!(df001.message, "Image 'ubuntu:latest' explicitly uses ':latest' tag");
+    }
+
+    #[test]
+    fn test_analyze_embedded_workflow() {
+        let path = fixture("embedded_workflow.yml");
+        let reports = Analyzer::new().analyze_embedded(&path).unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].file_path, format!("{}:jobs.image.steps[1].run", path.display()));
+        let df001 = reports[0].issues.iter().find(|i| i.rule_id == "DF001").unwrap();
+        assert_eq!(df001.line_number, Some(1));
+    }
+
+    #[test]
+    fn test_analyze_recursive_finds_nested_files() {
+        let reports = Analyzer::new().analyze_recursive(fixture("monorepo")).unwrap();
+        let mut names: Vec<String> = reports
+            .iter()
+            .map(|r| Path::new(&r.as_ref().unwrap().file_path).strip_prefix(fixture("monorepo")).unwrap().display().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["docker-compose.yml", "services/api/Dockerfile", "services/web/Dockerfile.prod"]);
+    }
+
+    #[test]
+    fn test_analyze_recursive_continues_past_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("api")).unwrap();
+        std::fs::write(dir.path().join("api/Dockerfile"), "FROM alpine:3.18\n").unwrap();
+        std::fs::write(dir.path().join("docker-compose.yml"), "services: [unclosed\n").unwrap();
+
+        let results = Analyzer::new().analyze_recursive(dir.path()).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+    }
+
+    #[test]
+    fn test_builder_scoring_weights() {
+        let weights = crate::scoring::ScoringWeights { security: 0, performance: 0, maintainability: 1 };
+        let analyzer = Analyzer::builder().scoring_weights(weights).build();
+        let report = analyzer.analyze(fixture("bad_dockerfile")).unwrap();
+        assert_eq!(report.scores.overall.current, report.scores.maintainability.current);
+    }
+
+    #[test]
+    fn test_builder_scoring_config() {
+        let content = "FROM ubuntu:latest\nUSER app\n";
+        let default = Analyzer::new().analyze_dockerfile_str(content).unwrap();
+        let lenient = ScoringConfig { critical_penalty: 0, warning_penalty: 0, suggestion_penalty: 0, ..ScoringConfig::default() };
+        let report = Analyzer::builder().scoring_config(lenient).build().analyze_dockerfile_str(content).unwrap();
+        assert!(default.scores.overall.current < 10);
+        assert_eq!(report.scores.overall.current, 10);
+        assert_eq!(report.scores.grade(), 'A');
+    }
+}
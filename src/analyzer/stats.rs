@@ -0,0 +1,41 @@
+use super::Report;
+use crate::rules::Severity;
+use serde::Serialize;
+use std::time::Duration;
+
+/// Issue totals per severity
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SeverityCounts {
+    pub critical: usize,
+    pub warning: usize,
+    pub suggestion: usize,
+}
+
+/// Summary of a whole scan, for observability (`--stats`)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ScanStats {
+    pub files_analyzed: usize,
+    pub issues: SeverityCounts,
+    pub rules_evaluated: usize,
+    pub elapsed_ms: u128,
+}
+
+impl ScanStats {
+    /// Count a reported file and its issues
+    pub fn record(&mut self, report: &Report) {
+        self.files_analyzed += 1;
+        for issue in &report.issues {
+            match issue.severity {
+                Severity::Critical => self.issues.critical += 1,
+                Severity::Warning => self.issues.warning += 1,
+                Severity::Suggestion => self.issues.suggestion += 1,
+            }
+        }
+    }
+
+    /// Fill in run-wide totals once scanning has finished
+    pub fn finish(&mut self, rules_evaluated: usize, elapsed: Duration) {
+        self.rules_evaluated = rules_evaluated;
+        self.elapsed_ms = elapsed.as_millis();
+    }
+}
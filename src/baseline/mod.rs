@@ -0,0 +1,331 @@
+//! Baselines record issues that already exist so later runs only report new ones.
+//!
+//! A baseline file is a JSON array of issues, written with `--write-baseline`
+//! or taken from the `issues` of a JSON report. Entries written by
+//! `--write-baseline` carry a fingerprint of the rule id and the text of the
+//! offending line, so they still match after lines are inserted above them.
+//! Other entries are matched by `Issue::fingerprint`. Entries that name their
+//! file only match issues in that file; paths are kept relative to the
+//! baseline file's directory (see `file_key`).
+//!
+//! A score baseline is a JSON object mapping file paths (keyed like issue
+//! baselines, by `file_key`) to their `Scores`,
+//! written with `--fail-on-score-drop <FILE> --write-baseline` and compared
+//! against on later `--fail-on-score-drop` runs.
+
+use crate::analyzer::relative_path;
+use crate::rules::Issue;
+use crate::scoring::{Category, Score, Scores};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum BaselineError {
+    #[error("Failed to read baseline: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Invalid baseline file: {0}")]
+    ParseError(#[from] serde_json::Error),
+}
+
+/// One recorded issue in a baseline file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// Absent in plain issue lists; `Issue::fingerprint` is used instead
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+    #[serde(flatten)]
+    pub issue: Issue,
+}
+
+/// Fingerprints of the issues accepted as pre-existing
+#[derive(Debug, Clone, Default)]
+pub struct Baseline {
+    /// From entries without a file path; these match in any file
+    fingerprints: HashSet<String>,
+    /// `(file, fingerprint)` pairs that only match in their own file
+    file_fingerprints: HashSet<(String, String)>,
+    entries: Vec<BaselineEntry>,
+}
+
+/// How an analyzed file is keyed in a baseline: its path relative to the
+/// baseline file's directory, so `Dockerfile`, `./Dockerfile` and the absolute
+/// path all match the same entry
+pub fn file_key(file_path: &str, baseline_path: &Path) -> String {
+    let base = baseline_path.parent().unwrap_or(Path::new(""));
+    relative_path(Path::new(file_path), base).unwrap_or_else(|| file_path.to_string())
+}
+
+/// Rule id plus the whitespace-normalized text of the issue's line. Falls back to
+/// `Issue::fingerprint` when the issue has no line or the source is unavailable.
+pub fn line_fingerprint(issue: &Issue, source: Option<&str>) -> String {
+    let line_text = issue
+        .line_number
+        .filter(|n| *n > 0)
+        .and_then(|n| source?.lines().nth(n - 1))
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty());
+    match line_text {
+        Some(text) => format!("{}@{}", issue.rule_id, text),
+        None => issue.fingerprint(),
+    }
+}
+
+impl Baseline {
+    pub fn from_issues(issues: &[Issue]) -> Self {
+        Self {
+            fingerprints: issues.iter().map(Issue::fingerprint).collect(),
+            ..Self::default()
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, BaselineError> {
+        let content = std::fs::read_to_string(path)?;
+        let entries: Vec<BaselineEntry> = serde_json::from_str(&content)?;
+        let mut baseline = Self::default();
+        for entry in entries {
+            let fingerprint = entry.fingerprint.unwrap_or_else(|| entry.issue.fingerprint());
+            match entry.file_path {
+                Some(file_path) => baseline.file_fingerprints.insert((file_path, fingerprint)),
+                None => baseline.fingerprints.insert(fingerprint),
+            };
+        }
+        Ok(baseline)
+    }
+
+    /// Add a file's issues, fingerprinted against its source text. `file_path` should
+    /// come from `file_key`.
+    pub fn record(&mut self, file_path: &str, issues: &[Issue], source: Option<&str>) {
+        for issue in issues {
+            let fingerprint = line_fingerprint(issue, source);
+            self.file_fingerprints.insert((file_path.to_string(), fingerprint.clone()));
+            self.entries.push(BaselineEntry {
+                fingerprint: Some(fingerprint),
+                file_path: Some(file_path.to_string()),
+                issue: issue.clone(),
+            });
+        }
+    }
+
+    /// Write the recorded entries as a baseline file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), BaselineError> {
+        let json = serde_json::to_string_pretty(&self.entries)?;
+        std::fs::write(path, format!("{}\n", json))?;
+        Ok(())
+    }
+
+    /// Whether an issue in `file_path` (as keyed by `file_key`) is in the baseline
+    pub fn contains(&self, file_path: &str, issue: &Issue, source: Option<&str>) -> bool {
+        [line_fingerprint(issue, source), issue.fingerprint()].into_iter().any(|fingerprint| {
+            self.fingerprints.contains(&fingerprint)
+                || self.file_fingerprints.contains(&(file_path.to_string(), fingerprint))
+        })
+    }
+
+    /// Issues that are not in the baseline; `source` is the analyzed file's text, when available
+    pub fn new_issues(&self, file_path: &str, issues: &[Issue], source: Option<&str>) -> Vec<Issue> {
+        self.partition(file_path, issues, source).0
+    }
+
+    /// Issues split into `(new, baselined)`
+    pub fn partition(&self, file_path: &str, issues: &[Issue], source: Option<&str>) -> (Vec<Issue>, Vec<Issue>) {
+        issues.iter().cloned().partition(|issue| !self.contains(file_path, issue, source))
+    }
+}
+
+/// Scores recorded per file path
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ScoreBaseline {
+    files: BTreeMap<String, Scores>,
+}
+
+/// A score that is lower than the one recorded in the score baseline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoreDrop {
+    /// `None` for the overall score
+    pub category: Option<Category>,
+    pub baseline: u8,
+    pub current: u8,
+}
+
+impl std::fmt::Display for ScoreDrop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.category {
+            Some(category) => write!(f, "{} score", category)?,
+            None => write!(f, "Overall score")?,
+        }
+        write!(f, " dropped from {}/10 to {}/10", self.baseline, self.current)
+    }
+}
+
+impl ScoreBaseline {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, BaselineError> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Record a file's scores, replacing any earlier ones. `file_path` should come
+    /// from `file_key`.
+    pub fn record(&mut self, file_path: &str, scores: &Scores) {
+        self.files.insert(file_path.to_string(), scores.clone());
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), BaselineError> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, format!("{}\n", json))?;
+        Ok(())
+    }
+
+    /// Category and overall scores that fell below the recorded ones, or `None`
+    /// when no scores were recorded for the file
+    pub fn drops(&self, file_path: &str, scores: &Scores) -> Option<Vec<ScoreDrop>> {
+        let recorded = self.files.get(file_path)?;
+        let pairs: [(Option<Category>, Score, Score); 4] = [
+            (Some(Category::Security), recorded.security, scores.security),
+            (Some(Category::Performance), recorded.performance, scores.performance),
+            (Some(Category::Maintainability), recorded.maintainability, scores.maintainability),
+            (None, recorded.overall, scores.overall),
+        ];
+        let drops = pairs
+            .into_iter()
+            .filter(|(_, before, now)| now.current < before.current)
+            .map(|(category, before, now)| ScoreDrop { category, baseline: before.current, current: now.current })
+            .collect();
+        Some(drops)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+
+    const LEGACY: &str = "FROM ubuntu:latest\nENV API_KEY=secret123\nRUN apt-get update\n";
+
+    #[test]
+    fn test_baselined_issues_are_filtered() {
+        let analyzer = Analyzer::new();
+        let legacy = analyzer.analyze_dockerfile_str(LEGACY).unwrap();
+        let baseline = Baseline::from_issues(&legacy.issues);
+
+        let report = analyzer.analyze_dockerfile_str(&format!("{}RUN curl https://example.com/install.sh | bash\n", LEGACY)).unwrap();
+        let new_issues = baseline.new_issues("Dockerfile", &report.issues, None);
+        assert!(!new_issues.is_empty());
+        assert!(new_issues.iter().all(|i| i.rule_id == "DF010"));
+    }
+
+    #[test]
+    fn test_score_new_only_improves_score() {
+        let analyzer = Analyzer::new();
+        let legacy = analyzer.analyze_dockerfile_str(LEGACY).unwrap();
+        let baseline = Baseline::from_issues(&legacy.issues);
+
+        let report = analyzer.analyze_dockerfile_str(&format!("{}RUN curl https://example.com/install.sh | bash\n", LEGACY)).unwrap();
+        let new_only = analyzer.score(&baseline.new_issues("Dockerfile", &report.issues, None));
+        assert!(new_only.overall.current > report.scores.overall.current);
+        assert!(new_only.security.current > report.scores.security.current);
+    }
+
+    #[test]
+    fn test_written_baseline_survives_shifted_lines() {
+        let analyzer = Analyzer::new();
+        let legacy = analyzer.analyze_dockerfile_str(LEGACY).unwrap();
+        let mut baseline = Baseline::default();
+        baseline.record("Dockerfile", &legacy.issues, Some(LEGACY));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        baseline.save(&path).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+
+        // Lines move down and a new issue appears above the old ones
+        let changed = format!("# syntax=docker/dockerfile:1\nARG VERSION=1\n{}RUN curl https://example.com/install.sh | bash\n", LEGACY);
+        let report = analyzer.analyze_dockerfile_str(&changed).unwrap();
+        let new_issues = baseline.new_issues("Dockerfile", &report.issues, Some(&changed));
+        assert!(!new_issues.is_empty());
+        assert!(new_issues.iter().all(|i| i.rule_id == "DF010"));
+
+        // The same rule on a different line is new
+        let edited = LEGACY.replace("ENV API_KEY=secret123", "ENV API_KEY=secret123\nENV DB_PASSWORD=hunter2");
+        let report = analyzer.analyze_dockerfile_str(&edited).unwrap();
+        let new_issues = baseline.new_issues("Dockerfile", &report.issues, Some(&edited));
+        assert!(new_issues.iter().any(|i| i.rule_id == "DF006" && i.line_number == Some(3)));
+    }
+
+    #[test]
+    fn test_written_baseline_only_matches_its_own_file() {
+        let analyzer = Analyzer::new();
+        let legacy = analyzer.analyze_dockerfile_str(LEGACY).unwrap();
+        let mut baseline = Baseline::default();
+        baseline.record("svc1/Dockerfile", &legacy.issues, Some(LEGACY));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        baseline.save(&path).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+
+        assert!(baseline.new_issues("svc1/Dockerfile", &legacy.issues, Some(LEGACY)).is_empty());
+        let other = baseline.new_issues("svc2/Dockerfile", &legacy.issues, Some(LEGACY));
+        assert_eq!(other.len(), legacy.issues.len());
+    }
+
+    #[test]
+    fn test_message_templates_do_not_resurface_baselined_issues() {
+        let compose = "services:\n  web:\n    image: nginx:1.25\n  db:\n    image: postgres:16\n";
+        let legacy = Analyzer::new().analyze_compose_str(compose).unwrap();
+        assert!(legacy.issues.iter().any(|i| i.rule_id == "DC003"));
+        let mut baseline = Baseline::default();
+        baseline.record("docker-compose.yml", &legacy.issues, Some(compose));
+
+        let templated = Analyzer::builder()
+            .message_template("DC003", "Dienst {service} hat keine Neustartrichtlinie")
+            .build()
+            .analyze_compose_str(compose)
+            .unwrap();
+        assert!(templated.issues.iter().any(|i| i.message.starts_with("Dienst")));
+        assert!(baseline.new_issues("docker-compose.yml", &templated.issues, Some(compose)).is_empty());
+    }
+
+    #[test]
+    fn test_file_key_is_relative_to_baseline_dir() {
+        let cwd = std::env::current_dir().unwrap();
+        let baseline = Path::new("ci/baseline.json");
+        assert_eq!(file_key("svc/Dockerfile", baseline), "../svc/Dockerfile");
+        assert_eq!(file_key("./svc/Dockerfile", baseline), "../svc/Dockerfile");
+        let absolute = cwd.join("svc/Dockerfile");
+        assert_eq!(file_key(absolute.to_str().unwrap(), baseline), "../svc/Dockerfile");
+        assert_eq!(file_key("Dockerfile", Path::new("baseline.json")), "Dockerfile");
+    }
+
+    #[test]
+    fn test_load_rejects_invalid_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        std::fs::write(&path, "{not json").unwrap();
+        assert!(matches!(Baseline::load(&path), Err(BaselineError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_score_baseline_reports_drops() {
+        let analyzer = Analyzer::new();
+        let clean = analyzer.analyze_dockerfile_str("FROM alpine:3.18\nUSER app\n").unwrap();
+        let mut scores = ScoreBaseline::default();
+        scores.record("Dockerfile", &clean.scores);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("scores.json");
+        scores.save(&path).unwrap();
+        let scores = ScoreBaseline::load(&path).unwrap();
+        assert_eq!(scores.drops("Dockerfile", &clean.scores), Some(vec![]));
+
+        let worse = analyzer.analyze_dockerfile_str(LEGACY).unwrap();
+        let drops = scores.drops("Dockerfile", &worse.scores).unwrap();
+        assert!(drops.iter().any(|d| d.category == Some(Category::Security)));
+        assert!(drops.iter().any(|d| d.category.is_none()));
+        assert_eq!(scores.drops("other/Dockerfile", &worse.scores), None);
+    }
+}
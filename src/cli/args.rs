@@ -1,5 +1,7 @@
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{ArgGroup, Parser, Subcommand};
+use super::Shell;
 use crate::rules::Severity;
+use crate::scoring::Category;
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -28,57 +30,212 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Commands {
     /// Analyze a Dockerfile or docker-compose file
-    Analyze(AnalyzeArgs),
+    Analyze(Box<AnalyzeArgs>),
 
     /// List all available rules
-    Rules,
+    Rules {
+        /// Print only the rule ids, one per line (for scripts and shell completion)
+        #[arg(long)]
+        ids: bool,
+    },
 
     /// Explain a specific rule
     Explain {
         /// Rule ID to explain (e.g., DF001, DC001)
         rule_id: String,
     },
+
+    /// Analyze two files and show how their scores and issues differ
+    Compare {
+        /// Baseline file or directory (A)
+        a: PathBuf,
+
+        /// File or directory to compare against it (B)
+        b: PathBuf,
+
+        /// Output the comparison as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Print a shell completion script (e.g. `docker-review completions bash > /etc/bash_completion.d/docker-review`)
+    Completions {
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+
+    /// Serve analysis over a local HTTP API (POST /analyze/dockerfile, /analyze/compose)
+    #[cfg(feature = "serve")]
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = crate::server::DEFAULT_ADDR)]
+        addr: String,
+    },
 }
 
 #[derive(Parser)]
+#[command(group(ArgGroup::new("baselines").args(["baseline", "fail_on_score_drop"]).multiple(true)))]
 pub struct AnalyzeArgs {
-    /// Path to Dockerfile or docker-compose.yml
+    /// Paths to Dockerfiles, docker-compose files or directories containing them
     #[arg(default_value = ".")]
-    pub path: PathBuf,
+    pub paths: Vec<PathBuf>,
+
+    /// Output format (e.g., terminal, json, sarif, github)
+    #[arg(long)]
+    pub format: Option<String>,
 
     /// Output in JSON format
     #[arg(long)]
     pub json: bool,
 
+    /// Output in JSON format with a severity/category legend
+    #[arg(long)]
+    pub json_detailed: bool,
+
+    /// Print the compose service dependency graph in Graphviz DOT format instead of a report
+    #[arg(long)]
+    pub graph: bool,
+
+    /// Output SARIF 2.1.0 for code scanning tools
+    #[arg(long)]
+    pub sarif: bool,
+
     /// Generate HTML report
     #[arg(long)]
     pub html: bool,
 
-    /// Generate PDF report
+    /// Generate PDF report (requires --output or --output-dir)
     #[arg(long)]
     pub pdf: bool,
 
-    /// Output file path for reports
+    /// Write the report to this file instead of stdout (single input only)
     #[arg(short, long)]
     pub output: Option<PathBuf>,
 
+    /// Write one report per analyzed file into this directory (created if needed)
+    #[arg(long)]
+    pub output_dir: Option<PathBuf>,
+
+    /// Print the analyzed file with a `# docker-review:` comment above each flagged line instead of a report
+    #[arg(long)]
+    pub annotate: bool,
+
     /// Minimum severity to report
     #[arg(long, value_enum)]
     pub severity: Option<Severity>,
 
+    /// Only report (and fail on) issues from one scoring category
+    #[arg(long, value_enum)]
+    pub category: Option<Category>,
+
+    /// Only analyze compose services in this profile (repeatable), plus services without profiles
+    #[arg(long, value_name = "NAME")]
+    pub profile: Vec<String>,
+
     /// Enable CI mode (machine-readable output)
     #[arg(long)]
     pub ci: bool,
 
-    /// Exit with non-zero code if issues at this severity or higher are found
+    /// Exit with non-zero code if issues at this severity or higher are found (default: critical)
     #[arg(long, value_enum)]
     pub fail_on: Option<Severity>,
 
+    /// Exit with non-zero code if any of these rules fire, regardless of severity (e.g., DF006,DC002)
+    #[arg(long, value_delimiter = ',')]
+    pub fail_on_rule: Vec<String>,
+
+    /// Run only these rules (e.g., DF001,DF002); the others are not evaluated
+    #[arg(long, value_delimiter = ',', value_name = "RULES")]
+    pub only: Vec<String>,
+
+    /// Skip these rules (e.g., DF003); cannot be combined with --only
+    #[arg(long, value_delimiter = ',', value_name = "RULES", conflicts_with = "only")]
+    pub disable: Vec<String>,
+
     /// Show only summary, not individual issues
     #[arg(long)]
     pub summary_only: bool,
 
+    /// Print nothing at all when no issues remain after filtering (for hooks and cron jobs)
+    #[arg(long)]
+    pub quiet_if_clean: bool,
+
     /// Show estimated impact of issues
     #[arg(long)]
     pub estimate_impact: bool,
+
+    /// Show the single most valuable fix after the issue list (implied by --verbose)
+    #[arg(long)]
+    pub next_steps: bool,
+
+    /// Link rule ids to their documentation (OSC 8); detected automatically on supporting terminals
+    #[arg(long)]
+    pub hyperlinks: bool,
+
+    /// Use ASCII instead of emoji and block characters; automatic when stdout is not a terminal
+    #[arg(long)]
+    pub no_emoji: bool,
+
+    /// Analyze every Dockerfile and compose file below directory paths (skips hidden and .gitignored paths)
+    #[arg(short, long)]
+    pub recursive: bool,
+
+    /// Treat paths as CI YAML files and analyze the Dockerfiles written inline in them
+    #[arg(long)]
+    pub extract_embedded: bool,
+
+    /// JSON file of known issues (from --write-baseline or the `issues` of a JSON report); only new issues are reported
+    #[arg(long)]
+    pub baseline: Option<PathBuf>,
+
+    /// Report file paths relative to DIR (default: the analyzed directory, or the file's directory)
+    #[arg(long, value_name = "DIR")]
+    pub relative_to: Option<Option<PathBuf>>,
+
+    /// Record the current issues into the --baseline file (and scores into the --fail-on-score-drop file) instead of checking against them
+    #[arg(long, requires = "baselines")]
+    pub write_baseline: bool,
+
+    /// JSON file of per-file scores (recorded with --write-baseline); fail when the overall or any category score is lower
+    #[arg(long, value_name = "FILE")]
+    pub fail_on_score_drop: Option<PathBuf>,
+
+    /// With --baseline, compute scores from the new issues only instead of all issues
+    #[arg(long, requires = "baseline")]
+    pub score_new_only: bool,
+
+    /// Config file to use instead of the .docker-review.toml found in the analyzed directory
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// YAML or JSON file mapping rule ids to custom message templates (e.g., DF001: "Image {image} ...")
+    #[arg(long)]
+    pub messages: Option<PathBuf>,
+
+    /// Print a JSON summary of the scan (files, issues, rules run, time) to stderr
+    #[arg(long)]
+    pub stats: bool,
+
+    /// Produce byte-identical output across runs (no color, stable ordering)
+    #[arg(long)]
+    pub deterministic: bool,
+}
+
+impl AnalyzeArgs {
+    /// Name of the output format selected by `--format` or the shorthand flags
+    pub fn format_name(&self) -> &str {
+        if let Some(ref format) = self.format {
+            format
+        } else if self.json || self.json_detailed {
+            "json"
+        } else if self.sarif {
+            "sarif"
+        } else if self.html {
+            "html"
+        } else if self.pdf {
+            "pdf"
+        } else {
+            "terminal"
+        }
+    }
 }
@@ -0,0 +1,314 @@
+//! Shell completion scripts (`docker-review completions <shell>`).
+//!
+//! Scripts are generated from the clap command tree. Rule ids are completed at
+//! runtime by calling `docker-review rules --ids`, so new rules need no
+//! regenerated script.
+
+use crate::output::formatter_names;
+use clap::{Arg, Command, ValueEnum};
+
+/// Arguments that take rule ids
+const RULE_ID_ARGS: &[&str] = &["rule_id", "fail_on_rule", "only", "disable"];
+
+const RULE_IDS_COMMAND: &str = "docker-review rules --ids";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    Powershell,
+}
+
+/// What a value of an argument completes to
+enum Values {
+    Files,
+    RuleIds,
+    List(Vec<String>),
+}
+
+/// A completable option or positional of one subcommand
+struct Completable {
+    long: Option<String>,
+    short: Option<char>,
+    help: String,
+    /// `None` for flags that take no value
+    values: Option<Values>,
+}
+
+struct Subcommand {
+    name: String,
+    about: String,
+    args: Vec<Completable>,
+}
+
+fn values_for(arg: &Arg) -> Option<Values> {
+    if !arg.get_action().takes_values() {
+        return None;
+    }
+    let id = arg.get_id().as_str();
+    if RULE_ID_ARGS.contains(&id) {
+        return Some(Values::RuleIds);
+    }
+    if id == "format" {
+        return Some(Values::List(formatter_names().iter().map(|s| s.to_string()).collect()));
+    }
+    let possible: Vec<String> = arg.get_possible_values().iter().map(|v| v.get_name().to_string()).collect();
+    if possible.is_empty() {
+        Some(Values::Files)
+    } else {
+        Some(Values::List(possible))
+    }
+}
+
+fn completables(command: &Command) -> Vec<Completable> {
+    command
+        .get_arguments()
+        .filter(|arg| !arg.is_hide_set())
+        .map(|arg| Completable {
+            long: arg.get_long().map(str::to_string),
+            short: arg.get_short(),
+            help: arg.get_help().map(|h| h.to_string()).unwrap_or_default(),
+            values: values_for(arg),
+        })
+        .collect()
+}
+
+fn subcommands(command: &Command) -> Vec<Subcommand> {
+    command
+        .get_subcommands()
+        .filter(|sub| !sub.is_hide_set() && sub.get_name() != "help")
+        .map(|sub| Subcommand {
+            name: sub.get_name().to_string(),
+            about: sub.get_about().map(|a| a.to_string()).unwrap_or_default(),
+            args: completables(sub),
+        })
+        .collect()
+}
+
+fn option_words(args: &[Completable]) -> Vec<String> {
+    let mut words = Vec::new();
+    for arg in args {
+        words.extend(arg.long.as_ref().map(|l| format!("--{}", l)));
+        words.extend(arg.short.map(|s| format!("-{}", s)));
+    }
+    words
+}
+
+/// The completion script for `shell`
+pub fn generate(shell: Shell, command: &mut Command) -> String {
+    command.build();
+    let global = completables(command);
+    let subcommands = subcommands(command);
+    match shell {
+        Shell::Bash => bash(&global, &subcommands),
+        Shell::Zsh => format!(
+            "#compdef docker-review\n\nautoload -U +X bashcompinit && bashcompinit\n\n{}",
+            bash(&global, &subcommands)
+        ),
+        Shell::Fish => fish(&global, &subcommands),
+        Shell::Powershell => powershell(&global, &subcommands),
+    }
+}
+
+fn bash_values(values: &Values) -> String {
+    match values {
+        Values::Files => "COMPREPLY=( $(compgen -f -- \"$cur\") )".to_string(),
+        Values::RuleIds => format!("COMPREPLY=( $(compgen -W \"$({} 2>/dev/null)\" -- \"$cur\") )", RULE_IDS_COMMAND),
+        Values::List(list) => format!("COMPREPLY=( $(compgen -W \"{}\" -- \"$cur\") )", list.join(" ")),
+    }
+}
+
+fn bash(global: &[Completable], subcommands: &[Subcommand]) -> String {
+    let mut script = String::new();
+    script.push_str("_docker_review() {\n");
+    script.push_str("    local cur=\"${COMP_WORDS[COMP_CWORD]}\"\n");
+    script.push_str("    local prev=\"${COMP_WORDS[COMP_CWORD-1]}\"\n");
+    script.push_str("    local subcommand=\"\" word\n");
+    script.push_str("    for word in \"${COMP_WORDS[@]:1:COMP_CWORD-1}\"; do\n");
+    script.push_str("        case \"$word\" in -*) ;; *) subcommand=\"$word\"; break ;; esac\n");
+    script.push_str("    done\n\n");
+
+    let names: Vec<&str> = subcommands.iter().map(|s| s.name.as_str()).collect();
+    script.push_str("    if [[ -z \"$subcommand\" ]]; then\n");
+    script.push_str(&format!(
+        "        COMPREPLY=( $(compgen -W \"{} {}\" -- \"$cur\") )\n",
+        names.join(" "),
+        option_words(global).join(" ")
+    ));
+    script.push_str("        return\n    fi\n\n");
+
+    script.push_str("    case \"$subcommand\" in\n");
+    for sub in subcommands {
+        script.push_str(&format!("        {})\n", sub.name));
+        // Values for the option just typed
+        let options_with_values: Vec<&Completable> =
+            sub.args.iter().filter(|a| a.long.is_some() && a.values.is_some()).collect();
+        if !options_with_values.is_empty() {
+            script.push_str("            case \"$prev\" in\n");
+            for arg in options_with_values {
+                let mut patterns = vec![format!("--{}", arg.long.as_deref().unwrap_or_default())];
+                patterns.extend(arg.short.map(|s| format!("-{}", s)));
+                script.push_str(&format!(
+                    "                {}) {}; return ;;\n",
+                    patterns.join("|"),
+                    bash_values(arg.values.as_ref().expect("filtered on values"))
+                ));
+            }
+            script.push_str("            esac\n");
+        }
+
+        // Global options are propagated into `sub.args` by `Command::build`
+        let words = option_words(&sub.args);
+        script.push_str("            if [[ \"$cur\" == -* ]]; then\n");
+        script.push_str(&format!(
+            "                COMPREPLY=( $(compgen -W \"{}\" -- \"$cur\") )\n",
+            words.join(" ")
+        ));
+        script.push_str("            else\n");
+        let positional = sub.args.iter().find(|a| a.long.is_none() && a.short.is_none());
+        let positional_values = positional.and_then(|a| a.values.as_ref()).unwrap_or(&Values::Files);
+        script.push_str(&format!("                {}\n", bash_values(positional_values)));
+        script.push_str("            fi\n            ;;\n");
+    }
+    script.push_str("    esac\n}\n\n");
+    script.push_str("complete -F _docker_review docker-review\n");
+    script
+}
+
+fn fish_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+fn fish_values(values: &Values) -> String {
+    match values {
+        Values::Files => " -r -F".to_string(),
+        Values::RuleIds => format!(" -r -f -a '({})'", RULE_IDS_COMMAND),
+        Values::List(list) => format!(" -r -f -a '{}'", list.join(" ")),
+    }
+}
+
+fn fish_option(condition: &str, arg: &Completable) -> Option<String> {
+    if arg.long.is_none() && arg.short.is_none() {
+        return None;
+    }
+    let mut line = format!("complete -c docker-review -n '{}'", condition);
+    if let Some(long) = &arg.long {
+        line.push_str(&format!(" -l {}", long));
+    }
+    if let Some(short) = arg.short {
+        line.push_str(&format!(" -s {}", short));
+    }
+    if let Some(values) = &arg.values {
+        line.push_str(&fish_values(values));
+    }
+    if !arg.help.is_empty() {
+        line.push_str(&format!(" -d '{}'", fish_escape(&arg.help)));
+    }
+    Some(line)
+}
+
+fn fish(global: &[Completable], subcommands: &[Subcommand]) -> String {
+    let mut lines = vec!["complete -c docker-review -f".to_string()];
+    for arg in global {
+        lines.extend(fish_option("true", arg));
+    }
+    for sub in subcommands {
+        lines.push(format!(
+            "complete -c docker-review -n '__fish_use_subcommand' -a {} -d '{}'",
+            sub.name,
+            fish_escape(&sub.about)
+        ));
+    }
+    for sub in subcommands {
+        let condition = format!("__fish_seen_subcommand_from {}", sub.name);
+        for arg in &sub.args {
+            match fish_option(&condition, arg) {
+                Some(line) => lines.push(line),
+                // Positionals
+                None => lines.push(format!(
+                    "complete -c docker-review -n '{}'{}",
+                    condition,
+                    fish_values(arg.values.as_ref().unwrap_or(&Values::Files))
+                )),
+            }
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+fn powershell_values(values: &Values) -> String {
+    match values {
+        Values::Files => "$null".to_string(),
+        Values::RuleIds => format!("@({})", RULE_IDS_COMMAND.replacen("docker-review", "& docker-review", 1)),
+        Values::List(list) => format!("@({})", list.iter().map(|v| format!("'{}'", v)).collect::<Vec<_>>().join(", ")),
+    }
+}
+
+fn powershell(global: &[Completable], subcommands: &[Subcommand]) -> String {
+    let quote = |words: Vec<String>| words.iter().map(|w| format!("'{}'", w)).collect::<Vec<_>>().join(", ");
+    let mut script = String::new();
+    script.push_str("Register-ArgumentCompleter -Native -CommandName docker-review -ScriptBlock {\n");
+    script.push_str("    param($wordToComplete, $commandAst, $cursorPosition)\n");
+    script.push_str("    $words = @($commandAst.CommandElements | Select-Object -Skip 1 | ForEach-Object { $_.ToString() })\n");
+    script.push_str("    if ($wordToComplete) { $words = @($words | Select-Object -SkipLast 1) }\n");
+    script.push_str("    $subcommand = $words | Where-Object { -not $_.StartsWith('-') } | Select-Object -First 1\n");
+    script.push_str("    $prev = if ($words.Count) { $words[-1] } else { '' }\n");
+    script.push_str(&format!("    $global = @({})\n", quote(option_words(global))));
+    script.push_str("    $candidates = switch ($subcommand) {\n");
+    for sub in subcommands {
+        script.push_str(&format!("        '{}' {{\n", sub.name));
+        script.push_str("            switch ($prev) {\n");
+        for arg in sub.args.iter().filter(|a| a.long.is_some()) {
+            if let Some(values) = &arg.values {
+                script.push_str(&format!(
+                    "                '--{}' {{ {}; return }}\n",
+                    arg.long.as_deref().unwrap_or_default(),
+                    powershell_values(values)
+                ));
+            }
+        }
+        let positional = sub.args.iter().find(|a| a.long.is_none() && a.short.is_none());
+        if let Some(Values::RuleIds) = positional.and_then(|a| a.values.as_ref()) {
+            script.push_str(&format!(
+                "                default {{ if (-not $wordToComplete.StartsWith('-')) {{ {}; return }} }}\n",
+                powershell_values(&Values::RuleIds)
+            ));
+        }
+        script.push_str("            }\n");
+        script.push_str(&format!("            @({})\n", quote(option_words(&sub.args))));
+        script.push_str("        }\n");
+    }
+    let names: Vec<String> = subcommands.iter().map(|s| s.name.clone()).collect();
+    script.push_str(&format!("        default {{ @({}) + $global }}\n", quote(names)));
+    script.push_str("    }\n");
+    script.push_str("    $candidates | Where-Object { $_ -and $_.StartsWith($wordToComplete) } | ForEach-Object {\n");
+    script.push_str("        [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)\n");
+    script.push_str("    }\n}\n");
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Cli;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_bash_completes_subcommands_and_rule_ids() {
+        let script = generate(Shell::Bash, &mut Cli::command());
+        assert!(script.contains("complete -F _docker_review docker-review"));
+        assert!(script.contains("analyze rules explain compare"));
+        assert!(script.contains("--fail-on-rule) COMPREPLY=( $(compgen -W \"$(docker-review rules --ids"));
+        assert!(script.contains("--fail-on) COMPREPLY=( $(compgen -W \"suggestion warning critical\""));
+    }
+
+    #[test]
+    fn test_every_shell_lists_analyze() {
+        for shell in Shell::value_variants() {
+            let script = generate(*shell, &mut Cli::command());
+            assert!(script.contains("analyze"), "{:?}", shell);
+            assert!(script.contains("rules --ids"), "{:?}", shell);
+        }
+    }
+}
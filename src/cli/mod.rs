@@ -1,3 +1,5 @@
 mod args;
+mod completions;
 
 pub use args::{Cli, Commands, AnalyzeArgs};
+pub use completions::{generate as generate_completions, Shell};
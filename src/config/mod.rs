@@ -0,0 +1,202 @@
+//! Project configuration read from `.docker-review.toml`.
+//!
+//! ```toml
+//! disable = ["DF001", "DF009"]
+//! fail_on = "warning"
+//! max_run_steps = 15
+//! sensitive_ports = [5432, 6379, 8081]
+//!
+//! [severity]
+//! DF002 = "warning"
+//! ```
+//!
+//! Tables other tools or newer versions may add (e.g. `[output]`) are ignored;
+//! unknown top-level keys are rejected so a misspelled `disable` is reported
+//! rather than ignored.
+
+use crate::rules::Severity;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use thiserror::Error;
+
+/// File looked up in the analyzed directory when no `--config` is given
+pub const CONFIG_FILE_NAME: &str = ".docker-review.toml";
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Failed to read config: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Invalid config at line {line}: {message}")]
+    ParseError { line: usize, message: String },
+}
+
+/// Rules to skip, severity overrides, rule thresholds and the default `--fail-on` level
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    pub disabled_rules: Vec<String>,
+    pub severity_overrides: BTreeMap<String, Severity>,
+    pub fail_on: Option<Severity>,
+    /// `&&`-joined commands allowed in one RUN before DF040 reports it
+    pub max_run_steps: Option<usize>,
+    /// Container ports DC009 reports when published on all interfaces
+    pub sensitive_ports: Option<Vec<u16>>,
+}
+
+impl Config {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// The `.docker-review.toml` next to an analyzed file, or inside an analyzed directory
+    pub fn discover(path: &Path) -> Result<Option<Self>, ConfigError> {
+        let dir = if path.is_dir() { path } else { path.parent().unwrap_or(Path::new(".")) };
+        let candidate = dir.join(CONFIG_FILE_NAME);
+        if candidate.is_file() {
+            Self::load(candidate).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, ConfigError> {
+        let raw: RawConfig = toml::from_str(content).map_err(|e| ConfigError::ParseError {
+            line: e.span().map_or(1, |span| line_of(content, span.start)),
+            message: e.message().to_string(),
+        })?;
+
+        if let Some((key, _)) = raw.other.iter().find(|(_, value)| !value.is_table()) {
+            return Err(ConfigError::ParseError {
+                line: key_line(content, key),
+                message: format!("unknown key '{}'", key),
+            });
+        }
+
+        Ok(Self {
+            disabled_rules: raw.disable.into_iter().map(|id| id.to_uppercase()).collect(),
+            severity_overrides: raw.severity.into_iter().map(|(id, severity)| (id.to_uppercase(), severity)).collect(),
+            fail_on: raw.fail_on,
+            max_run_steps: raw.max_run_steps,
+            sensitive_ports: raw.sensitive_ports,
+        })
+    }
+}
+
+/// `.docker-review.toml` as written, before rule ids are normalized
+#[derive(Deserialize)]
+struct RawConfig {
+    #[serde(default)]
+    disable: Vec<String>,
+    fail_on: Option<Severity>,
+    max_run_steps: Option<usize>,
+    sensitive_ports: Option<Vec<u16>>,
+    #[serde(default)]
+    severity: BTreeMap<String, Severity>,
+    #[serde(flatten)]
+    other: BTreeMap<String, toml::Value>,
+}
+
+/// 1-based line containing byte `offset`
+fn line_of(content: &str, offset: usize) -> usize {
+    content[..offset.min(content.len())].matches('\n').count() + 1
+}
+
+/// Line of a top-level `key = ...` assignment
+fn key_line(content: &str, key: &str) -> usize {
+    content
+        .lines()
+        .position(|line| {
+            let line = line.trim_start();
+            line.strip_prefix(key).is_some_and(|rest| rest.trim_start().starts_with('='))
+        })
+        .map_or(1, |index| index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use std::path::Path;
+
+    const CONFIG: &str = r#"
+# Legacy base images are tracked elsewhere
+disable = ["DF001"]
+fail_on = "warning"
+
+[severity]
+DF002 = "warning"  # runs as root only in CI images
+"#;
+
+    #[test]
+    fn test_config_disables_and_remaps_rules() {
+        let config = Config::parse(CONFIG).unwrap();
+        assert_eq!(config.disabled_rules, vec!["DF001"]);
+        assert_eq!(config.fail_on, Some(Severity::Warning));
+
+        let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/bad_dockerfile");
+        let default = Analyzer::new().analyze(&fixture).unwrap();
+        assert!(default.issues.iter().any(|i| i.rule_id == "DF001"));
+        assert!(default.issues.iter().any(|i| i.rule_id == "DF002" && i.severity == Severity::Critical));
+
+        let report = Analyzer::builder().config(&config).build().analyze(&fixture).unwrap();
+        assert!(!report.issues.iter().any(|i| i.rule_id == "DF001"));
+        let df002 = report.issues.iter().find(|i| i.rule_id == "DF002").unwrap();
+        assert_eq!(df002.severity, Severity::Warning);
+        assert!(report.scores.overall.current >= default.scores.overall.current);
+    }
+
+    #[test]
+    fn test_discover_in_analyzed_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Dockerfile"), "FROM alpine:3.18\n").unwrap();
+        assert_eq!(Config::discover(dir.path()).unwrap(), None);
+
+        std::fs::write(dir.path().join(CONFIG_FILE_NAME), "disable = [\n  \"DF009\",\n  \"df005\",\n]\n").unwrap();
+        let config = Config::discover(&dir.path().join("Dockerfile")).unwrap().unwrap();
+        assert_eq!(config.disabled_rules, vec!["DF009", "DF005"]);
+    }
+
+    #[test]
+    fn test_rejects_unknown_keys_and_severities() {
+        let err = Config::parse("disabled = [\"DF001\"]").unwrap_err();
+        assert!(err.to_string().contains("line 1"));
+        let err = Config::parse("[severity]\nDF002 = \"low\"").unwrap_err();
+        assert!(matches!(err, ConfigError::ParseError { line: 2, .. }));
+    }
+
+    #[test]
+    fn test_accepts_compact_arrays_and_ignores_unknown_tables() {
+        let config = Config::parse("disable=[\"DF001\",\n \"df002\"]\n\n[output]\nformat = \"json\"\n").unwrap();
+        assert_eq!(config.disabled_rules, vec!["DF001", "DF002"]);
+    }
+
+    #[test]
+    fn test_max_run_steps_sets_long_run_chain_threshold() {
+        let config = Config::parse("max_run_steps = 2\n").unwrap();
+        assert_eq!(config.max_run_steps, Some(2));
+
+        let content = "FROM alpine:3.18\nRUN echo 1 && echo 2 && echo 3\n";
+        let default = Analyzer::new().analyze_dockerfile_str(content).unwrap();
+        assert!(!default.issues.iter().any(|i| i.rule_id == "DF040"));
+
+        let report = Analyzer::builder().config(&config).build().analyze_dockerfile_str(content).unwrap();
+        assert!(report.issues.iter().any(|i| i.rule_id == "DF040"));
+    }
+
+    #[test]
+    fn test_sensitive_ports_set_port_binding_list() {
+        let config = Config::parse("sensitive_ports = [8081]\n").unwrap();
+        assert_eq!(config.sensitive_ports, Some(vec![8081]));
+
+        let content = "services:\n  admin:\n    image: mongo-express:1.0\n    ports:\n      - \"8081:8081\"\n  db:\n    image: postgres:16\n    ports:\n      - \"5432:5432\"\n";
+        let default = Analyzer::new().analyze_compose_str(content).unwrap();
+        let flagged: Vec<_> = default.issues.iter().filter(|i| i.rule_id == "DC009").collect();
+        assert_eq!(flagged.len(), 1);
+        assert!(flagged[0].message.contains("5432"));
+
+        let report = Analyzer::builder().config(&config).build().analyze_compose_str(content).unwrap();
+        let flagged: Vec<_> = report.issues.iter().filter(|i| i.rule_id == "DC009").collect();
+        assert_eq!(flagged.len(), 1);
+        assert!(flagged[0].message.contains("8081"));
+    }
+}
@@ -1,6 +1,10 @@
 pub mod analyzer;
+pub mod baseline;
 pub mod cli;
+pub mod config;
 pub mod output;
 pub mod parser;
 pub mod rules;
 pub mod scoring;
+#[cfg(feature = "serve")]
+pub mod server;
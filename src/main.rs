@@ -1,9 +1,15 @@
-use clap::Parser;
-use docker_review::cli::{Cli, Commands};
-use docker_review::analyzer::Analyzer;
-use docker_review::output::{JsonOutput, TerminalOutput, OutputFormatter};
-use docker_review::rules::Severity;
+use clap::{CommandFactory, Parser};
+use docker_review::cli::{generate_completions, AnalyzeArgs, Cli, Commands};
+use docker_review::analyzer::{load_message_templates, Analyzer, AnalyzerError, Report, ScanStats};
+use docker_review::baseline::{file_key, Baseline, ScoreBaseline};
+use docker_review::config::Config;
+use docker_review::output::{annotate, compose_to_dot, Comparison, get_formatter_with_options, report_file_name, stdout_supports_hyperlinks, FormatOptions, OutputFormatter};
+use docker_review::rules::{Issue, Severity};
+use docker_review::scoring::Category;
+use std::io::IsTerminal;
+use std::path::Path;
 use std::process::ExitCode;
+use std::time::Instant;
 
 fn main() -> ExitCode {
     let cli = Cli::parse();
@@ -13,53 +19,37 @@ fn main() -> ExitCode {
         colored::control::set_override(false);
     }
 
-    match cli.command {
+    match &cli.command {
         Commands::Analyze(args) => {
+            return run_analyze(&cli, args);
+        }
+        Commands::Rules { ids } => {
+            if *ids {
+                for rule in docker_review::rules::get_all_rules() {
+                    println!("{}", rule.id());
+                }
+            } else {
+                docker_review::rules::print_all_rules();
+            }
+        }
+        Commands::Explain { rule_id } => {
+            if let Some(rule) = docker_review::rules::get_rule_by_id(rule_id) {
+                println!("{}", rule.explain());
+            } else {
+                eprintln!("Unknown rule: {}", rule_id);
+                return ExitCode::from(1);
+            }
+        }
+        Commands::Compare { a, b, json } => {
             let analyzer = Analyzer::new();
-            
-            match analyzer.analyze(&args.path) {
-                Ok(report) => {
-                    // Filter by severity if specified
-                    let filtered_issues: Vec<_> = if let Some(ref min_severity) = args.severity {
-                        report.issues.iter()
-                            .filter(|issue| issue.severity >= *min_severity)
-                            .cloned()
-                            .collect()
+            let reports = analyzer.analyze(a).and_then(|a| Ok((a, analyzer.analyze(b)?)));
+            match reports {
+                Ok((a, b)) => {
+                    let comparison = Comparison::new(&a, &b);
+                    if *json {
+                        println!("{}", comparison.to_json());
                     } else {
-                        report.issues.clone()
-                    };
-
-                    let filtered_report = docker_review::analyzer::Report {
-                        issues: filtered_issues,
-                        scores: report.scores.clone(),
-                        file_path: report.file_path.clone(),
-                    };
-
-                    // Output format
-                    if args.json {
-                        let output = JsonOutput;
-                        println!("{}", output.format(&filtered_report));
-                    } else if !args.summary_only {
-                        let output = TerminalOutput::new(cli.verbose, args.estimate_impact);
-                        println!("{}", output.format(&filtered_report));
-                    }
-
-                    // Summary for --summary-only or always show summary
-                    if args.summary_only {
-                        println!("Issues found: {}", filtered_report.issues.len());
-                        println!("  Critical: {}", filtered_report.issues.iter().filter(|i| i.severity == Severity::Critical).count());
-                        println!("  Warning: {}", filtered_report.issues.iter().filter(|i| i.severity == Severity::Warning).count());
-                        println!("  Suggestion: {}", filtered_report.issues.iter().filter(|i| i.severity == Severity::Suggestion).count());
-                    }
-
-                    // Exit code for CI
-                    if args.ci {
-                        let fail_severity = args.fail_on.unwrap_or(Severity::Critical);
-                        let has_failures = filtered_report.issues.iter()
-                            .any(|issue| issue.severity >= fail_severity);
-                        if has_failures {
-                            return ExitCode::from(1);
-                        }
+                        print!("{}", comparison.to_terminal());
                     }
                 }
                 Err(e) => {
@@ -68,14 +58,17 @@ fn main() -> ExitCode {
                 }
             }
         }
-        Commands::Rules => {
-            docker_review::rules::print_all_rules();
+        Commands::Completions { shell } => {
+            print!("{}", generate_completions(*shell, &mut Cli::command()));
         }
-        Commands::Explain { rule_id } => {
-            if let Some(rule) = docker_review::rules::get_rule_by_id(&rule_id) {
-                println!("{}", rule.explain());
-            } else {
-                eprintln!("Unknown rule: {}", rule_id);
+        #[cfg(feature = "serve")]
+        Commands::Serve { addr } => {
+            let result = docker_review::server::Server::bind(addr.as_str(), Analyzer::new()).and_then(|server| {
+                eprintln!("Listening on http://{}", server.local_addr()?);
+                server.run()
+            });
+            if let Err(e) = result {
+                eprintln!("Error: {}", e);
                 return ExitCode::from(1);
             }
         }
@@ -83,3 +76,363 @@ fn main() -> ExitCode {
 
     ExitCode::SUCCESS
 }
+
+fn run_analyze(cli: &Cli, args: &AnalyzeArgs) -> ExitCode {
+    // Reports written to files should not contain color codes
+    if args.deterministic || args.output.is_some() || args.output_dir.is_some() {
+        colored::control::set_override(false);
+    }
+
+    // A mistyped id would silently skip every rule (--only), none (--disable)
+    // or never fail the build (--fail-on-rule)
+    if let Some(rule_id) = args.only.iter().chain(&args.disable).chain(&args.fail_on_rule).find(|id| docker_review::rules::get_rule_by_id(id).is_none()) {
+        eprintln!("Error: unknown rule id '{}' (see `docker-review rules`)", rule_id);
+        return ExitCode::from(1);
+    }
+
+    let started = Instant::now();
+    let mut builder = Analyzer::builder();
+    if !args.profile.is_empty() {
+        builder = builder.profiles(&args.profile);
+    }
+    if !args.only.is_empty() {
+        builder = builder.only_rules(&args.only);
+    }
+    builder = builder.disable_rules(&args.disable);
+    if let Some(path) = &args.messages {
+        match load_message_templates(path) {
+            Ok(templates) => builder = builder.message_templates(templates),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::from(1);
+            }
+        }
+    }
+    let analyzer = builder.clone().build();
+
+    // An explicit --config applies to every path; otherwise each path may have its own
+    let explicit_config = match &args.config {
+        Some(path) => match Config::load(path) {
+            Ok(config) => Some(config),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::from(1);
+            }
+        },
+        None => None,
+    };
+
+    if args.graph {
+        for path in &args.paths {
+            match analyzer.load_compose(path) {
+                Ok(compose) => print!("{}", compose_to_dot(&compose)),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::from(1);
+                }
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let format_name = args.format_name();
+    let writes_stdout = !args.deterministic && args.output.is_none() && args.output_dir.is_none();
+    let options = FormatOptions {
+        verbose: cli.verbose,
+        show_impact: args.estimate_impact,
+        detailed: args.json_detailed,
+        next_steps: args.next_steps,
+        hyperlinks: args.hyperlinks || (writes_stdout && stdout_supports_hyperlinks()),
+        ascii: args.no_emoji || (writes_stdout && !std::io::stdout().is_terminal()),
+    };
+    let Some(formatter) = get_formatter_with_options(format_name, &options) else {
+        eprintln!("Unknown output format: {}", format_name);
+        return ExitCode::from(1);
+    };
+
+    let issues = match &args.baseline {
+        // Recording starts from scratch; the file may not exist yet
+        Some(_) if args.write_baseline => Some(Baseline::default()),
+        Some(path) => match Baseline::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::from(1);
+            }
+        },
+        None => None,
+    };
+    let scores = match &args.fail_on_score_drop {
+        Some(_) if args.write_baseline => Some(ScoreBaseline::default()),
+        Some(path) => match ScoreBaseline::load(path) {
+            Ok(scores) => Some(scores),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return ExitCode::from(1);
+            }
+        },
+        None => None,
+    };
+    let mut baselines = Baselines { issues, scores };
+
+    if args.output.is_some() && (args.paths.len() > 1 || args.output_dir.is_some()) {
+        eprintln!("Error: --output takes a single input; use --output-dir for several files");
+        return ExitCode::from(1);
+    }
+
+    // Binary formats cannot go to the terminal
+    if format_name.eq_ignore_ascii_case("pdf") && args.output.is_none() && args.output_dir.is_none() {
+        eprintln!("Error: PDF reports must be written to a file; pass --output <FILE>");
+        return ExitCode::from(1);
+    }
+
+    if let Some(dir) = &args.output_dir {
+        if let Err(e) = std::fs::create_dir_all(dir) {
+            eprintln!("Error: cannot create output directory {}: {}", dir.display(), e);
+            return ExitCode::from(1);
+        }
+    }
+
+    let mut failed = false;
+    let mut stats = ScanStats::default();
+    let mut rules_evaluated = 0;
+    for path in &args.paths {
+        let config = match &explicit_config {
+            Some(config) => config.clone(),
+            None => match Config::discover(path) {
+                Ok(config) => config.unwrap_or_default(),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    failed = true;
+                    continue;
+                }
+            },
+        };
+        let analyzer = builder.clone().config(&config).build();
+        if !analyze_path(args, &analyzer, formatter.as_ref(), &mut baselines, &config, path, &mut stats) {
+            failed = true;
+        }
+        rules_evaluated += analyzer.rules_evaluated();
+    }
+
+    if let (Some(baseline), Some(path), true) = (&baselines.issues, &args.baseline, args.write_baseline) {
+        if let Err(e) = baseline.save(path) {
+            eprintln!("Error: cannot write {}: {}", path.display(), e);
+            failed = true;
+        }
+    }
+    if let (Some(scores), Some(path), true) = (&baselines.scores, &args.fail_on_score_drop, args.write_baseline) {
+        if let Err(e) = scores.save(path) {
+            eprintln!("Error: cannot write {}: {}", path.display(), e);
+            failed = true;
+        }
+    }
+
+    if args.stats {
+        stats.finish(rules_evaluated, started.elapsed());
+        match serde_json::to_string(&stats) {
+            Ok(json) => eprintln!("{}", json),
+            Err(e) => eprintln!("Error: failed to serialize stats: {}", e),
+        }
+    }
+
+    if failed {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// The issue and score baselines given on the command line
+struct Baselines {
+    issues: Option<Baseline>,
+    scores: Option<ScoreBaseline>,
+}
+
+/// Analyze and report a single path. Returns false when the run should fail.
+fn analyze_path(
+    args: &AnalyzeArgs,
+    analyzer: &Analyzer,
+    formatter: &dyn OutputFormatter,
+    baselines: &mut Baselines,
+    config: &Config,
+    path: &Path,
+    stats: &mut ScanStats,
+) -> bool {
+    let results: Vec<Result<Report, AnalyzerError>> = if args.extract_embedded {
+        match analyzer.analyze_embedded(path) {
+            Ok(reports) => reports.into_iter().map(Ok).collect(),
+            Err(e) => vec![Err(e)],
+        }
+    } else if args.recursive && path.is_dir() {
+        match analyzer.analyze_recursive(path) {
+            Ok(results) if results.is_empty() => {
+                eprintln!("Error: no Dockerfile or compose file found under {}", path.display());
+                return false;
+            }
+            Ok(results) => results,
+            Err(e) => vec![Err(e)],
+        }
+    } else {
+        vec![analyzer.analyze(path)]
+    };
+
+    // Every report would overwrite the same file
+    if args.output.is_some() && results.len() > 1 {
+        eprintln!(
+            "Error: {} produced {} reports but --output takes one; use --output-dir instead",
+            path.display(),
+            results.len()
+        );
+        return false;
+    }
+
+    // Portable paths are needed before baseline matching and output
+    let relative_base = args.relative_to.as_ref().map(|base| match base {
+        Some(dir) => dir.clone(),
+        None if path.is_dir() => path.to_path_buf(),
+        None => path.parent().map(Path::to_path_buf).unwrap_or_default(),
+    });
+
+    // One broken file should not hide the reports of the others
+    let mut passed = true;
+    for result in results {
+        let passed_one = match result {
+            Ok(mut report) => {
+                // Line fingerprints and annotations need the source text, read before the path is rewritten
+                let source = (baselines.issues.is_some() || args.annotate)
+                    .then(|| std::fs::read_to_string(&report.file_path).ok())
+                    .flatten();
+                // Baseline entries are keyed by the path as analyzed, not as reported
+                let issue_key = args.baseline.as_ref().map(|path| file_key(&report.file_path, path)).unwrap_or_default();
+                let score_key = args.fail_on_score_drop.as_ref().map(|path| file_key(&report.file_path, path)).unwrap_or_default();
+                if let Some(base) = &relative_base {
+                    report.relativize(base);
+                }
+                match baselines.issues.as_mut() {
+                    Some(baseline) if args.write_baseline => {
+                        baseline.record(&issue_key, &report.issues, source.as_deref());
+                    }
+                    Some(baseline) => {
+                        // Pre-existing issues are set aside before any filtering or gating
+                        let (new, baselined) = baseline.partition(&issue_key, &report.issues, source.as_deref());
+                        report.issues = new;
+                        report.suppressed = baselined;
+                        if args.score_new_only {
+                            report.scores = analyzer.score(&report.issues);
+                        }
+                    }
+                    None => {}
+                }
+                let mut kept_scores = true;
+                match baselines.scores.as_mut() {
+                    Some(scores) if args.write_baseline => scores.record(&score_key, &report.scores),
+                    Some(scores) => match scores.drops(&score_key, &report.scores) {
+                        Some(drops) => {
+                            for drop in drops {
+                                eprintln!("Error: {}: {}", report.file_path, drop);
+                                kept_scores = false;
+                            }
+                        }
+                        None => eprintln!(
+                            "Warning: {}: no score recorded in the score baseline; record it with --write-baseline",
+                            report.file_path
+                        ),
+                    },
+                    None => {}
+                }
+                report_one(args, formatter, config, report, source.as_deref(), stats) && kept_scores
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                false
+            }
+        };
+        passed &= passed_one;
+    }
+    passed
+}
+
+/// Filter, print and gate one report. Returns false when the run should fail.
+fn report_one(
+    args: &AnalyzeArgs,
+    formatter: &dyn OutputFormatter,
+    config: &Config,
+    report: Report,
+    source: Option<&str>,
+    stats: &mut ScanStats,
+) -> bool {
+    // Filter by severity and category if specified
+    let filter = |issues: &[Issue]| -> Vec<Issue> {
+        issues.iter()
+            .filter(|issue| args.severity.is_none_or(|min| issue.severity >= min))
+            .filter(|issue| args.category.is_none_or(|c| Category::for_rule(&issue.rule_id) == Some(c)))
+            .cloned()
+            .collect()
+    };
+
+    let mut filtered_report = Report::new(report.file_path.clone(), filter(&report.issues), report.scores.clone());
+    filtered_report.suppressed = filter(&report.suppressed);
+    if args.deterministic {
+        filtered_report.sort_deterministic();
+    }
+    stats.record(&filtered_report);
+
+    // Nothing to report: stay silent for hooks and cron jobs
+    let silent = args.quiet_if_clean && filtered_report.issues.is_empty();
+
+    // Output format
+    let format_name = if args.annotate { "annotated" } else { args.format_name() };
+    let terminal_summary_only = args.summary_only && format_name == "terminal";
+    if !silent && !terminal_summary_only {
+        let output = match (args.annotate, source) {
+            (false, _) => formatter.format(&filtered_report),
+            (true, Some(source)) => annotate(source, &filtered_report),
+            (true, None) => {
+                eprintln!("Error: cannot annotate {}: source file is not readable", filtered_report.file_path);
+                return false;
+            }
+        };
+        let target = match (&args.output, &args.output_dir) {
+            (Some(file), _) => Some(file.clone()),
+            (None, Some(dir)) => Some(dir.join(report_file_name(&filtered_report.file_path, format_name))),
+            (None, None) => None,
+        };
+        match target {
+            Some(target) => {
+                if let Err(e) = std::fs::write(&target, format!("{}\n", output)) {
+                    eprintln!("Error: cannot write {}: {}", target.display(), e);
+                    return false;
+                }
+            }
+            None => println!("{}", output),
+        }
+    }
+
+    // Summary for --summary-only or always show summary
+    if args.summary_only && !silent {
+        let by_severity = filtered_report.issues_by_severity();
+        let count = |severity| by_severity.get(&severity).map_or(0, Vec::len);
+        println!("Issues found: {}", filtered_report.issues.len());
+        println!("  Critical: {}", count(Severity::Critical));
+        println!("  Warning: {}", count(Severity::Warning));
+        println!("  Suggestion: {}", count(Severity::Suggestion));
+    }
+
+    // Listed rules are blockers regardless of severity or filtering
+    if report.issues.iter().any(|issue| {
+        args.fail_on_rule.iter().any(|id| id.eq_ignore_ascii_case(&issue.rule_id))
+    }) {
+        return false;
+    }
+
+    // Exit code for CI
+    if args.ci {
+        let fail_severity = args.fail_on.or(config.fail_on).unwrap_or(Severity::Critical);
+        if filtered_report.count_at_least(fail_severity) > 0 {
+            return false;
+        }
+    }
+
+    true
+}
@@ -0,0 +1,72 @@
+use crate::analyzer::Report;
+use crate::parser::parse_directive;
+use crate::rules::Issue;
+
+/// Prefix of every inserted comment, so annotations are easy to find and strip
+pub const ANNOTATION_PREFIX: &str = "# docker-review:";
+
+fn comment(issue: &Issue, indent: &str) -> String {
+    format!("{}{} [{}] {}", indent, ANNOTATION_PREFIX, issue.rule_id, issue.message.replace('\n', " "))
+}
+
+/// `source` with a `# docker-review: [ID] message` comment above each line that has
+/// an issue. Issues without a line go at the top, after any parser directives.
+/// Only comment lines are added, so the file means the same to Docker and Compose.
+pub fn annotate(source: &str, report: &Report) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut by_line: Vec<Vec<&Issue>> = vec![Vec::new(); lines.len() + 1];
+    for issue in &report.issues {
+        let index = issue.line_number.filter(|n| (1..=lines.len()).contains(n)).unwrap_or(0);
+        by_line[index].push(issue);
+    }
+
+    let directives = lines.iter().take_while(|line| parse_directive(line).is_some()).count();
+    let mut output = Vec::with_capacity(lines.len() + report.issues.len());
+    for (index, line) in lines.iter().enumerate() {
+        if index == directives {
+            output.extend(by_line[0].iter().map(|issue| comment(issue, "")));
+        }
+        let indent = &line[..line.len() - line.trim_start().len()];
+        output.extend(by_line[index + 1].iter().map(|issue| comment(issue, indent)));
+        output.push(line.to_string());
+    }
+    if directives == lines.len() {
+        output.extend(by_line[0].iter().map(|issue| comment(issue, "")));
+    }
+    output.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::parser::DockerfileParser;
+
+    #[test]
+    fn test_comment_above_flagged_from() {
+        let source = "# syntax=docker/dockerfile:1\nFROM ubuntu:latest\nRUN echo hi\n";
+        let report = Analyzer::new().analyze_dockerfile_str(source).unwrap();
+        let annotated = annotate(source, &report);
+        let lines: Vec<&str> = annotated.lines().collect();
+
+        let from = lines.iter().position(|l| *l == "FROM ubuntu:latest").unwrap();
+        assert!(lines[..from].iter().any(|l| l.starts_with("# docker-review: [DF001] ")));
+        // Directives stay first; file-level issues follow them
+        assert_eq!(lines[0], "# syntax=docker/dockerfile:1");
+        assert!(lines[1].starts_with(ANNOTATION_PREFIX));
+    }
+
+    #[test]
+    fn test_only_comments_are_added() {
+        let source = "FROM node:20\nWORKDIR /app\nRUN npm install && \\\n    npm run build\n";
+        let report = Analyzer::new().analyze_dockerfile_str(source).unwrap();
+        let annotated = annotate(source, &report);
+
+        let stripped: Vec<&str> = annotated.lines().filter(|l| !l.trim_start().starts_with(ANNOTATION_PREFIX)).collect();
+        assert_eq!(stripped, source.lines().collect::<Vec<_>>());
+        let original = DockerfileParser::parse_content(source);
+        let reparsed = DockerfileParser::parse_content(&annotated);
+        let arguments = |p: &DockerfileParser| p.instructions.iter().map(|i| i.arguments.clone()).collect::<Vec<_>>();
+        assert_eq!(arguments(&reparsed), arguments(&original));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::analyzer::Report;
+use crate::rules::Issue;
+use crate::scoring::Scores;
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// One side of a comparison
+#[derive(Debug, Clone, Serialize)]
+pub struct ComparedFile {
+    pub file_path: String,
+    pub scores: Scores,
+    pub issue_count: usize,
+    /// Issues the other file does not have
+    pub unique_issues: Vec<Issue>,
+}
+
+/// Scores and issue differences between two analyzed files (`docker-review compare`)
+#[derive(Debug, Clone, Serialize)]
+pub struct Comparison {
+    pub left: ComparedFile,
+    pub right: ComparedFile,
+}
+
+impl Comparison {
+    /// Issues are matched by fingerprint, so the same finding on different
+    /// lines or for differently named images counts as shared
+    pub fn new(left: &Report, right: &Report) -> Self {
+        Self {
+            left: compared_file(left, right),
+            right: compared_file(right, left),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|e| {
+            format!("{{\"error\": \"Failed to serialize comparison: {}\"}}", e)
+        })
+    }
+
+    pub fn to_terminal(&self) -> String {
+        let mut output = String::new();
+        let (left, right) = (&self.left, &self.right);
+
+        output.push_str(&format!("\n{}\n", "Docker Review Comparison".bold().underline()));
+        output.push_str(&format!("  A: {}\n", left.file_path.cyan()));
+        output.push_str(&format!("  B: {}\n\n", right.file_path.cyan()));
+
+        output.push_str(&format!("{}\n", "📊 Scores".bold()));
+        output.push_str(&format!("  {:<16} {:>6} {:>6}\n", "", "A", "B"));
+        for (label, a, b) in [
+            ("Security", left.scores.security.current, right.scores.security.current),
+            ("Performance", left.scores.performance.current, right.scores.performance.current),
+            ("Maintainability", left.scores.maintainability.current, right.scores.maintainability.current),
+            ("Overall", left.scores.overall.current, right.scores.overall.current),
+        ] {
+            output.push_str(&format!("  {:<16} {:>6} {:>6}  {}\n", label, format!("{}/10", a), format!("{}/10", b), delta(a, b)));
+        }
+        output.push_str(&format!("  {:<16} {:>6} {:>6}\n\n", "Issues", left.issue_count, right.issue_count));
+
+        for (title, file) in [("Only in A", left), ("Only in B", right)] {
+            output.push_str(&format!("{} ({})\n", title.bold(), file.unique_issues.len()));
+            if file.unique_issues.is_empty() {
+                output.push_str("  (none)\n");
+            }
+            for issue in &file.unique_issues {
+                let line = issue.line_number.map(|l| format!(":{}", l)).unwrap_or_default();
+                output.push_str(&format!("  [{}] {} {}\n", issue.rule_id, issue.message, line.dimmed()));
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+fn compared_file(report: &Report, other: &Report) -> ComparedFile {
+    let other_fingerprints: HashSet<String> = other.issues.iter().map(Issue::fingerprint).collect();
+    ComparedFile {
+        file_path: report.file_path.clone(),
+        scores: report.scores.clone(),
+        issue_count: report.issues.len(),
+        unique_issues: report
+            .issues
+            .iter()
+            .filter(|issue| !other_fingerprints.contains(&issue.fingerprint()))
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Change from A to B
+fn delta(a: u8, b: u8) -> String {
+    match b.cmp(&a) {
+        std::cmp::Ordering::Greater => format!("+{}", b - a).green().to_string(),
+        std::cmp::Ordering::Less => format!("-{}", a - b).red().to_string(),
+        std::cmp::Ordering::Equal => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use std::path::Path;
+
+    fn fixture(name: &str) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+    }
+
+    #[test]
+    fn test_good_dockerfile_beats_bad() {
+        let analyzer = Analyzer::new();
+        let bad = analyzer.analyze(fixture("bad_dockerfile")).unwrap();
+        let good = analyzer.analyze(fixture("good_dockerfile")).unwrap();
+        let comparison = Comparison::new(&bad, &good);
+
+        assert!(comparison.right.scores.overall.current > comparison.left.scores.overall.current);
+        assert!(comparison.right.issue_count < comparison.left.issue_count);
+        assert!(comparison.left.unique_issues.iter().any(|i| i.rule_id == "DF001"));
+        assert!(!comparison.right.unique_issues.iter().any(|i| i.rule_id == "DF001"));
+
+        let json: serde_json::Value = serde_json::from_str(&comparison.to_json()).unwrap();
+        assert_eq!(json["left"]["issue_count"], comparison.left.issue_count);
+    }
+
+    #[test]
+    fn test_shared_issues_are_not_unique() {
+        let analyzer = Analyzer::new();
+        let a = analyzer.analyze_dockerfile_str("FROM ubuntu:latest\n").unwrap();
+        let b = analyzer.analyze_dockerfile_str("FROM ubuntu:latest\n").unwrap();
+        let comparison = Comparison::new(&a, &b);
+        assert!(comparison.left.unique_issues.is_empty());
+        assert!(comparison.right.unique_issues.is_empty());
+    }
+}
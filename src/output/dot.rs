@@ -0,0 +1,100 @@
+use crate::parser::ComposeFile;
+use std::collections::BTreeSet;
+
+/// Render compose `depends_on` edges and shared networks as a Graphviz DOT graph.
+///
+/// Services are solid boxes with an arrow to each dependency; networks are dashed
+/// nodes linked to every service that joins them. Render with `dot -Tsvg`.
+pub fn compose_to_dot(compose: &ComposeFile) -> String {
+    let mut services: Vec<_> = compose.services.iter().flatten().collect();
+    services.sort_by_key(|(name, _)| name.as_str());
+
+    let mut output = String::from("digraph compose {\n");
+    output.push_str("  rankdir=LR;\n");
+    output.push_str("  node [shape=box];\n");
+
+    for (name, _) in &services {
+        output.push_str(&format!("  {};\n", quote(name)));
+    }
+
+    let networks: BTreeSet<&str> = services.iter().flat_map(|(_, s)| s.network_names()).collect();
+    for network in &networks {
+        output.push_str(&format!(
+            "  {} [label={}, shape=ellipse, style=dashed];\n",
+            quote(&network_id(network)),
+            quote(network)
+        ));
+    }
+
+    for (name, service) in &services {
+        for dependency in service.dependencies() {
+            output.push_str(&format!("  {} -> {};\n", quote(name), quote(dependency)));
+        }
+    }
+
+    for (name, service) in &services {
+        for network in service.network_names() {
+            output.push_str(&format!(
+                "  {} -> {} [style=dashed, arrowhead=none];\n",
+                quote(name),
+                quote(&network_id(network))
+            ));
+        }
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+/// Node id for a network, kept apart from service ids of the same name
+fn network_id(network: &str) -> String {
+    format!("network:{}", network)
+}
+
+fn quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_dot_has_edge_per_dependency() {
+        let content = r#"
+services:
+  web:
+    image: nginx:1.25
+    depends_on: [api]
+    networks: [frontend]
+  api:
+    image: api:1.0
+    depends_on:
+      db:
+        condition: service_healthy
+      cache:
+        condition: service_started
+    networks: [frontend, backend]
+  db:
+    image: postgres:16
+    networks: [backend]
+  cache:
+    image: redis:7
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let dot = compose_to_dot(&compose);
+
+        assert!(dot.starts_with("digraph compose {"));
+        assert!(dot.contains("\"web\" -> \"api\";"));
+        assert!(dot.contains("\"api\" -> \"db\";"));
+        assert!(dot.contains("\"api\" -> \"cache\";"));
+        assert_eq!(dot.matches(" -> ").count(), 3 + 4);
+        assert!(dot.contains("\"db\" -> \"network:backend\" [style=dashed, arrowhead=none];"));
+    }
+
+    #[test]
+    fn test_quotes_special_characters() {
+        assert_eq!(quote("my\"svc"), "\"my\\\"svc\"");
+    }
+}
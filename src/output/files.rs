@@ -0,0 +1,36 @@
+/// File extension used when a report in `format` is written to disk
+pub fn format_extension(format: &str) -> &str {
+    match format.to_ascii_lowercase().as_str() {
+        "terminal" | "github" => "txt",
+        "json" => "json",
+        "sarif" => "sarif",
+        _ => format,
+    }
+}
+
+/// File name for the report of `file_path`, derived from the path so reports
+/// for different inputs do not collide (e.g. `services/api/Dockerfile` becomes
+/// `services_api_Dockerfile.json`)
+pub fn report_file_name(file_path: &str, format: &str) -> String {
+    let trimmed = file_path.trim_start_matches("./").trim_start_matches(['/', '\\']);
+    let sanitized: String = trimmed
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    let sanitized = sanitized.trim_matches('.');
+    let stem = if sanitized.is_empty() { "report" } else { sanitized };
+    format!("{}.{}", stem, format_extension(format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_file_name_from_path() {
+        assert_eq!(report_file_name("services/api/Dockerfile", "json"), "services_api_Dockerfile.json");
+        assert_eq!(report_file_name("./docker-compose.yml", "terminal"), "docker-compose.yml.txt");
+        assert_eq!(report_file_name("/srv/app dir/Dockerfile", "json"), "srv_app_dir_Dockerfile.json");
+        assert_eq!(report_file_name("..", "json"), "report.json");
+    }
+}
@@ -0,0 +1,100 @@
+use super::OutputFormatter;
+use crate::analyzer::Report;
+use crate::rules::{Issue, Severity};
+
+/// GitHub Actions workflow commands, shown as inline annotations on pull requests
+#[derive(Default)]
+pub struct GithubOutput;
+
+impl GithubOutput {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+fn command(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "error",
+        Severity::Warning => "warning",
+        Severity::Suggestion => "notice",
+    }
+}
+
+/// Escape a workflow command message
+fn escape_data(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escape a workflow command property value, which also ends at `,` and `:`
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+fn annotation(issue: &Issue, file_path: &str) -> String {
+    // Annotations need a line; issues about the whole file go on the first one
+    let line = issue.line_number.filter(|l| *l > 0).unwrap_or(1);
+    format!(
+        "::{} file={},line={}::{}: {}",
+        command(issue.severity),
+        escape_property(file_path),
+        line,
+        issue.rule_id,
+        escape_data(&issue.message)
+    )
+}
+
+impl OutputFormatter for GithubOutput {
+    fn format(&self, report: &Report) -> String {
+        let file_path = report.file_path.trim_start_matches("./").replace('\\', "/");
+        report
+            .issues
+            .iter()
+            .map(|issue| annotation(issue, &file_path))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::calculate_scores;
+    use crate::rules::MessageParams;
+
+    fn issue(rule_id: &str, severity: Severity, line_number: Option<usize>, message: &str) -> Issue {
+        Issue {
+            rule_id: rule_id.to_string(),
+            rule_name: String::new(),
+            severity,
+            line_number,
+            message: message.to_string(),
+            fix_suggestion: None,
+            impact: None,
+            params: MessageParams::new(),
+        }
+    }
+
+    #[test]
+    fn test_annotation_per_severity() {
+        let issues = vec![
+            issue("DF001", Severity::Critical, Some(1), "Base image uses 'latest' tag"),
+            issue("DF003", Severity::Warning, Some(4), "apt-get cache not cleaned"),
+            issue("DF005", Severity::Suggestion, None, "No HEALTHCHECK instruction"),
+        ];
+        let scores = calculate_scores(&issues);
+        let report = Report::new("./services/api/Dockerfile", issues, scores);
+
+        assert_eq!(
+            GithubOutput::new().format(&report),
+            "::error file=services/api/Dockerfile,line=1::DF001: Base image uses 'latest' tag\n\
+             ::warning file=services/api/Dockerfile,line=4::DF003: apt-get cache not cleaned\n\
+             ::notice file=services/api/Dockerfile,line=1::DF005: No HEALTHCHECK instruction"
+        );
+    }
+
+    #[test]
+    fn test_escapes_properties_and_message() {
+        let line = annotation(&issue("DC001", Severity::Warning, Some(3), "50% done\nnext"), "ci.yml:jobs,build");
+        assert_eq!(line, "::warning file=ci.yml%3Ajobs%2Cbuild,line=3::DC001: 50%25 done%0Anext");
+    }
+}
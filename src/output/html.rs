@@ -0,0 +1,202 @@
+use super::OutputFormatter;
+use crate::analyzer::Report;
+use crate::rules::{Issue, Severity};
+use crate::scoring::Score;
+
+const STYLE: &str = "\
+body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; margin: 2rem auto; max-width: 960px; color: #24292f; }
+h1 { margin-bottom: 0.25rem; }
+.file { color: #0969da; font-family: monospace; }
+table.scores { border-collapse: collapse; margin: 1rem 0 2rem; }
+table.scores th, table.scores td { border: 1px solid #d0d7de; padding: 0.4rem 0.8rem; text-align: left; }
+table.scores th { background: #f6f8fa; }
+.good { color: #1a7f37; font-weight: bold; }
+.fair { color: #9a6700; font-weight: bold; }
+.poor { color: #cf222e; font-weight: bold; }
+details { margin-bottom: 1rem; border: 1px solid #d0d7de; border-radius: 6px; padding: 0.5rem 1rem; }
+summary { cursor: pointer; font-weight: bold; font-size: 1.1rem; }
+.issue { border-top: 1px solid #eaeef2; padding: 0.6rem 0; }
+.badge { display: inline-block; padding: 0.1rem 0.5rem; border-radius: 1rem; color: #fff; font-size: 0.8rem; font-family: monospace; }
+.critical { background: #cf222e; }
+.warning { background: #bf8700; }
+.suggestion { background: #0969da; }
+.line { color: #57606a; font-size: 0.9rem; }
+.fix { color: #1a7f37; }
+.clean { color: #1a7f37; font-weight: bold; }
+";
+
+/// Standalone HTML document with the score table and issues grouped by severity
+#[derive(Default)]
+pub struct HtmlOutput {
+    show_impact: bool,
+}
+
+impl HtmlOutput {
+    pub fn new(show_impact: bool) -> Self {
+        Self { show_impact }
+    }
+}
+
+impl OutputFormatter for HtmlOutput {
+    fn format(&self, report: &Report) -> String {
+        let mut html = String::new();
+        let file_path = escape(&report.file_path);
+
+        html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str(&format!("<title>Docker Review Report - {}</title>\n", file_path));
+        html.push_str(&format!("<style>\n{}</style>\n</head>\n<body>\n", STYLE));
+        html.push_str("<h1>Docker Review Report</h1>\n");
+        html.push_str(&format!("<p>File: <span class=\"file\">{}</span></p>\n", file_path));
+
+        // Scores
+        html.push_str("<table class=\"scores\">\n<tr><th>Category</th><th>Score</th><th>Potential</th></tr>\n");
+        for (label, score) in [
+            ("Security", &report.scores.security),
+            ("Performance", &report.scores.performance),
+            ("Maintainability", &report.scores.maintainability),
+            ("Overall", &report.scores.overall),
+        ] {
+            html.push_str(&format_score_row(label, score));
+        }
+        html.push_str("</table>\n");
+
+        if report.issues.is_empty() {
+            html.push_str("<p class=\"clean\">No issues found! Great job!</p>\n");
+        }
+
+        // Most severe group first
+        for severity in Severity::all().iter().rev() {
+            let issues: Vec<_> = report.issues.iter().filter(|i| i.severity == *severity).collect();
+            if issues.is_empty() {
+                continue;
+            }
+            html.push_str(&format!(
+                "<details open>\n<summary>{} ({})</summary>\n",
+                severity_heading(*severity),
+                issues.len()
+            ));
+            for issue in issues {
+                html.push_str(&self.format_issue(issue));
+            }
+            html.push_str("</details>\n");
+        }
+
+        html.push_str("</body>\n</html>");
+        html
+    }
+}
+
+impl HtmlOutput {
+    fn format_issue(&self, issue: &Issue) -> String {
+        let mut s = String::from("<div class=\"issue\">\n");
+        s.push_str(&format!(
+            "<span class=\"badge {}\">{}</span> <strong>{}</strong>",
+            severity_class(issue.severity),
+            escape(&issue.rule_id),
+            escape(&issue.rule_name)
+        ));
+        if let Some(line) = issue.line_number {
+            s.push_str(&format!(" <span class=\"line\">line {}</span>", line));
+        }
+        s.push_str(&format!("\n<p>{}</p>\n", escape(&issue.message)));
+
+        if let Some(fix) = &issue.fix_suggestion {
+            s.push_str(&format!("<p class=\"fix\">Fix: {}</p>\n", escape(fix)));
+        }
+
+        if self.show_impact {
+            if let Some(impact) = &issue.impact {
+                let estimates = [
+                    ("Build time", &impact.build_time_improvement),
+                    ("Image size", &impact.image_size_reduction),
+                    ("Security", &impact.security_improvement),
+                    ("Reliability", &impact.reliability_improvement),
+                ];
+                s.push_str("<ul>\n");
+                for (label, value) in estimates {
+                    if let Some(value) = value {
+                        s.push_str(&format!("<li>{}: {}</li>\n", label, escape(value)));
+                    }
+                }
+                s.push_str("</ul>\n");
+            }
+        }
+
+        s.push_str("</div>\n");
+        s
+    }
+}
+
+fn format_score_row(label: &str, score: &Score) -> String {
+    // Same thresholds as the terminal score bars
+    let class = if score.current >= 8 {
+        "good"
+    } else if score.current >= 5 {
+        "fair"
+    } else {
+        "poor"
+    };
+    format!(
+        "<tr><td>{}</td><td class=\"{}\">{}/10</td><td>{}/10</td></tr>\n",
+        label, class, score.current, score.potential
+    )
+}
+
+fn severity_heading(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "Critical Issues",
+        Severity::Warning => "Warnings",
+        Severity::Suggestion => "Suggestions",
+    }
+}
+
+fn severity_class(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "critical",
+        Severity::Warning => "warning",
+        Severity::Suggestion => "suggestion",
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+
+    #[test]
+    fn test_html_report_contains_path_and_rules() {
+        let report = Analyzer::new()
+            .analyze_dockerfile_str("FROM ubuntu:latest\nRUN apt-get update\nRUN apt-get install -y curl\n")
+            .unwrap();
+        assert!(!report.issues.is_empty());
+
+        let html = HtmlOutput::new(true).format(&report);
+        assert!(html.starts_with("<!DOCTYPE html>\n<html"));
+        assert!(html.trim_end().ends_with("</html>"));
+        assert_eq!(html.matches("<html").count(), 1);
+        assert!(html.contains(&report.file_path));
+        for issue in &report.issues {
+            assert!(html.contains(&issue.rule_id), "missing {}", issue.rule_id);
+        }
+    }
+
+    #[test]
+    fn test_escapes_markup() {
+        assert_eq!(escape("<b>'x' & \"y\"</b>"), "&lt;b&gt;&#39;x&#39; &amp; &quot;y&quot;&lt;/b&gt;");
+    }
+}
@@ -1,12 +1,138 @@
 use super::OutputFormatter;
 use crate::analyzer::Report;
+use crate::rules::Severity;
+use crate::scoring::{category_contributions, Category};
+use serde::Serialize;
 
-pub struct JsonOutput;
+#[derive(Default)]
+pub struct JsonOutput {
+    detailed: bool,
+}
+
+impl JsonOutput {
+    pub fn new(detailed: bool) -> Self {
+        Self { detailed }
+    }
+}
+
+/// A label entry in the legend (machine id + display label)
+#[derive(Debug, Serialize)]
+pub struct LegendEntry {
+    pub id: String,
+    pub label: String,
+}
+
+/// Severity levels and categories known to this version of the tool
+#[derive(Debug, Serialize)]
+pub struct Legend {
+    pub severities: Vec<LegendEntry>,
+    pub categories: Vec<LegendEntry>,
+}
+
+impl Legend {
+    pub fn current() -> Self {
+        Self {
+            severities: Severity::all().iter().map(legend_entry).collect(),
+            categories: Category::all().iter().map(legend_entry).collect(),
+        }
+    }
+}
+
+fn legend_entry<T: Serialize + std::fmt::Display>(value: &T) -> LegendEntry {
+    let id = serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_default();
+    LegendEntry {
+        id,
+        label: value.to_string(),
+    }
+}
+
+#[derive(Serialize)]
+struct DetailedReport<'a> {
+    #[serde(flatten)]
+    report: &'a Report,
+    legend: Legend,
+}
 
 impl OutputFormatter for JsonOutput {
     fn format(&self, report: &Report) -> String {
-        serde_json::to_string_pretty(report).unwrap_or_else(|e| {
+        let result = if self.detailed {
+            let mut report = report.clone();
+            report.scores.contributions = category_contributions(&report.issues);
+            serde_json::to_string_pretty(&DetailedReport {
+                report: &report,
+                legend: Legend::current(),
+            })
+        } else {
+            serde_json::to_string_pretty(report)
+        };
+
+        result.unwrap_or_else(|e| {
             format!("{{\"error\": \"Failed to serialize report: {}\"}}", e)
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::calculate_scores;
+
+    fn empty_report() -> Report {
+        Report {
+            file_path: "Dockerfile".to_string(),
+            suppressed: Vec::new(),
+            issues: vec![],
+            scores: calculate_scores(&[]),
+        }
+    }
+
+    #[test]
+    fn test_legend_lists_all_severities() {
+        let output = JsonOutput::new(true).format(&empty_report());
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let severities: Vec<&str> = value["legend"]["severities"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(severities, vec!["suggestion", "warning", "critical"]);
+        assert_eq!(value["legend"]["categories"].as_array().unwrap().len(), Category::all().len());
+    }
+
+    #[test]
+    fn test_detailed_json_lists_score_contributions() {
+        let mut report = empty_report();
+        report.issues.push(crate::rules::Issue {
+            rule_id: "DF002".to_string(),
+            rule_name: "Running as root".to_string(),
+            severity: Severity::Warning,
+            line_number: Some(1),
+            message: "No USER instruction".to_string(),
+            fix_suggestion: None,
+            impact: None,
+            params: Default::default(),
+        });
+        report.scores = calculate_scores(&report.issues);
+
+        let output = JsonOutput::new(true).format(&report);
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(value["scores"]["contributions"]["security"], serde_json::json!(["DF002"]));
+        assert_eq!(value["scores"]["contributions"]["performance"], serde_json::json!([]));
+
+        let output = JsonOutput::default().format(&report);
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert!(value["scores"].get("contributions").is_none());
+    }
+
+    #[test]
+    fn test_plain_json_has_no_legend() {
+        let output = JsonOutput::default().format(&empty_report());
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert!(value.get("legend").is_none());
+        assert_eq!(value["file_path"], "Dockerfile");
+    }
+}
@@ -1,8 +1,26 @@
 mod terminal;
 mod json;
+mod html;
+mod pdf;
+mod sarif;
+mod github;
+mod registry;
+mod dot;
+mod compare;
+mod files;
+mod annotate;
 
-pub use terminal::TerminalOutput;
+pub use terminal::{stdout_supports_hyperlinks, TerminalOutput};
 pub use json::JsonOutput;
+pub use html::HtmlOutput;
+pub use pdf::PdfOutput;
+pub use sarif::SarifOutput;
+pub use github::GithubOutput;
+pub use dot::compose_to_dot;
+pub use compare::{ComparedFile, Comparison};
+pub use files::{format_extension, report_file_name};
+pub use annotate::{annotate, ANNOTATION_PREFIX};
+pub use registry::{get_formatter, get_formatter_with_options, register_formatter, formatter_names, FormatOptions, FormatterFactory};
 
 use crate::analyzer::Report;
 
@@ -0,0 +1,259 @@
+use super::OutputFormatter;
+use crate::analyzer::Report;
+use crate::rules::{Issue, Severity};
+
+// A4 in points
+const PAGE_WIDTH: u32 = 595;
+const PAGE_HEIGHT: u32 = 842;
+const MARGIN: u32 = 50;
+const WRAP_COLUMNS: usize = 95;
+
+/// PDF report: score summary on the first page, issues paginated after it.
+///
+/// Uses the standard Helvetica fonts, so no fonts are embedded; characters
+/// outside ASCII are replaced with '?'. The output is binary-safe ASCII but
+/// should be written to a file rather than a terminal.
+#[derive(Default)]
+pub struct PdfOutput;
+
+impl PdfOutput {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Style {
+    Title,
+    Heading,
+    Body,
+}
+
+impl Style {
+    fn font(self) -> &'static str {
+        match self {
+            Style::Title | Style::Heading => "F2",
+            Style::Body => "F1",
+        }
+    }
+
+    fn size(self) -> u32 {
+        match self {
+            Style::Title => 18,
+            Style::Heading => 12,
+            Style::Body => 10,
+        }
+    }
+
+    fn leading(self) -> u32 {
+        self.size() + 6
+    }
+}
+
+/// Lines laid out top to bottom on pages
+struct Layout {
+    pages: Vec<Vec<(Style, u32, String)>>,
+    y: u32,
+}
+
+impl Layout {
+    fn new() -> Self {
+        Self { pages: vec![Vec::new()], y: PAGE_HEIGHT - MARGIN }
+    }
+
+    fn new_page(&mut self) {
+        self.pages.push(Vec::new());
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    fn line(&mut self, style: Style, text: &str) {
+        for chunk in wrap(text, WRAP_COLUMNS) {
+            if self.y < MARGIN + style.leading() {
+                self.new_page();
+            }
+            self.y -= style.leading();
+            let y = self.y;
+            self.pages.last_mut().expect("layout always has a page").push((style, y, chunk));
+        }
+    }
+
+    fn gap(&mut self) {
+        self.y = self.y.saturating_sub(Style::Body.leading() / 2);
+    }
+}
+
+impl OutputFormatter for PdfOutput {
+    fn format(&self, report: &Report) -> String {
+        let mut layout = Layout::new();
+
+        layout.line(Style::Title, "Docker Review Report");
+        layout.line(Style::Body, &format!("File: {}", report.file_path));
+        layout.gap();
+
+        layout.line(Style::Heading, "Scores");
+        for (label, score) in [
+            ("Security", &report.scores.security),
+            ("Performance", &report.scores.performance),
+            ("Maintainability", &report.scores.maintainability),
+            ("Overall", &report.scores.overall),
+        ] {
+            layout.line(Style::Body, &format!("{}: {}/10 (potential {}/10)", label, score.current, score.potential));
+        }
+        layout.gap();
+
+        let count = |severity| report.issues.iter().filter(|i| i.severity == severity).count();
+        layout.line(Style::Heading, "Issues Summary");
+        layout.line(Style::Body, &format!(
+            "{} Critical, {} Warnings, {} Suggestions",
+            count(Severity::Critical),
+            count(Severity::Warning),
+            count(Severity::Suggestion)
+        ));
+
+        if !report.issues.is_empty() {
+            layout.new_page();
+            for severity in Severity::all().iter().rev() {
+                let issues: Vec<&Issue> = report.issues.iter().filter(|i| i.severity == *severity).collect();
+                if issues.is_empty() {
+                    continue;
+                }
+                layout.line(Style::Heading, &format!("{} ({})", severity, issues.len()));
+                for issue in issues {
+                    let line = issue.line_number.map(|l| format!(" (line {})", l)).unwrap_or_default();
+                    layout.line(Style::Body, &format!("[{}] {}{}", issue.rule_id, issue.rule_name, line));
+                    layout.line(Style::Body, &format!("    {}", issue.message));
+                    if let Some(fix) = &issue.fix_suggestion {
+                        layout.line(Style::Body, &format!("    Fix: {}", fix));
+                    }
+                    layout.gap();
+                }
+            }
+        }
+
+        render(&layout.pages)
+    }
+}
+
+/// Serialize the pages as a PDF document with a cross-reference table
+fn render(pages: &[Vec<(Style, u32, String)>]) -> String {
+    // Objects 1-4 are the catalog, page tree and fonts; each page adds a page and a content object
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            (0..pages.len()).map(|i| format!("{} 0 R", 5 + 2 * i)).collect::<Vec<_>>().join(" "),
+            pages.len()
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>".to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>".to_string(),
+    ];
+
+    for (i, lines) in pages.iter().enumerate() {
+        let mut stream = String::new();
+        for (style, y, text) in lines {
+            stream.push_str(&format!(
+                "BT /{} {} Tf {} {} Td ({}) Tj ET\n",
+                style.font(),
+                style.size(),
+                MARGIN,
+                y,
+                escape(text)
+            ));
+        }
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+            PAGE_WIDTH,
+            PAGE_HEIGHT,
+            6 + 2 * i
+        ));
+        objects.push(format!("<< /Length {} >>\nstream\n{}endstream", stream.len(), stream));
+    }
+
+    let mut pdf = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.push_str(&format!("{} 0 obj\n{}\nendobj\n", i + 1, object));
+    }
+
+    let xref_offset = pdf.len();
+    pdf.push_str(&format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1));
+    for offset in offsets {
+        pdf.push_str(&format!("{:010} 00000 n \n", offset));
+    }
+    pdf.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF",
+        objects.len() + 1,
+        xref_offset
+    ));
+    pdf
+}
+
+/// Escape a PDF string literal, keeping the document pure ASCII
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '(' | ')' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '→' => escaped.push_str("->"),
+            c if c.is_ascii() && !c.is_ascii_control() => escaped.push(c),
+            _ => escaped.push('?'),
+        }
+    }
+    escaped
+}
+
+/// Break text into lines of at most `width` characters at spaces where possible
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let indent: String = text.chars().take_while(|c| *c == ' ').collect();
+    let mut lines = Vec::new();
+    let mut current = indent.clone();
+
+    for word in text.split_whitespace() {
+        let current_len = current.chars().count();
+        if current_len > indent.len() && current_len + 1 + word.chars().count() > width {
+            lines.push(std::mem::replace(&mut current, indent.clone()));
+        }
+        if current.chars().count() > indent.len() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    lines.push(current);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+
+    #[test]
+    fn test_pdf_structure() {
+        let report = Analyzer::new()
+            .analyze_dockerfile_str("FROM ubuntu:latest\nENV API_KEY=secret123\nRUN apt-get update\n")
+            .unwrap();
+        let pdf = PdfOutput::new().format(&report);
+
+        assert!(pdf.starts_with("%PDF-1.4\n"));
+        assert!(pdf.ends_with("%%EOF"));
+        assert!(pdf.is_ascii());
+        // Summary page plus at least one issues page
+        assert!(pdf.contains("/Count 2") || pdf.contains("/Count 3"));
+        assert!(pdf.contains("[DF001]"));
+
+        // startxref must point at the xref table
+        let start: usize = pdf.rsplit("startxref\n").next().unwrap().lines().next().unwrap().parse().unwrap();
+        assert!(pdf[start..].starts_with("xref\n"));
+    }
+
+    #[test]
+    fn test_escape_and_wrap() {
+        assert_eq!(escape("a (b) \\ é"), "a \\(b\\) \\\\ ?");
+        let lines = wrap("    one two three four", 12);
+        assert_eq!(lines, vec!["    one two", "    three", "    four"]);
+    }
+}
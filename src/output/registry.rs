@@ -0,0 +1,89 @@
+use super::{GithubOutput, HtmlOutput, JsonOutput, OutputFormatter, PdfOutput, SarifOutput, TerminalOutput};
+use once_cell::sync::Lazy;
+use std::sync::RwLock;
+
+/// Options passed to formatter constructors
+#[derive(Debug, Clone, Default)]
+pub struct FormatOptions {
+    pub verbose: bool,
+    pub show_impact: bool,
+    pub detailed: bool,
+    pub next_steps: bool,
+    pub hyperlinks: bool,
+    /// Plain ASCII instead of emoji and block characters
+    pub ascii: bool,
+}
+
+/// Constructs a formatter from the shared options
+pub type FormatterFactory = fn(&FormatOptions) -> Box<dyn OutputFormatter>;
+
+/// All registered output formats, keyed by name
+static FORMATTERS: Lazy<RwLock<Vec<(&'static str, FormatterFactory)>>> = Lazy::new(|| {
+    RwLock::new(vec![
+        ("terminal", |opts| Box::new(TerminalOutput::new(opts.verbose, opts.show_impact).with_next_steps(opts.next_steps).with_hyperlinks(opts.hyperlinks).with_ascii(opts.ascii))),
+        ("json", |opts| Box::new(JsonOutput::new(opts.detailed))),
+        ("html", |opts| Box::new(HtmlOutput::new(opts.show_impact))),
+        ("pdf", |_| Box::new(PdfOutput::new())),
+        ("sarif", |_| Box::new(SarifOutput::new())),
+        ("github", |_| Box::new(GithubOutput::new())),
+    ])
+});
+
+/// Register an output format, replacing any existing format with the same name
+pub fn register_formatter(name: &'static str, factory: FormatterFactory) {
+    let mut formatters = FORMATTERS.write().unwrap_or_else(|e| e.into_inner());
+    formatters.retain(|(existing, _)| *existing != name);
+    formatters.push((name, factory));
+}
+
+/// Look up a formatter by name using default options
+pub fn get_formatter(name: &str) -> Option<Box<dyn OutputFormatter>> {
+    get_formatter_with_options(name, &FormatOptions::default())
+}
+
+/// Look up a formatter by name
+pub fn get_formatter_with_options(name: &str, options: &FormatOptions) -> Option<Box<dyn OutputFormatter>> {
+    let formatters = FORMATTERS.read().unwrap_or_else(|e| e.into_inner());
+    formatters
+        .iter()
+        .find(|(registered, _)| registered.eq_ignore_ascii_case(name))
+        .map(|(_, factory)| factory(options))
+}
+
+/// Names of all registered output formats
+pub fn formatter_names() -> Vec<&'static str> {
+    let formatters = FORMATTERS.read().unwrap_or_else(|e| e.into_inner());
+    formatters.iter().map(|(name, _)| *name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Report;
+
+    #[test]
+    fn test_builtin_formats_discoverable() {
+        for name in ["terminal", "json", "html", "pdf", "sarif", "github"] {
+            assert!(formatter_names().contains(&name));
+            assert!(get_formatter(name).is_some(), "format '{}' not found", name);
+        }
+    }
+
+    #[test]
+    fn test_unknown_format() {
+        assert!(get_formatter("does-not-exist").is_none());
+    }
+
+    #[test]
+    fn test_register_custom_formatter() {
+        struct CountOutput;
+        impl OutputFormatter for CountOutput {
+            fn format(&self, report: &Report) -> String {
+                report.issues.len().to_string()
+            }
+        }
+
+        register_formatter("count", |_| Box::new(CountOutput));
+        assert!(get_formatter("count").is_some());
+    }
+}
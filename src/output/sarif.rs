@@ -0,0 +1,134 @@
+use super::OutputFormatter;
+use crate::analyzer::Report;
+use crate::rules::{get_all_rules, Issue, Severity};
+use serde_json::{json, Value};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const INFORMATION_URI: &str = env!("CARGO_PKG_REPOSITORY");
+
+/// SARIF 2.1.0 log for GitHub code scanning and other security dashboards
+#[derive(Default)]
+pub struct SarifOutput;
+
+impl SarifOutput {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// SARIF result level for a severity
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "error",
+        Severity::Warning => "warning",
+        Severity::Suggestion => "note",
+    }
+}
+
+fn result(issue: &Issue, file_path: &str, rule_index: Option<usize>) -> Value {
+    let mut physical_location = json!({
+        "artifactLocation": { "uri": file_path },
+    });
+    // SARIF lines are 1-based; issues without a line point at the whole file
+    if let Some(line) = issue.line_number.filter(|l| *l > 0) {
+        physical_location["region"] = json!({ "startLine": line });
+    }
+
+    let mut result = json!({
+        "ruleId": issue.rule_id,
+        "level": level(issue.severity),
+        "message": { "text": issue.message },
+        "locations": [{ "physicalLocation": physical_location }],
+    });
+    if let Some(index) = rule_index {
+        result["ruleIndex"] = json!(index);
+    }
+    result
+}
+
+impl OutputFormatter for SarifOutput {
+    fn format(&self, report: &Report) -> String {
+        let rules = get_all_rules();
+        let rule_descriptors: Vec<Value> = rules
+            .iter()
+            .map(|rule| {
+                json!({
+                    "id": rule.id(),
+                    "name": rule.name(),
+                    "shortDescription": { "text": rule.description() },
+                    "fullDescription": { "text": rule.rationale() },
+                    "defaultConfiguration": { "level": level(rule.severity()) },
+                })
+            })
+            .collect();
+
+        let file_path = report.file_path.trim_start_matches("./").replace('\\', "/");
+        let rule_index = |issue: &Issue| rules.iter().position(|r| r.id() == issue.rule_id);
+        let mut results: Vec<Value> = report
+            .issues
+            .iter()
+            .map(|issue| result(issue, &file_path, rule_index(issue)))
+            .collect();
+        // Baselined issues stay in the log so scanning platforms record them as suppressed
+        results.extend(report.suppressed.iter().map(|issue| {
+            let mut result = result(issue, &file_path, rule_index(issue));
+            result["suppressions"] = json!([{
+                "kind": "external",
+                "justification": "Recorded in the docker-review baseline",
+            }]);
+            result
+        }));
+
+        let log = json!({
+            "$schema": SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "docker-review",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": INFORMATION_URI,
+                        "rules": rule_descriptors,
+                    }
+                },
+                "results": results,
+            }],
+        });
+
+        serde_json::to_string_pretty(&log).unwrap_or_else(|e| {
+            format!("{{\"error\": \"Failed to serialize report: {}\"}}", e)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+
+    #[test]
+    fn test_sarif_round_trip() {
+        let report = Analyzer::new()
+            .analyze_dockerfile_str("FROM ubuntu:latest\nENV API_KEY=secret123\nRUN apt-get update\n")
+            .unwrap();
+        let output = SarifOutput::new().format(&report);
+        let log: Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(log["$schema"], SARIF_SCHEMA);
+
+        let run = &log["runs"][0];
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), report.issues.len());
+        for (result, issue) in results.iter().zip(&report.issues) {
+            assert_eq!(result["ruleId"], issue.rule_id.as_str());
+            assert_eq!(result["message"]["text"], issue.message.as_str());
+            let index = result["ruleIndex"].as_u64().unwrap() as usize;
+            assert_eq!(run["tool"]["driver"]["rules"][index]["id"], issue.rule_id.as_str());
+        }
+
+        let df001 = results.iter().find(|r| r["ruleId"] == "DF001").unwrap();
+        assert_eq!(df001["level"], "error");
+        assert_eq!(df001["locations"][0]["physicalLocation"]["region"]["startLine"], 1);
+    }
+}
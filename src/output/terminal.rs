@@ -1,36 +1,126 @@
 use super::OutputFormatter;
 use crate::analyzer::Report;
-use crate::rules::Severity;
+use crate::rules::{get_rule_by_id, Issue, Severity};
+use crate::scoring::overall_gain_if_fixed;
 use colored::Colorize;
+use std::io::IsTerminal;
 
 pub struct TerminalOutput {
     verbose: bool,
     show_impact: bool,
+    next_steps: bool,
+    hyperlinks: bool,
+    symbols: &'static Symbols,
+}
+
+/// Decorations used in the report; `ASCII` replaces emoji and block characters
+struct Symbols {
+    scores: &'static str,
+    no_issues: &'static str,
+    summary: &'static str,
+    impact: &'static str,
+    next_steps: &'static str,
+    bullet: &'static str,
+    arrow: &'static str,
+    bar_filled: &'static str,
+    bar_empty: &'static str,
+    ascii: bool,
+}
+
+const UNICODE: Symbols = Symbols {
+    scores: "📊 Scores",
+    no_issues: "✅ No issues found! Great job!",
+    summary: "📋 Issues Summary",
+    impact: "📈 Estimated Impact",
+    next_steps: "🎯 Next Steps",
+    bullet: "•",
+    arrow: "→",
+    bar_filled: "█",
+    bar_empty: "░",
+    ascii: false,
+};
+
+const ASCII: Symbols = Symbols {
+    scores: "[SCORES]",
+    no_issues: "[OK] No issues found! Great job!",
+    summary: "[SUMMARY] Issues Summary",
+    impact: "[IMPACT] Estimated Impact",
+    next_steps: "[NEXT] Next Steps",
+    bullet: "*",
+    arrow: "->",
+    bar_filled: "#",
+    bar_empty: "-",
+    ascii: true,
+};
+
+impl Symbols {
+    fn severity(&self, severity: Severity) -> &'static str {
+        if self.ascii { severity.ascii_symbol() } else { severity.symbol() }
+    }
+
+    fn score_bar(&self, score: u8) -> String {
+        let filled = score as usize;
+        let empty = 10 - filled;
+
+        let bar = format!("{}{}", self.bar_filled.repeat(filled), self.bar_empty.repeat(empty));
+        let colored_bar = if score >= 8 {
+            bar.green()
+        } else if score >= 5 {
+            bar.yellow()
+        } else {
+            bar.red()
+        };
+
+        format!("{} {}/10", colored_bar, score)
+    }
 }
 
 impl TerminalOutput {
     pub fn new(verbose: bool, show_impact: bool) -> Self {
-        Self { verbose, show_impact }
+        Self { verbose, show_impact, next_steps: verbose, hyperlinks: false, symbols: &UNICODE }
+    }
+
+    /// Use ASCII instead of emoji and block characters (for logs, plain terminals and screen readers)
+    pub fn with_ascii(mut self, ascii: bool) -> Self {
+        self.symbols = if ascii { &ASCII } else { &UNICODE };
+        self
+    }
+
+    /// Render rule ids as OSC 8 links to the rule documentation
+    pub fn with_hyperlinks(mut self, hyperlinks: bool) -> Self {
+        self.hyperlinks = hyperlinks;
+        self
+    }
+
+    /// Show the "Next steps" footer (enabled by default in verbose mode)
+    pub fn with_next_steps(mut self, next_steps: bool) -> Self {
+        self.next_steps = self.next_steps || next_steps;
+        self
     }
 }
 
 impl OutputFormatter for TerminalOutput {
     fn format(&self, report: &Report) -> String {
         let mut output = String::new();
+        let symbols = self.symbols;
 
         // Header
         output.push_str(&format!("\n{}\n", "Docker Review Report".bold().underline()));
         output.push_str(&format!("File: {}\n\n", report.file_path.cyan()));
 
         // Scores summary
-        output.push_str(&format!("{}\n", "📊 Scores".bold()));
-        output.push_str(&format!("  Security:       {}\n", format_score_bar(report.scores.security.current)));
-        output.push_str(&format!("  Performance:    {}\n", format_score_bar(report.scores.performance.current)));
-        output.push_str(&format!("  Maintainability:{}\n", format_score_bar(report.scores.maintainability.current)));
-        output.push_str(&format!("  Overall:        {}\n\n", format_score_bar(report.scores.overall.current)));
+        output.push_str(&format!("{}\n", symbols.scores.bold()));
+        output.push_str(&format!("  Security:       {}\n", symbols.score_bar(report.scores.security.current)));
+        output.push_str(&format!("  Performance:    {}\n", symbols.score_bar(report.scores.performance.current)));
+        output.push_str(&format!("  Maintainability:{}\n", symbols.score_bar(report.scores.maintainability.current)));
+        output.push_str(&format!(
+            "  Overall:        {} (grade {})\n\n",
+            symbols.score_bar(report.scores.overall.current),
+            report.scores.grade()
+        ));
 
         if report.issues.is_empty() {
-            output.push_str(&format!("{}\n", "✅ No issues found! Great job!".green().bold()));
+            output.push_str(&format!("{}\n", symbols.no_issues.green().bold()));
             return output;
         }
 
@@ -46,7 +136,7 @@ impl OutputFormatter for TerminalOutput {
             .collect();
 
         // Summary line
-        output.push_str(&format!("{}\n", "📋 Issues Summary".bold()));
+        output.push_str(&format!("{}\n", symbols.summary.bold()));
         output.push_str(&format!(
             "  {} Critical, {} Warnings, {} Suggestions\n\n",
             critical.len().to_string().red().bold(),
@@ -56,61 +146,146 @@ impl OutputFormatter for TerminalOutput {
 
         // Critical issues
         if !critical.is_empty() {
-            output.push_str(&format!("{}\n", "✗ Critical Issues".red().bold()));
+            output.push_str(&format!("{}\n", format!("{} Critical Issues", symbols.severity(Severity::Critical)).red().bold()));
             for issue in &critical {
-                output.push_str(&format_issue(issue, self.verbose, self.show_impact));
+                output.push_str(&format_issue(issue, self.verbose, self.show_impact, self.hyperlinks));
             }
             output.push('\n');
         }
 
         // Warnings
         if !warnings.is_empty() {
-            output.push_str(&format!("{}\n", "⚠ Warnings".yellow().bold()));
+            output.push_str(&format!("{}\n", format!("{} Warnings", symbols.severity(Severity::Warning)).yellow().bold()));
             for issue in &warnings {
-                output.push_str(&format_issue(issue, self.verbose, self.show_impact));
+                output.push_str(&format_issue(issue, self.verbose, self.show_impact, self.hyperlinks));
             }
             output.push('\n');
         }
 
         // Suggestions
         if !suggestions.is_empty() {
-            output.push_str(&format!("{}\n", "ℹ Suggestions".blue().bold()));
+            output.push_str(&format!("{}\n", format!("{} Suggestions", symbols.severity(Severity::Suggestion)).blue().bold()));
             for issue in &suggestions {
-                output.push_str(&format_issue(issue, self.verbose, self.show_impact));
+                output.push_str(&format_issue(issue, self.verbose, self.show_impact, self.hyperlinks));
             }
             output.push('\n');
         }
 
         // Impact estimation
         if self.show_impact {
-            output.push_str(&format!("{}\n", "📈 Estimated Impact".bold()));
+            output.push_str(&format!("{}\n", symbols.impact.bold()));
             output.push_str("  Fixing all issues could improve:\n");
-            output.push_str(&format!("  • Security score:       {} → {}\n", 
-                report.scores.security.current, 
+            output.push_str(&format!("  {} Security score:       {} {} {}\n", 
+                symbols.bullet,
+                report.scores.security.current,
+                symbols.arrow,
                 report.scores.security.potential));
-            output.push_str(&format!("  • Performance score:    {} → {}\n", 
-                report.scores.performance.current, 
+            output.push_str(&format!("  {} Performance score:    {} {} {}\n", 
+                symbols.bullet,
+                report.scores.performance.current,
+                symbols.arrow,
                 report.scores.performance.potential));
-            output.push_str(&format!("  • Maintainability score:{} → {}\n\n", 
-                report.scores.maintainability.current, 
+            output.push_str(&format!("  {} Maintainability score:{} {} {}\n\n", 
+                symbols.bullet,
+                report.scores.maintainability.current,
+                symbols.arrow,
                 report.scores.maintainability.potential));
         }
 
+        if self.next_steps {
+            output.push_str(&format_next_steps(report, symbols));
+        }
+
         output
     }
 }
 
-fn format_issue(issue: &crate::rules::Issue, verbose: bool, show_impact: bool) -> String {
+/// Pick the rule whose fix raises the overall score the most (ties go to the
+/// more severe issue) and describe it as the next step.
+fn format_next_steps(report: &Report, symbols: &Symbols) -> String {
+    let mut best: Option<(&Issue, u8)> = None;
+    for issue in &report.issues {
+        if best.is_some_and(|(b, _)| b.rule_id == issue.rule_id) {
+            continue;
+        }
+        let gain = overall_gain_if_fixed(&report.issues, &issue.rule_id, &report.scores.config);
+        let is_better = match best {
+            None => true,
+            Some((b, best_gain)) => gain > best_gain || (gain == best_gain && issue.severity > b.severity),
+        };
+        if is_better {
+            best = Some((issue, gain));
+        }
+    }
+
+    let Some((issue, gain)) = best else {
+        return String::new();
+    };
+
+    let occurrences = report.issues.iter().filter(|i| i.rule_id == issue.rule_id).count();
+    let current = report.scores.overall.current;
+
+    let mut s = String::new();
+    s.push_str(&format!("{}\n", symbols.next_steps.bold()));
+    s.push_str(&format!("  Fix [{}] {} ({} occurrence{})\n",
+        issue.rule_id,
+        issue.rule_name,
+        occurrences,
+        if occurrences == 1 { "" } else { "s" }));
+    if let Some(fix) = &issue.fix_suggestion {
+        s.push_str(&format!("    {} {}\n", "Fix:".green(), fix));
+    }
+    s.push_str(&format!("    Overall score: {} {} {}\n", current, symbols.arrow, (current + gain).min(10)));
+    if let Some(impact) = &issue.impact {
+        let benefit = impact.security_improvement.as_ref()
+            .or(impact.image_size_reduction.as_ref())
+            .or(impact.build_time_improvement.as_ref())
+            .or(impact.reliability_improvement.as_ref());
+        if let Some(benefit) = benefit {
+            s.push_str(&format!("    Benefit: {}\n", benefit));
+        }
+    }
+    s.push('\n');
+    s
+}
+
+/// Whether stdout is a terminal known to understand OSC 8 hyperlinks
+pub fn stdout_supports_hyperlinks() -> bool {
+    if !std::io::stdout().is_terminal() {
+        return false;
+    }
+    let env = |name| std::env::var(name).unwrap_or_default();
+    let term_program = env("TERM_PROGRAM");
+    let vte_version: u32 = env("VTE_VERSION").parse().unwrap_or(0);
+
+    ["iTerm.app", "WezTerm", "vscode", "Hyper", "ghostty"].contains(&term_program.as_str())
+        || vte_version >= 5000
+        || std::env::var_os("WT_SESSION").is_some()
+        || ["xterm-kitty", "alacritty", "foot"].contains(&env("TERM").as_str())
+}
+
+/// Wrap `text` in an OSC 8 hyperlink to `url`
+fn hyperlink(text: &str, url: &str) -> String {
+    format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+}
+
+fn format_issue(issue: &crate::rules::Issue, verbose: bool, show_impact: bool, hyperlinks: bool) -> String {
     let mut s = String::new();
     
     let line_info = issue.line_number
         .map(|l| format!(":{}", l))
         .unwrap_or_default();
     
+    let badge = format!("[{}]", issue.rule_id);
     let severity_badge = match issue.severity {
-        Severity::Critical => format!("[{}]", issue.rule_id).red(),
-        Severity::Warning => format!("[{}]", issue.rule_id).yellow(),
-        Severity::Suggestion => format!("[{}]", issue.rule_id).blue(),
+        Severity::Critical => badge.red(),
+        Severity::Warning => badge.yellow(),
+        Severity::Suggestion => badge.blue(),
+    };
+    let doc_url = get_rule_by_id(&issue.rule_id).filter(|_| hyperlinks).map(|rule| rule.doc_url());
+    let severity_badge = match doc_url {
+        Some(url) => hyperlink(&severity_badge.to_string(), &url),
+        None => severity_badge.to_string(),
     };
 
     s.push_str(&format!("  {} {} {}\n", 
@@ -144,18 +319,79 @@ fn format_issue(issue: &crate::rules::Issue, verbose: bool, show_impact: bool) -
     s
 }
 
-fn format_score_bar(score: u8) -> String {
-    let filled = score as usize;
-    let empty = 10 - filled;
-    
-    let bar = format!("{}{}", "█".repeat(filled), "░".repeat(empty));
-    let colored_bar = if score >= 8 {
-        bar.green()
-    } else if score >= 5 {
-        bar.yellow()
-    } else {
-        bar.red()
-    };
-    
-    format!("{} {}/10", colored_bar, score)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Report;
+    use crate::parser::DockerfileParser;
+    use crate::rules::dockerfile::{DockerfileRule, LatestTagRule, LargeBaseImageRule};
+    use crate::scoring::calculate_scores;
+
+    #[test]
+    fn test_next_steps_references_top_issue() {
+        let parser = DockerfileParser::parse_content("FROM ubuntu\nRUN apt-get update");
+        let mut issues = LargeBaseImageRule.check(&parser, None);
+        issues.extend(LatestTagRule.check(&parser, None));
+        let report = Report {
+            file_path: "Dockerfile".to_string(),
+            suppressed: Vec::new(),
+            scores: calculate_scores(&issues),
+            issues,
+        };
+
+        let output = TerminalOutput::new(false, false).with_next_steps(true).format(&report);
+        let footer = &output[output.find("Next Steps").expect("footer missing")..];
+        assert!(footer.contains("[DF001]"));
+        assert!(!footer.contains("[DF009]"));
+    }
+
+    #[test]
+    fn test_next_steps_hidden_by_default() {
+        let report = Report {
+            file_path: "Dockerfile".to_string(),
+            suppressed: Vec::new(),
+            issues: vec![],
+            scores: calculate_scores(&[]),
+        };
+        let output = TerminalOutput::new(false, false).format(&report);
+        assert!(!output.contains("Next Steps"));
+    }
+
+    #[test]
+    fn test_hyperlinks_wrap_rule_id() {
+        let parser = DockerfileParser::parse_content("FROM ubuntu:latest");
+        let issues = LatestTagRule.check(&parser, None);
+        let report = Report {
+            file_path: "Dockerfile".to_string(),
+            suppressed: Vec::new(),
+            scores: calculate_scores(&issues),
+            issues,
+        };
+
+        let url = get_rule_by_id("DF001").unwrap().doc_url();
+        let linked = TerminalOutput::new(false, false).with_hyperlinks(true).format(&report);
+        let start = format!("\x1b]8;;{}\x1b\\", url);
+        let open = linked.find(&start).expect("link start missing");
+        let id = linked.find("[DF001]").unwrap();
+        let close = linked[id..].find("\x1b]8;;\x1b\\").expect("link end missing");
+        assert!(open < id && close > 0);
+
+        let plain = TerminalOutput::new(false, false).format(&report);
+        assert!(!plain.contains("\x1b]8;;"));
+    }
+
+    #[test]
+    fn test_ascii_output_has_no_unicode() {
+        let report = crate::analyzer::Analyzer::new()
+            .analyze_dockerfile_str("FROM ubuntu\nENV API_KEY=secret123\nRUN apt-get install -y curl\nEXPOSE 80")
+            .unwrap();
+        assert!(!report.issues.is_empty());
+
+        let output = TerminalOutput::new(true, true).with_ascii(true).format(&report);
+        assert!(output.is_ascii(), "non-ASCII in: {}", output);
+        assert!(output.contains("[SCORES]"));
+
+        let unicode = TerminalOutput::new(true, true).format(&report);
+        assert!(unicode.contains("📊 Scores"));
+    }
 }
@@ -1,7 +1,9 @@
+use super::{LimitError, ParseLimits};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct ComposeFile {
@@ -9,6 +11,38 @@ pub struct ComposeFile {
     pub services: Option<HashMap<String, Service>>,
     pub volumes: Option<HashMap<String, serde_yaml::Value>>,
     pub networks: Option<HashMap<String, serde_yaml::Value>>,
+    pub configs: Option<HashMap<String, serde_yaml::Value>>,
+    pub secrets: Option<HashMap<String, SecretDefinition>>,
+    /// Top-level `x-` extension fields (e.g. `x-common` anchors)
+    #[serde(skip)]
+    pub extensions: HashMap<String, serde_yaml::Value>,
+    /// Path the file was read from; `None` for in-memory content
+    #[serde(skip)]
+    pub source_path: Option<PathBuf>,
+}
+
+impl ComposeFile {
+    /// Whether the file name marks it for production (e.g. `docker-compose.prod.yml`)
+    pub fn is_production_file(&self) -> bool {
+        self.source_path
+            .as_ref()
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .is_some_and(|name| {
+                name.to_lowercase()
+                    .split(['.', '-', '_'])
+                    .any(|part| part == "prod" || part == "production")
+            })
+    }
+
+    /// A copy with only the services enabled when `profiles` are active
+    pub fn with_profiles(&self, profiles: &[String]) -> ComposeFile {
+        let mut compose = self.clone();
+        if let Some(services) = &mut compose.services {
+            services.retain(|_, service| service.is_enabled_for(profiles));
+        }
+        compose
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -17,8 +51,10 @@ pub struct Service {
     pub build: Option<BuildConfig>,
     pub environment: Option<Environment>,
     pub env_file: Option<EnvFile>,
-    pub ports: Option<Vec<String>>,
+    pub ports: Option<Vec<PortEntry>>,
+    pub expose: Option<Vec<serde_yaml::Value>>,
     pub volumes: Option<Vec<String>>,
+    pub volumes_from: Option<Vec<String>>,
     pub depends_on: Option<DependsOn>,
     pub restart: Option<String>,
     pub privileged: Option<bool>,
@@ -33,7 +69,236 @@ pub struct Service {
     pub cap_drop: Option<Vec<String>>,
     pub security_opt: Option<Vec<String>>,
     pub networks: Option<serde_yaml::Value>,
+    pub network_mode: Option<String>,
+    pub pid: Option<String>,
+    pub ipc: Option<String>,
     pub labels: Option<serde_yaml::Value>,
+    pub profiles: Option<Vec<String>>,
+    pub secrets: Option<Vec<ServiceSecret>>,
+}
+
+impl Service {
+    /// Parsed `ports` entries (unparseable entries are skipped)
+    pub fn port_mappings(&self) -> Vec<PortMapping> {
+        self.ports
+            .iter()
+            .flatten()
+            .filter_map(PortEntry::mapping)
+            .collect()
+    }
+
+    /// Ports listed under `expose` (numbers or strings such as "8080/tcp")
+    pub fn exposed_ports(&self) -> Vec<String> {
+        self.expose
+            .iter()
+            .flatten()
+            .filter_map(|p| match p {
+                serde_yaml::Value::Number(n) => Some(n.to_string()),
+                serde_yaml::Value::String(s) => Some(s.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Whether the service only runs under a production profile
+    pub fn has_production_profile(&self) -> bool {
+        self.profiles
+            .iter()
+            .flatten()
+            .any(|p| p.eq_ignore_ascii_case("prod") || p.eq_ignore_ascii_case("production"))
+    }
+
+    /// Whether the service runs when `profiles` are active; services without profiles always do
+    pub fn is_enabled_for(&self, profiles: &[String]) -> bool {
+        match &self.profiles {
+            Some(own) if !own.is_empty() => own.iter().any(|p| profiles.contains(p)),
+            _ => true,
+        }
+    }
+
+    /// Names of the services this service depends on
+    pub fn dependencies(&self) -> Vec<&str> {
+        self.depends_on.as_ref().map_or_else(Vec::new, |d| d.services())
+    }
+
+    /// Names of the top-level secrets the service uses
+    pub fn secret_names(&self) -> Vec<&str> {
+        self.secrets.iter().flatten().map(ServiceSecret::source).collect()
+    }
+
+    /// Networks listed under `networks` (list or map form)
+    pub fn network_names(&self) -> Vec<&str> {
+        match &self.networks {
+            Some(serde_yaml::Value::Sequence(list)) => list.iter().filter_map(|v| v.as_str()).collect(),
+            Some(serde_yaml::Value::Mapping(map)) => map.keys().filter_map(|k| k.as_str()).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// A top-level `secrets` entry
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct SecretDefinition {
+    pub file: Option<String>,
+    pub environment: Option<String>,
+    /// `true`, or `{ name: ... }` in older files
+    pub external: Option<serde_yaml::Value>,
+    pub name: Option<String>,
+}
+
+/// An entry under a service's `secrets`, in short (`db_password`) or long (`{ source: db_password }`) syntax
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum ServiceSecret {
+    Short(String),
+    Long(LongServiceSecret),
+}
+
+impl ServiceSecret {
+    /// The name of the top-level secret
+    pub fn source(&self) -> &str {
+        match self {
+            ServiceSecret::Short(name) => name,
+            ServiceSecret::Long(secret) => &secret.source,
+        }
+    }
+}
+
+/// A long-syntax service secret
+#[derive(Debug, Deserialize, Clone)]
+pub struct LongServiceSecret {
+    pub source: String,
+    /// File name under `/run/secrets`, or an absolute path
+    pub target: Option<String>,
+    #[serde(default, deserialize_with = "optional_scalar_string")]
+    pub uid: Option<String>,
+    #[serde(default, deserialize_with = "optional_scalar_string")]
+    pub gid: Option<String>,
+    pub mode: Option<serde_yaml::Value>,
+}
+
+/// An entry under `ports`, in short (`"8080:80"`) or long (`{ target: 80, published: 8080 }`) syntax
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum PortEntry {
+    Short(#[serde(deserialize_with = "scalar_string")] String),
+    Long(LongPort),
+}
+
+impl PortEntry {
+    /// The entry as a mapping, or `None` when a short-syntax string cannot be parsed
+    pub fn mapping(&self) -> Option<PortMapping> {
+        match self {
+            PortEntry::Short(spec) => PortMapping::parse(spec),
+            PortEntry::Long(port) => Some(PortMapping {
+                host_ip: port.host_ip.clone(),
+                published: port.published.clone(),
+                target: port.target.clone(),
+                protocol: port.protocol.clone(),
+            }),
+        }
+    }
+}
+
+/// A long-syntax port entry
+#[derive(Debug, Deserialize, Clone)]
+pub struct LongPort {
+    #[serde(deserialize_with = "scalar_string")]
+    pub target: String,
+    #[serde(default, deserialize_with = "optional_scalar_string")]
+    pub published: Option<String>,
+    pub host_ip: Option<String>,
+    pub protocol: Option<String>,
+    /// `host` or `ingress` (swarm)
+    pub mode: Option<String>,
+}
+
+/// The string form of a YAML scalar, so `8080` and `"8080"` read the same
+fn scalar_to_string(value: serde_yaml::Value) -> Option<String> {
+    match value {
+        serde_yaml::Value::String(s) => Some(s),
+        serde_yaml::Value::Number(n) => Some(n.to_string()),
+        serde_yaml::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn scalar_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value: serde_yaml::Value = Deserialize::deserialize(deserializer)?;
+    scalar_to_string(value).ok_or_else(|| serde::de::Error::custom("expected a string or number"))
+}
+
+fn optional_scalar_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value: Option<serde_yaml::Value> = Deserialize::deserialize(deserializer)?;
+    match value {
+        None | Some(serde_yaml::Value::Null) => Ok(None),
+        Some(value) => scalar_to_string(value)
+            .map(Some)
+            .ok_or_else(|| serde::de::Error::custom("expected a string or number")),
+    }
+}
+
+/// A short-syntax port mapping such as `127.0.0.1:8080:80/tcp`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortMapping {
+    pub host_ip: Option<String>,
+    pub published: Option<String>,
+    pub target: String,
+    pub protocol: Option<String>,
+}
+
+impl PortMapping {
+    pub fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+        let (spec, protocol) = match spec.split_once('/') {
+            Some((ports, proto)) => (ports, Some(proto.to_string())),
+            None => (spec, None),
+        };
+
+        // IPv6 host addresses are bracketed: [::1]:8080:80
+        let (host_ip, rest) = if let Some(stripped) = spec.strip_prefix('[') {
+            let (ip, rest) = stripped.split_once("]:")?;
+            (Some(ip.to_string()), rest)
+        } else {
+            (None, spec)
+        };
+
+        let parts: Vec<&str> = rest.split(':').collect();
+        let (host_ip, published, target) = match (host_ip, parts.as_slice()) {
+            (None, [target]) => (None, None, *target),
+            (None, [published, target]) => (None, Some(*published), *target),
+            (None, [ip, published, target]) => (Some(ip.to_string()), Some(*published), *target),
+            (Some(ip), [published, target]) => (Some(ip), Some(*published), *target),
+            _ => return None,
+        };
+
+        if target.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            host_ip,
+            published: published.filter(|p| !p.is_empty()).map(|p| p.to_string()),
+            target: target.to_string(),
+            protocol,
+        })
+    }
+
+    /// First container port of the (possibly ranged) target
+    pub fn target_port(&self) -> Option<u16> {
+        self.target.split('-').next()?.parse().ok()
+    }
+
+    /// Whether the port is only reachable from the host's loopback interface
+    pub fn is_loopback(&self) -> bool {
+        matches!(self.host_ip.as_deref(), Some(ip) if ip == "localhost" || ip.starts_with("127.") || ip == "::1")
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -52,7 +317,49 @@ pub enum BuildConfig {
 #[serde(untagged)]
 pub enum Environment {
     List(Vec<String>),
-    Map(HashMap<String, Option<String>>),
+    Map(#[serde(deserialize_with = "scalar_map")] HashMap<String, Option<String>>),
+}
+
+/// Deserialize a map whose values may be YAML booleans or numbers (e.g. `DEBUG: true`)
+/// as strings, the way Compose itself treats them
+fn scalar_map<'de, D>(deserializer: D) -> Result<HashMap<String, Option<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let raw: HashMap<String, Option<serde_yaml::Value>> = Deserialize::deserialize(deserializer)?;
+    raw.into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                None | Some(serde_yaml::Value::Null) => None,
+                Some(serde_yaml::Value::String(s)) => Some(s),
+                Some(serde_yaml::Value::Bool(b)) => Some(b.to_string()),
+                Some(serde_yaml::Value::Number(n)) => Some(n.to_string()),
+                Some(_) => {
+                    return Err(serde::de::Error::custom(format!(
+                        "environment variable '{}' must be a string, number or boolean",
+                        key
+                    )))
+                }
+            };
+            Ok((key, value))
+        })
+        .collect()
+}
+
+impl Environment {
+    /// `(name, value)` pairs in either form; `None` for pass-through variables
+    pub fn pairs(&self) -> Vec<(String, Option<String>)> {
+        match self {
+            Environment::List(list) => list
+                .iter()
+                .map(|item| match item.split_once('=') {
+                    Some((key, value)) => (key.to_string(), Some(value.to_string())),
+                    None => (item.clone(), None),
+                })
+                .collect(),
+            Environment::Map(map) => map.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -69,6 +376,18 @@ pub enum DependsOn {
     Extended(HashMap<String, DependsOnCondition>),
 }
 
+impl DependsOn {
+    /// Dependency service names, sorted for stable output
+    pub fn services(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = match self {
+            DependsOn::Simple(list) => list.iter().map(|s| s.as_str()).collect(),
+            DependsOn::Extended(map) => map.keys().map(|s| s.as_str()).collect(),
+        };
+        names.sort_unstable();
+        names
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct DependsOnCondition {
     pub condition: Option<String>,
@@ -81,6 +400,36 @@ pub struct HealthCheck {
     pub timeout: Option<String>,
     pub retries: Option<i32>,
     pub start_period: Option<String>,
+    /// Turns off the healthcheck defined by the image
+    pub disable: Option<bool>,
+}
+
+impl HealthCheck {
+    /// Whether the healthcheck is turned off, by `disable: true` or `test: ["NONE"]`
+    pub fn is_disabled(&self) -> bool {
+        if self.disable == Some(true) {
+            return true;
+        }
+        match &self.test {
+            Some(serde_yaml::Value::Sequence(parts)) => parts.first().and_then(|p| p.as_str()) == Some("NONE"),
+            _ => false,
+        }
+    }
+
+    /// The command run by `test`, or `None` when disabled (`NONE`) or unset
+    pub fn command(&self) -> Option<String> {
+        match self.test.as_ref()? {
+            serde_yaml::Value::String(command) => Some(command.clone()),
+            serde_yaml::Value::Sequence(parts) => {
+                let parts: Vec<&str> = parts.iter().filter_map(|p| p.as_str()).collect();
+                match parts.split_first() {
+                    Some((&"CMD", args)) | Some((&"CMD-SHELL", args)) => Some(args.join(" ")),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -110,16 +459,113 @@ pub struct RestartPolicy {
     pub window: Option<String>,
 }
 
+/// A compose file whose overall layout this tool cannot analyze
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ComposeFormatError {
+    #[error("legacy Compose v1 layout: services ({}) are defined at the top level. \
+             Move them under a top-level 'services:' key to use the current Compose format", .0.join(", "))]
+    LegacyV1(Vec<String>),
+    #[error("'services' is a list; it must be a mapping of service names to their definitions")]
+    ServicesList,
+}
+
+/// Whether a key is an `x-` extension field, which Compose ignores
+fn is_extension(key: &str) -> bool {
+    key.starts_with("x-")
+}
+
+/// Reject layouts that would otherwise deserialize silently to nothing (v1) or
+/// fail with a low-level serde message (list-style services)
+fn check_layout(value: &serde_yaml::Value) -> Result<(), ComposeFormatError> {
+    let Some(root) = value.as_mapping() else {
+        return Ok(());
+    };
+
+    match root.get("services") {
+        Some(serde_yaml::Value::Sequence(_)) => return Err(ComposeFormatError::ServicesList),
+        Some(_) => return Ok(()),
+        None if root.contains_key("version") => return Ok(()),
+        None => {}
+    }
+
+    let mut legacy: Vec<String> = root
+        .iter()
+        .filter(|(name, _)| !name.as_str().is_some_and(is_extension))
+        .filter(|(_, definition)| {
+            definition
+                .as_mapping()
+                .is_some_and(|d| d.contains_key("image") || d.contains_key("build"))
+        })
+        .filter_map(|(name, _)| name.as_str().map(|s| s.to_string()))
+        .collect();
+
+    if legacy.is_empty() {
+        return Ok(());
+    }
+    legacy.sort();
+    Err(ComposeFormatError::LegacyV1(legacy))
+}
+
+/// Why a compose file could not be parsed
+#[derive(Error, Debug)]
+pub enum ComposeParseError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Limit(#[from] LimitError),
+    /// Invalid YAML, or YAML that does not match the compose schema
+    #[error("{message}")]
+    Yaml {
+        message: String,
+        /// 1-based position of the error, when serde_yaml knows it
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    #[error(transparent)]
+    UnsupportedLayout(#[from] ComposeFormatError),
+}
+
+impl From<serde_yaml::Error> for ComposeParseError {
+    fn from(error: serde_yaml::Error) -> Self {
+        let location = error.location();
+        Self::Yaml {
+            message: error.to_string(),
+            line: location.as_ref().map(|l| l.line()),
+            column: location.as_ref().map(|l| l.column()),
+        }
+    }
+}
+
 pub struct ComposeParser;
 
 impl ComposeParser {
-    pub fn parse<P: AsRef<Path>>(path: P) -> Result<ComposeFile, Box<dyn std::error::Error>> {
+    pub fn parse<P: AsRef<Path>>(path: P) -> Result<ComposeFile, ComposeParseError> {
+        Self::parse_with_limits(path, &ParseLimits::default())
+    }
+
+    /// Parse a file, rejecting inputs that exceed `limits`
+    pub fn parse_with_limits<P: AsRef<Path>>(path: P, limits: &ParseLimits) -> Result<ComposeFile, ComposeParseError> {
+        limits.check_file(&path)?;
         let content = fs::read_to_string(&path)?;
-        Self::parse_content(&content)
+        limits.check_content(&content)?;
+        let mut compose = Self::parse_content(&content)?;
+        compose.source_path = Some(path.as_ref().to_path_buf());
+        Ok(compose)
     }
 
-    pub fn parse_content(content: &str) -> Result<ComposeFile, Box<dyn std::error::Error>> {
-        let compose: ComposeFile = serde_yaml::from_str(content)?;
+    pub fn parse_content(content: &str) -> Result<ComposeFile, ComposeParseError> {
+        let mut value: serde_yaml::Value = serde_yaml::from_str(content)?;
+        // `<<: *common` merges fields shared through `x-` anchors
+        value.apply_merge()?;
+        check_layout(&value)?;
+        let extensions = value
+            .as_mapping()
+            .into_iter()
+            .flatten()
+            .filter_map(|(key, field)| Some((key.as_str().filter(|k| is_extension(k))?.to_string(), field.clone())))
+            .collect();
+        let mut compose: ComposeFile = serde_yaml::from_value(value)?;
+        compose.extensions = extensions;
         Ok(compose)
     }
 }
@@ -150,6 +596,187 @@ services:
         assert!(services.contains_key("db"));
     }
 
+    #[test]
+    fn test_rejects_oversized_compose() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("docker-compose.yml");
+        let padding = "# padding\n".repeat(500);
+        fs::write(&path, format!("{}services:\n  web:\n    image: nginx:1.25\n", padding)).unwrap();
+
+        let limits = ParseLimits { max_bytes: 1024, ..ParseLimits::default() };
+        let err = ComposeParser::parse_with_limits(&path, &limits).unwrap_err();
+        assert!(err.to_string().contains("exceeding the limit"));
+    }
+
+    #[test]
+    fn test_yaml_error_has_location() {
+        let err = ComposeParser::parse_content("services:\n  web:\n    image: [nginx\n").unwrap_err();
+        match err {
+            ComposeParseError::Yaml { line, column, .. } => {
+                assert!(line.is_some());
+                assert!(column.is_some());
+            }
+            other => panic!("expected a YAML error, got {:?}", other),
+        }
+
+        let err = ComposeParser::parse_content("services:\n  - web\n").unwrap_err();
+        assert!(matches!(err, ComposeParseError::UnsupportedLayout(ComposeFormatError::ServicesList)));
+    }
+
+    #[test]
+    fn test_parse_port_mappings() {
+        let port = PortMapping::parse("127.0.0.1:8080:80/tcp").unwrap();
+        assert_eq!(port.host_ip.as_deref(), Some("127.0.0.1"));
+        assert_eq!(port.published.as_deref(), Some("8080"));
+        assert_eq!(port.target_port(), Some(80));
+        assert_eq!(port.protocol.as_deref(), Some("tcp"));
+        assert!(port.is_loopback());
+
+        let port = PortMapping::parse("9090-9091:8080-8081").unwrap();
+        assert_eq!(port.host_ip, None);
+        assert_eq!(port.target_port(), Some(8080));
+
+        let port = PortMapping::parse("3000").unwrap();
+        assert_eq!(port.published, None);
+        assert_eq!(port.target_port(), Some(3000));
+
+        let port = PortMapping::parse("[::1]:6000:6000").unwrap();
+        assert!(port.is_loopback());
+    }
+
+    #[test]
+    fn test_parse_long_syntax_ports() {
+        let content = r#"
+services:
+  dns:
+    image: coredns/coredns:1.11.1
+    ports:
+      - "8080:80"
+      - 9000
+      - target: 53
+        published: "5353"
+        protocol: udp
+        mode: host
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let service = &compose.services.as_ref().unwrap()["dns"];
+        let mappings = service.port_mappings();
+        assert_eq!(mappings.len(), 3);
+        assert_eq!(mappings[1].target, "9000");
+        assert_eq!(mappings[2].target_port(), Some(53));
+        assert_eq!(mappings[2].published.as_deref(), Some("5353"));
+        assert_eq!(mappings[2].protocol.as_deref(), Some("udp"));
+        assert!(matches!(&service.ports.as_ref().unwrap()[2], PortEntry::Long(port) if port.mode.as_deref() == Some("host")));
+    }
+
+    #[test]
+    fn test_dependencies_and_networks() {
+        let content = r#"
+services:
+  web:
+    image: nginx:1.25
+    depends_on:
+      db:
+        condition: service_healthy
+      cache:
+        condition: service_started
+    networks: [frontend, backend]
+  worker:
+    image: worker:1.0
+    depends_on: [db]
+    networks:
+      backend:
+        aliases: [jobs]
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let services = compose.services.unwrap();
+        assert_eq!(services["web"].dependencies(), vec!["cache", "db"]);
+        assert_eq!(services["web"].network_names(), vec!["frontend", "backend"]);
+        assert_eq!(services["worker"].dependencies(), vec!["db"]);
+        assert_eq!(services["worker"].network_names(), vec!["backend"]);
+    }
+
+    #[test]
+    fn test_rejects_legacy_v1_layout() {
+        let content = r#"
+web:
+  image: nginx:1.25
+  links:
+    - db
+db:
+  image: postgres:16
+"#;
+        let err = ComposeParser::parse_content(content).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("legacy Compose v1"));
+        assert!(message.contains("db, web"));
+        assert!(message.contains("'services:'"));
+    }
+
+    #[test]
+    fn test_rejects_list_style_services() {
+        let content = "services:\n  - name: web\n    image: nginx:1.25\n";
+        let err = ComposeParser::parse_content(content).unwrap_err();
+        assert!(err.to_string().contains("must be a mapping"));
+    }
+
+    #[test]
+    fn test_healthcheck_command_forms() {
+        let content = r#"
+services:
+  a:
+    healthcheck:
+      test: ["CMD", "curl", "-f", "http://localhost"]
+  b:
+    healthcheck:
+      test: ["CMD-SHELL", "pg_isready -h 127.0.0.1"]
+  c:
+    healthcheck:
+      test: wget -q http://localhost:8080
+  d:
+    healthcheck:
+      test: ["NONE"]
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let services = compose.services.unwrap();
+        let command = |name: &str| services[name].healthcheck.as_ref().unwrap().command();
+        assert_eq!(command("a").as_deref(), Some("curl -f http://localhost"));
+        assert_eq!(command("b").as_deref(), Some("pg_isready -h 127.0.0.1"));
+        assert_eq!(command("c").as_deref(), Some("wget -q http://localhost:8080"));
+        assert_eq!(command("d"), None);
+    }
+
+    #[test]
+    fn test_environment_map_accepts_scalars() {
+        let content = "services:\n  web:\n    environment:\n      DEBUG: true\n      WORKERS: 4\n      EMPTY:\n";
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let services = compose.services.unwrap();
+        let mut pairs = services["web"].environment.as_ref().unwrap().pairs();
+        pairs.sort();
+        assert_eq!(pairs, vec![
+            ("DEBUG".to_string(), Some("true".to_string())),
+            ("EMPTY".to_string(), None),
+            ("WORKERS".to_string(), Some("4".to_string())),
+        ]);
+    }
+
+    #[test]
+    fn test_production_file_detection() {
+        let mut compose = ComposeParser::parse_content("services: {}").unwrap();
+        assert!(!compose.is_production_file());
+
+        for (name, expected) in [
+            ("docker-compose.prod.yml", true),
+            ("compose.production.yaml", true),
+            ("prod-compose.yml", true),
+            ("docker-compose.yml", false),
+            ("docker-compose.products.yml", false),
+        ] {
+            compose.source_path = Some(PathBuf::from(name));
+            assert_eq!(compose.is_production_file(), expected, "{}", name);
+        }
+    }
+
     #[test]
     fn test_parse_compose_with_build() {
         let content = r#"
@@ -165,4 +792,59 @@ services:
         let app = services.get("app").unwrap();
         assert!(app.build.is_some());
     }
+
+    #[test]
+    fn test_parse_secrets_configs_and_extensions() {
+        let content = r#"
+x-common: &common
+  restart: unless-stopped
+services:
+  db:
+    <<: *common
+    image: postgres:16
+    secrets:
+      - db_password
+      - source: db_root_password
+        target: root_password
+        mode: 0400
+configs:
+  app_config:
+    file: ./app.conf
+secrets:
+  db_password:
+    file: ./db_password.txt
+  db_root_password:
+    external: true
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let services = compose.services.as_ref().unwrap();
+        assert_eq!(services["db"].secret_names(), vec!["db_password", "db_root_password"]);
+        assert_eq!(services["db"].restart.as_deref(), Some("unless-stopped"));
+
+        let secrets = compose.secrets.as_ref().unwrap();
+        assert_eq!(secrets["db_password"].file.as_deref(), Some("./db_password.txt"));
+        assert!(secrets["db_root_password"].external.is_some());
+        assert!(compose.configs.as_ref().unwrap().contains_key("app_config"));
+        assert!(compose.extensions.contains_key("x-common"));
+    }
+
+    #[test]
+    fn test_extension_fields_are_not_legacy_services() {
+        let content = "x-base:\n  image: alpine:3.18\nversion_note: none\n";
+        assert!(ComposeParser::parse_content(content).is_ok());
+    }
+
+    #[test]
+    fn test_with_profiles_keeps_profileless_services() {
+        let content = "services:\n  web:\n    image: nginx:1.25\n  debug:\n    image: busybox:1.36\n    profiles: [debug]\n";
+        let compose = ComposeParser::parse_content(content).unwrap();
+
+        let active = compose.with_profiles(&[]);
+        let services = active.services.unwrap();
+        assert!(services.contains_key("web"));
+        assert!(!services.contains_key("debug"));
+
+        let active = compose.with_profiles(&["debug".to_string()]);
+        assert_eq!(active.services.unwrap().len(), 2);
+    }
 }
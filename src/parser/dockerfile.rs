@@ -1,3 +1,4 @@
+use super::{LimitError, ParseLimits};
 use regex::Regex;
 use once_cell::sync::Lazy;
 use std::fs;
@@ -11,30 +12,190 @@ static CONTINUATION_RE: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"\\\s*$").unwrap()
 });
 
+/// BuildKit heredoc markers (`<<EOF`, `<<-EOF`, `<<"EOF"`), but not `<<<` here-strings
+static HEREDOC_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?:^|[^<])<<(-?)["']?([A-Za-z_][A-Za-z0-9_]*)["']?"#).unwrap()
+});
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Instruction {
     pub name: String,
     pub arguments: String,
     pub line_number: usize,
     pub raw_line: String,
+    /// `(byte offset into arguments, physical line number)` for each source line
+    /// that was joined into `arguments` through continuations
+    pub line_offsets: Vec<(usize, usize)>,
+    /// BuildKit options of a RUN (`--mount=...`, `--network=...`, `--security=...`),
+    /// which are not part of `arguments`
+    pub run_flags: Vec<String>,
+}
+
+impl Instruction {
+    /// Physical line number of the byte at `offset` within `arguments`
+    pub fn line_at(&self, offset: usize) -> usize {
+        self.line_offsets
+            .iter()
+            .take_while(|(start, _)| *start <= offset)
+            .last()
+            .map(|(_, line)| *line)
+            .unwrap_or(self.line_number)
+    }
+
+    /// Whether a RUN mounts a BuildKit cache (`--mount=type=cache,...`)
+    pub fn has_cache_mount(&self) -> bool {
+        self.run_flags.iter().any(|flag| {
+            flag.strip_prefix("--mount=")
+                .is_some_and(|mount| mount.split(',').any(|option| option == "type=cache"))
+        })
+    }
+
+    /// Whether the arguments use the JSON exec form (`["executable", "arg"]`)
+    /// rather than the shell form, which runs under `/bin/sh -c`
+    pub fn is_exec_form(&self) -> bool {
+        self.exec_args().is_some()
+    }
+
+    /// The command run by a HEALTHCHECK instruction, without its options.
+    /// Returns `None` for `HEALTHCHECK NONE` or other instructions.
+    pub fn healthcheck_command(&self) -> Option<String> {
+        if !self.name.eq_ignore_ascii_case("HEALTHCHECK") {
+            return None;
+        }
+
+        let mut rest = self.arguments.trim();
+        while rest.starts_with("--") {
+            rest = rest.split_once(char::is_whitespace).map_or("", |(_, r)| r).trim_start();
+        }
+
+        let (keyword, command) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        if !keyword.eq_ignore_ascii_case("CMD") {
+            return None;
+        }
+
+        let command = command.trim();
+        Some(match serde_json::from_str::<Vec<String>>(command) {
+            Ok(args) => args.join(" "),
+            Err(_) => command.to_string(),
+        })
+    }
+
+    /// Source paths of a COPY or ADD instruction (flags and destination removed)
+    pub fn copy_sources(&self) -> Vec<String> {
+        let mut paths = self.copy_paths();
+        paths.pop();
+        paths
+    }
+
+    /// Destination of a COPY or ADD instruction
+    pub fn copy_destination(&self) -> Option<String> {
+        let mut paths = self.copy_paths();
+        if paths.len() < 2 {
+            return None;
+        }
+        paths.pop()
+    }
+
+    /// Whether a COPY or ADD copies the whole build context (e.g. `COPY . .`)
+    pub fn copies_build_context(&self) -> bool {
+        !self.arguments.contains("--from=")
+            && self.copy_sources().iter().any(|s| matches!(s.as_str(), "." | "./" | "*"))
+    }
+
+    /// Sources followed by the destination, with flags removed. Heredoc bodies
+    /// follow the first line of the arguments and are not paths.
+    fn copy_paths(&self) -> Vec<String> {
+        if !self.name.eq_ignore_ascii_case("COPY") && !self.name.eq_ignore_ascii_case("ADD") {
+            return Vec::new();
+        }
+
+        let mut rest = self.arguments.lines().next().unwrap_or("").trim();
+        while rest.starts_with("--") {
+            rest = rest.split_once(char::is_whitespace).map_or("", |(_, r)| r).trim_start();
+        }
+
+        serde_json::from_str::<Vec<String>>(rest).unwrap_or_else(|_| split_quoted(rest))
+    }
+
+    /// The argument list of an exec-form instruction, or `None` for shell form
+    pub fn exec_args(&self) -> Option<Vec<String>> {
+        serde_json::from_str(self.arguments.trim()).ok()
+    }
+
+    /// Image or earlier stage a FROM builds on, skipping flags such as `--platform=...`
+    pub fn base_image(&self) -> Option<&str> {
+        self.stage_words().next()
+    }
+
+    /// Stage name given by a FROM's `AS name`
+    pub fn stage_name(&self) -> Option<&str> {
+        match self.stage_words().collect::<Vec<_>>().as_slice() {
+            [_, as_kw, name, ..] if as_kw.eq_ignore_ascii_case("AS") => Some(name),
+            _ => None,
+        }
+    }
+
+    fn stage_words(&self) -> impl Iterator<Item = &str> {
+        let arguments = if self.name.eq_ignore_ascii_case("FROM") { self.arguments.as_str() } else { "" };
+        arguments.split_whitespace().filter(|word| !word.starts_with("--"))
+    }
+}
+
+/// A build stage: its FROM and the instructions up to the next FROM
+#[derive(Debug, Clone, Copy)]
+pub struct Stage<'a> {
+    pub from: &'a Instruction,
+    pub instructions: &'a [Instruction],
+    /// Index of the earlier stage this one is built FROM, if any
+    pub parent: Option<usize>,
+}
+
+/// Parser directives BuildKit understands; other `# key=value` lines are plain comments
+const DIRECTIVES: &[&str] = &["syntax", "escape", "check"];
+
+/// A parser directive line such as `# syntax=docker/dockerfile:1`, as a lowercase
+/// key and its value
+pub fn parse_directive(line: &str) -> Option<(String, String)> {
+    let (key, value) = line.trim().strip_prefix('#')?.split_once('=')?;
+    let key = key.trim().to_lowercase();
+    DIRECTIVES.contains(&key.as_str()).then(|| (key, value.trim().to_string()))
 }
 
 #[derive(Debug)]
 pub struct DockerfileParser {
     pub instructions: Vec<Instruction>,
+    /// Parser directives from the top of the file, in order
+    pub directives: Vec<(String, String)>,
     pub raw_content: String,
 }
 
 impl DockerfileParser {
     pub fn parse<P: AsRef<Path>>(path: P) -> Result<Self, std::io::Error> {
+        Self::parse_with_limits(path, &ParseLimits::default())
+    }
+
+    /// Parse a file, rejecting inputs that exceed `limits` with an `InvalidData` error
+    pub fn parse_with_limits<P: AsRef<Path>>(path: P, limits: &ParseLimits) -> Result<Self, std::io::Error> {
+        limits.check_file(&path)?;
         let content = fs::read_to_string(&path)?;
-        Ok(Self::parse_content(&content))
+        Self::parse_content_with_limits(&content, limits)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
     }
 
     pub fn parse_content(content: &str) -> Self {
+        Self::parse_content_with_limits(content, &ParseLimits::unlimited())
+            .expect("unlimited parsing cannot exceed limits")
+    }
+
+    pub fn parse_content_with_limits(content: &str, limits: &ParseLimits) -> Result<Self, LimitError> {
+        limits.check_content(content)?;
+
         let mut instructions = Vec::new();
-        let lines: Vec<&str> = content.lines().collect();
-        let mut i = 0;
+        // `lines` only strips `\r` before `\n`; a CRLF file may still end in a bare `\r`
+        let lines: Vec<&str> = content.lines().map(|line| line.strip_suffix('\r').unwrap_or(line)).collect();
+        // Directives only count before any other line, including blank lines and comments
+        let directives: Vec<(String, String)> = lines.iter().map_while(|line| parse_directive(line)).collect();
+        let mut i = directives.len();
 
         while i < lines.len() {
             let line = lines[i].trim();
@@ -51,36 +212,97 @@ impl DockerfileParser {
                 let mut arguments = captures.get(2).unwrap().as_str().to_string();
                 let start_line = i + 1; // 1-indexed
                 let mut raw_line = line.to_string();
+                let mut line_offsets = vec![(0, start_line)];
 
                 // Handle line continuations
                 let mut current_line = line;
                 while CONTINUATION_RE.is_match(current_line) && i + 1 < lines.len() {
+                    if i + 1 - start_line >= limits.max_continuation_lines {
+                        return Err(LimitError::ContinuationTooLong {
+                            line: start_line,
+                            max: limits.max_continuation_lines,
+                        });
+                    }
+
                     i += 1;
-                    current_line = lines[i].trim();
-                    raw_line.push_str("\n");
-                    raw_line.push_str(current_line);
-                    
+                    let next_line = lines[i].trim();
+                    raw_line.push('\n');
+                    raw_line.push_str(next_line);
+
+                    // Like Docker, drop comments and blank lines inside a continuation
+                    if next_line.is_empty() || next_line.starts_with('#') {
+                        continue;
+                    }
+                    current_line = next_line;
+
                     // Remove the backslash and append the continuation
-                    arguments = CONTINUATION_RE.replace(&arguments, "").to_string();
+                    strip_continuation(&mut arguments);
                     arguments.push(' ');
+                    line_offsets.push((arguments.len(), i + 1));
                     arguments.push_str(current_line);
                 }
 
+                // Heredoc bodies become part of the arguments, one line each
+                if matches!(instruction_name.as_str(), "RUN" | "COPY" | "ADD") {
+                    for (terminator, strip_tabs) in heredoc_terminators(&arguments) {
+                        while i + 1 < lines.len() {
+                            if i + 1 - start_line >= limits.max_continuation_lines {
+                                return Err(LimitError::ContinuationTooLong {
+                                    line: start_line,
+                                    max: limits.max_continuation_lines,
+                                });
+                            }
+
+                            i += 1;
+                            raw_line.push('\n');
+                            raw_line.push_str(lines[i]);
+                            let body_line = if strip_tabs { lines[i].trim_start_matches('\t') } else { lines[i] };
+                            if body_line.trim_end() == terminator {
+                                break;
+                            }
+                            arguments.push('\n');
+                            line_offsets.push((arguments.len(), i + 1));
+                            arguments.push_str(body_line);
+                        }
+                    }
+                }
+
+                let (run_flags, command_start) = if instruction_name == "RUN" {
+                    split_run_flags(&arguments)
+                } else {
+                    (Vec::new(), 0)
+                };
+                let line_offsets = line_offsets
+                    .into_iter()
+                    .map(|(start, line)| (start.saturating_sub(command_start), line))
+                    .collect();
+
                 instructions.push(Instruction {
                     name: instruction_name,
-                    arguments: arguments.trim().to_string(),
+                    arguments: arguments[command_start..].trim().to_string(),
                     line_number: start_line,
                     raw_line,
+                    line_offsets,
+                    run_flags,
                 });
             }
 
             i += 1;
         }
 
-        Self {
+        Ok(Self {
             instructions,
+            directives,
             raw_content: content.to_string(),
-        }
+        })
+    }
+
+    /// Value of a parser directive (e.g. "syntax"), if the file sets it
+    pub fn directive(&self, key: &str) -> Option<&str> {
+        self.directives
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.as_str())
     }
 
     pub fn get_instructions(&self, name: &str) -> Vec<&Instruction> {
@@ -90,9 +312,197 @@ impl DockerfileParser {
             .collect()
     }
 
+    /// Build stages in order. Instructions before the first FROM belong to none.
+    pub fn stages(&self) -> Vec<Stage<'_>> {
+        let starts: Vec<usize> = self
+            .instructions
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| i.name.eq_ignore_ascii_case("FROM"))
+            .map(|(index, _)| index)
+            .collect();
+
+        let mut stages: Vec<Stage> = Vec::new();
+        for (n, &start) in starts.iter().enumerate() {
+            let end = starts.get(n + 1).copied().unwrap_or(self.instructions.len());
+            let from = &self.instructions[start];
+            let parent = from.base_image().and_then(|image| {
+                stages.iter().rposition(|stage| stage.from.stage_name().is_some_and(|name| name.eq_ignore_ascii_case(image)))
+            });
+            stages.push(Stage { from, instructions: &self.instructions[start + 1..end], parent });
+        }
+        stages
+    }
+
+    /// For each stage, the instructions that run while no WORKDIR is in effect: those
+    /// before its first WORKDIR, or none when it builds on a stage that set one
+    pub fn before_workdir(&self) -> Vec<&[Instruction]> {
+        let mut sets_workdir: Vec<bool> = Vec::new();
+        self.stages()
+            .into_iter()
+            .map(|stage| {
+                let inherited = stage.parent.is_some_and(|parent| sets_workdir[parent]);
+                let first = stage.instructions.iter().position(|i| i.name.eq_ignore_ascii_case("WORKDIR"));
+                sets_workdir.push(inherited || first.is_some());
+                let end = if inherited { 0 } else { first.unwrap_or(stage.instructions.len()) };
+                &stage.instructions[..end]
+            })
+            .collect()
+    }
+
     pub fn has_instruction(&self, name: &str) -> bool {
         self.instructions.iter().any(|i| i.name.eq_ignore_ascii_case(name))
     }
+
+    /// All ports declared by EXPOSE instructions, in order
+    pub fn exposed_ports(&self) -> Vec<ExposedPort> {
+        self.get_instructions("EXPOSE")
+            .into_iter()
+            .flat_map(|i| parse_exposed_ports(&i.arguments))
+            .collect()
+    }
+
+    /// All key/value pairs declared by LABEL instructions, in order
+    pub fn labels(&self) -> Vec<(String, String)> {
+        self.get_instructions("LABEL")
+            .into_iter()
+            .flat_map(|i| parse_key_values(&i.arguments))
+            .collect()
+    }
+}
+
+/// A port or port range declared by EXPOSE, e.g. `8080`, `53/udp` or `8000-8010/tcp`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExposedPort {
+    pub start: u16,
+    pub end: u16,
+    pub protocol: Option<String>,
+}
+
+impl ExposedPort {
+    pub fn contains(&self, port: u16) -> bool {
+        (self.start..=self.end).contains(&port)
+    }
+}
+
+/// Parse the ports of an EXPOSE instruction. Entries that are not literal
+/// numbers (e.g. `$PORT`) are skipped, as are invalid ones.
+pub fn parse_exposed_ports(args: &str) -> Vec<ExposedPort> {
+    parse_expose_entries(args).into_iter().filter_map(Result::ok).collect()
+}
+
+/// Each literal entry of an EXPOSE instruction, or the entry text when it is not a
+/// valid port or range (1-65535, start <= end) with an optional tcp/udp/sctp suffix.
+/// Entries using variables are skipped since their value is only known at build time.
+pub fn parse_expose_entries(args: &str) -> Vec<Result<ExposedPort, String>> {
+    let port = |value: &str| value.parse::<u16>().ok().filter(|p| *p > 0);
+    args.split_whitespace()
+        .filter(|entry| !entry.contains('$'))
+        .map(|entry| {
+            let (ports, protocol) = match entry.split_once('/') {
+                Some((ports, proto)) => (ports, Some(proto.to_lowercase())),
+                None => (entry, None),
+            };
+            let range = match ports.split_once('-') {
+                Some((start, end)) => port(start).zip(port(end)),
+                None => port(ports).map(|p| (p, p)),
+            };
+            match (range, protocol.as_deref()) {
+                (Some((start, end)), None | Some("tcp" | "udp" | "sctp")) if start <= end => {
+                    Ok(ExposedPort { start, end, protocol })
+                }
+                _ => Err(entry.to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Remove a trailing line-continuation backslash in place. Only the end of the
+/// string is touched, so long continuation chains stay linear.
+fn strip_continuation(arguments: &mut String) {
+    let trimmed = arguments.trim_end().len();
+    arguments.truncate(trimmed);
+    if arguments.ends_with('\\') {
+        arguments.pop();
+    }
+}
+
+/// Leading `--flag=value` options of a RUN and the byte offset where its command starts
+fn split_run_flags(arguments: &str) -> (Vec<String>, usize) {
+    let mut flags = Vec::new();
+    let mut start = arguments.len() - arguments.trim_start().len();
+    while arguments[start..].starts_with("--") {
+        let rest = &arguments[start..];
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        flags.push(rest[..end].to_string());
+        let after = &rest[end..];
+        start += end + (after.len() - after.trim_start().len());
+    }
+    (flags, start)
+}
+
+/// Terminators of the heredocs opened by an instruction, in order, and whether
+/// leading tabs are stripped from their bodies (`<<-`)
+fn heredoc_terminators(arguments: &str) -> Vec<(String, bool)> {
+    HEREDOC_RE
+        .captures_iter(arguments)
+        .map(|captures| (captures[2].to_string(), !captures[1].is_empty()))
+        .collect()
+}
+
+/// Parse `key=value` pairs (as used by LABEL and ENV), honoring quotes.
+/// Falls back to the legacy `key value` form when no `=` is present.
+pub fn parse_key_values(args: &str) -> Vec<(String, String)> {
+    let tokens = split_quoted(args);
+
+    if !tokens.iter().any(|t| t.contains('=')) {
+        let mut parts = args.trim().splitn(2, char::is_whitespace);
+        return match parts.next() {
+            Some(key) if !key.is_empty() => vec![(
+                key.to_string(),
+                parts.next().unwrap_or("").trim().trim_matches('"').to_string(),
+            )],
+            _ => Vec::new(),
+        };
+    }
+
+    tokens
+        .into_iter()
+        .filter_map(|token| {
+            token.split_once('=').map(|(k, v)| (k.to_string(), v.to_string()))
+        })
+        .collect()
+}
+
+/// Split on whitespace outside of double quotes, removing the quotes
+fn split_quoted(args: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = args.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '\\' if in_quotes => {
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
 }
 
 #[cfg(test)]
@@ -127,6 +537,208 @@ RUN apk add --no-cache \
         assert!(parser.instructions[1].arguments.contains("git"));
     }
 
+    #[test]
+    fn test_parse_crlf_line_endings() {
+        let content = "FROM ubuntu:20.04\r\nRUN apt-get update && \\ \r\n    apt-get install -y curl\r\nCMD [\"bash\"]\r";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(parser.instructions.len(), 3);
+        assert_eq!(parser.instructions[0].arguments, "ubuntu:20.04");
+        assert!(parser.instructions[1].arguments.ends_with("apt-get install -y curl"));
+        assert_eq!(parser.instructions[2].arguments, "[\"bash\"]");
+        assert!(parser.instructions.iter().all(|i| !i.arguments.contains('\r')));
+    }
+
+    #[test]
+    fn test_parse_heredoc_run() {
+        let content = "FROM debian:12.5\nRUN <<EOF\nset -e\n# refresh the index\napt-get update\napt-get install -y curl\nEOF\nCOPY <<-'CONF' /etc/app.conf\n\tport=8080\n\tCONF\nUSER app\n";
+        let parser = DockerfileParser::parse_content(content);
+        let names: Vec<&str> = parser.instructions.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["FROM", "RUN", "COPY", "USER"]);
+
+        let run = &parser.instructions[1];
+        assert_eq!(run.arguments, "<<EOF\nset -e\n# refresh the index\napt-get update\napt-get install -y curl");
+        assert_eq!(run.line_at(run.arguments.find("apt-get install").unwrap()), 6);
+        assert_eq!(parser.instructions[2].arguments, "<<-'CONF' /etc/app.conf\nport=8080");
+        assert_eq!(parser.instructions[2].copy_sources(), vec!["<<-'CONF'"]);
+        assert_eq!(parser.instructions[2].copy_destination().as_deref(), Some("/etc/app.conf"));
+        assert_eq!(parser.instructions[3].line_number, 11);
+    }
+
+    #[test]
+    fn test_stages_skip_from_flags() {
+        let content = "ARG BASE=node:20\nFROM --platform=$BUILDPLATFORM node:20 AS build\nWORKDIR /src\nCOPY . .\nFROM build AS test\nCOPY . .\nFROM --platform=linux/amd64 alpine:3.18\nCOPY . .\nWORKDIR /app";
+        let parser = DockerfileParser::parse_content(content);
+        let stages = parser.stages();
+        assert_eq!(stages.len(), 3);
+        assert_eq!(stages[0].from.base_image(), Some("node:20"));
+        assert_eq!(stages[0].from.stage_name(), Some("build"));
+        assert_eq!(stages[1].parent, Some(0));
+        assert_eq!(stages[2].from.base_image(), Some("alpine:3.18"));
+        assert_eq!(stages[2].parent, None);
+
+        let before: Vec<usize> = parser.before_workdir().iter().map(|stage| stage.len()).collect();
+        assert_eq!(before, vec![0, 0, 1]);
+    }
+
+    #[test]
+    fn test_here_string_is_not_heredoc() {
+        let parser = DockerfileParser::parse_content("FROM alpine:3.18\nRUN cat <<< word\nUSER app\n");
+        assert_eq!(parser.instructions.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_directives() {
+        let content = "# syntax=docker/dockerfile:1\n# Escape = \\\n# check=skip=JSONArgsRecommended\nFROM alpine:3.18\n";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(parser.directive("syntax"), Some("docker/dockerfile:1"));
+        assert_eq!(parser.directive("escape"), Some("\\"));
+        assert_eq!(parser.directives.len(), 3);
+        assert_eq!(parser.instructions.len(), 1);
+        assert_eq!(parser.instructions[0].line_number, 4);
+    }
+
+    #[test]
+    fn test_directives_stop_at_first_other_line() {
+        let parser = DockerfileParser::parse_content("# build notes\n# syntax=docker/dockerfile:1\nFROM alpine:3.18\n");
+        assert!(parser.directives.is_empty());
+        let parser = DockerfileParser::parse_content("# maintainer=ops\n# syntax=docker/dockerfile:1\nFROM alpine:3.18\n");
+        assert!(parser.directives.is_empty());
+    }
+
+    #[test]
+    fn test_run_flags_are_split_from_command() {
+        let content = "FROM python:3.12-slim\nRUN --mount=type=cache,target=/root/.cache/pip \\\n    --network=host \\\n    pip install requests\nRUN --mount=type=secret,id=token cat /run/secrets/token";
+        let parser = DockerfileParser::parse_content(content);
+        let run = &parser.instructions[1];
+        assert_eq!(run.run_flags, vec!["--mount=type=cache,target=/root/.cache/pip", "--network=host"]);
+        assert_eq!(run.arguments, "pip install requests");
+        assert_eq!(run.line_at(0), 4);
+        assert!(run.has_cache_mount());
+
+        let secret = &parser.instructions[2];
+        assert_eq!(secret.arguments, "cat /run/secrets/token");
+        assert!(!secret.has_cache_mount());
+    }
+
+    #[test]
+    fn test_parse_labels() {
+        let content = r#"
+FROM alpine
+LABEL org.opencontainers.image.source="https://github.com/example/app" version=1.0
+LABEL description "Legacy form"
+"#;
+        let parser = DockerfileParser::parse_content(content);
+        let labels = parser.labels();
+        assert_eq!(labels.len(), 3);
+        assert_eq!(labels[0], ("org.opencontainers.image.source".to_string(), "https://github.com/example/app".to_string()));
+        assert_eq!(labels[1], ("version".to_string(), "1.0".to_string()));
+        assert_eq!(labels[2], ("description".to_string(), "Legacy form".to_string()));
+    }
+
+    #[test]
+    fn test_rejects_too_many_lines() {
+        let content = "FROM alpine\n".repeat(50);
+        let limits = ParseLimits { max_lines: 10, ..ParseLimits::default() };
+        let result = DockerfileParser::parse_content_with_limits(&content, &limits);
+        assert_eq!(result.unwrap_err(), LimitError::TooManyLines { lines: 50, max: 10 });
+    }
+
+    #[test]
+    fn test_rejects_oversized_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("Dockerfile");
+        fs::write(&path, format!("FROM alpine\nRUN echo {}\n", "x".repeat(2048))).unwrap();
+
+        let limits = ParseLimits { max_bytes: 1024, ..ParseLimits::default() };
+        let err = DockerfileParser::parse_with_limits(&path, &limits).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("exceeding the limit of 1024 bytes"));
+    }
+
+    #[test]
+    fn test_rejects_runaway_continuation() {
+        let content = format!("FROM alpine\nRUN echo \\\n{}", "  a \\\n".repeat(100));
+        let limits = ParseLimits { max_continuation_lines: 20, ..ParseLimits::default() };
+        let result = DockerfileParser::parse_content_with_limits(&content, &limits);
+        assert_eq!(result.unwrap_err(), LimitError::ContinuationTooLong { line: 2, max: 20 });
+    }
+
+    #[test]
+    fn test_line_at_offset_in_continuation() {
+        let content = "FROM alpine\nRUN apk add --no-cache \\\n    curl \\\n    git\n";
+        let parser = DockerfileParser::parse_content(content);
+        let run = &parser.instructions[1];
+        assert_eq!(run.line_at(0), 2);
+        assert_eq!(run.line_at(run.arguments.find("curl").unwrap()), 3);
+        assert_eq!(run.line_at(run.arguments.find("git").unwrap()), 4);
+    }
+
+    #[test]
+    fn test_exec_form_detection() {
+        let parser = DockerfileParser::parse_content("FROM alpine\nENTRYPOINT [\"./app\", \"--serve\"]\nCMD ./app --serve\nCMD [broken");
+        let forms: Vec<bool> = parser.instructions[1..].iter().map(|i| i.is_exec_form()).collect();
+        assert_eq!(forms, vec![true, false, false]);
+    }
+
+    #[test]
+    fn test_parse_exposed_ports() {
+        let parser = DockerfileParser::parse_content("FROM alpine\nEXPOSE 80 53/UDP\nEXPOSE 8000-8010/tcp $PORT");
+        let ports = parser.exposed_ports();
+        assert_eq!(ports.len(), 3);
+        assert_eq!(ports[0], ExposedPort { start: 80, end: 80, protocol: None });
+        assert_eq!(ports[1].protocol.as_deref(), Some("udp"));
+        assert!(ports[2].contains(8005));
+        assert!(!ports[2].contains(8011));
+    }
+
+    #[test]
+    fn test_parse_invalid_expose_entries() {
+        let entries = parse_expose_entries("70000 0 90-80 8080/http 443/TCP ${PORT}/tcp");
+        let invalid: Vec<_> = entries.iter().filter_map(|e| e.as_ref().err().map(String::as_str)).collect();
+        assert_eq!(invalid, vec!["70000", "0", "90-80", "8080/http"]);
+        assert_eq!(entries.len(), 5);
+    }
+
+    #[test]
+    fn test_comments_inside_continuation_are_dropped() {
+        let parser = DockerfileParser::parse_content("FROM alpine\nRUN apk add \\\n# pinned below\n\n    curl\nUSER nobody");
+        assert_eq!(parser.instructions.len(), 3);
+        let run = &parser.instructions[1];
+        assert!(!run.arguments.contains('#'));
+        assert!(run.arguments.ends_with("curl"));
+        assert_eq!(run.line_at(run.arguments.len() - 1), 5);
+    }
+
+    #[test]
+    fn test_healthcheck_command() {
+        let parser = DockerfileParser::parse_content(
+            "FROM alpine\nHEALTHCHECK --interval=30s --timeout=3s CMD curl -f http://localhost/\nHEALTHCHECK CMD [\"wget\", \"-q\", \"http://127.0.0.1\"]\nHEALTHCHECK NONE",
+        );
+        let commands: Vec<Option<String>> = parser.instructions.iter().map(|i| i.healthcheck_command()).collect();
+        assert_eq!(commands, vec![
+            None,
+            Some("curl -f http://localhost/".to_string()),
+            Some("wget -q http://127.0.0.1".to_string()),
+            None,
+        ]);
+    }
+
+    #[test]
+    fn test_copy_sources() {
+        let parser = DockerfileParser::parse_content(
+            "FROM alpine\nCOPY --chown=app:app package.json yarn.lock ./\nADD [\"a b\", \"/dst/\"]\nCOPY . .",
+        );
+        let sources: Vec<Vec<String>> = parser.instructions.iter().map(|i| i.copy_sources()).collect();
+        assert_eq!(sources[1], vec!["package.json", "yarn.lock"]);
+        assert_eq!(sources[2], vec!["a b"]);
+        assert_eq!(sources[3], vec!["."]);
+        assert!(sources[0].is_empty());
+
+        assert_eq!(parser.instructions[2].copy_destination().as_deref(), Some("/dst/"));
+        assert!(parser.instructions[3].copies_build_context());
+        assert!(!parser.instructions[1].copies_build_context());
+    }
+
     #[test]
     fn test_skip_comments() {
         let content = r#"
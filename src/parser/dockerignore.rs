@@ -12,6 +12,52 @@ pub fn parse_dockerignore<P: AsRef<Path>>(path: P) -> Result<Vec<String>, std::i
     Ok(parse_patterns(&content))
 }
 
+/// Whether `path` (relative to the build context) is excluded by the given
+/// .dockerignore patterns. Supports `*`, `?`, `**` and `!` exceptions; the
+/// last matching pattern wins, as in Docker.
+pub fn is_excluded(patterns: &[String], path: &str) -> bool {
+    let path = path.trim_start_matches("./").trim_matches('/');
+    let mut excluded = false;
+
+    for pattern in patterns {
+        let (negated, pattern) = match pattern.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, pattern.as_str()),
+        };
+        let pattern = pattern.trim_start_matches("./").trim_matches('/');
+        if glob_to_regex(pattern).is_some_and(|re| re.is_match(path)) {
+            excluded = !negated;
+        }
+    }
+
+    excluded
+}
+
+fn glob_to_regex(pattern: &str) -> Option<regex::Regex> {
+    let mut regex = String::from("^");
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                // `**/` also matches zero directories
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    // A directory pattern also excludes everything below it
+    regex.push_str("(?:/.*)?$");
+    regex::Regex::new(&regex).ok()
+}
+
 fn parse_patterns(content: &str) -> Vec<String> {
     content
         .lines()
@@ -40,4 +86,20 @@ target/
         assert!(patterns.contains(&"node_modules".to_string()));
         assert!(patterns.contains(&".git".to_string()));
     }
+
+    #[test]
+    fn test_is_excluded() {
+        let patterns: Vec<String> = ["node_modules", "/.git/", "*.log", "!keep.log", "**/tmp"]
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        assert!(is_excluded(&patterns, ".git"));
+        assert!(is_excluded(&patterns, ".git/config"));
+        assert!(is_excluded(&patterns, "debug.log"));
+        assert!(!is_excluded(&patterns, "keep.log"));
+        assert!(is_excluded(&patterns, "a/b/tmp"));
+        assert!(!is_excluded(&patterns, "src/main.rs"));
+        assert!(!is_excluded(&[".gitignore".to_string()], ".git"));
+        assert!(is_excluded(&[".git*".to_string()], ".git"));
+    }
 }
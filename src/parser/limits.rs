@@ -0,0 +1,70 @@
+use std::path::Path;
+use thiserror::Error;
+
+/// Upper bounds applied when reading and parsing input files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// Maximum file size in bytes
+    pub max_bytes: u64,
+    /// Maximum number of lines
+    pub max_lines: usize,
+    /// Maximum number of physical lines joined by `\` continuations into one instruction
+    pub max_continuation_lines: usize,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: 4 * 1024 * 1024,
+            max_lines: 100_000,
+            max_continuation_lines: 1_000,
+        }
+    }
+}
+
+impl ParseLimits {
+    /// No limits at all (used by the in-memory `parse_content` helpers)
+    pub fn unlimited() -> Self {
+        Self {
+            max_bytes: u64::MAX,
+            max_lines: usize::MAX,
+            max_continuation_lines: usize::MAX,
+        }
+    }
+
+    /// Check a file's size on disk before reading it into memory
+    pub fn check_file<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let size = std::fs::metadata(path)?.len();
+        if size > self.max_bytes {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                LimitError::FileTooLarge { size, max: self.max_bytes },
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check already-loaded content against the size and line limits
+    pub fn check_content(&self, content: &str) -> Result<(), LimitError> {
+        let size = content.len() as u64;
+        if size > self.max_bytes {
+            return Err(LimitError::FileTooLarge { size, max: self.max_bytes });
+        }
+        let lines = content.lines().count();
+        if lines > self.max_lines {
+            return Err(LimitError::TooManyLines { lines, max: self.max_lines });
+        }
+        Ok(())
+    }
+}
+
+/// An input exceeded one of the configured `ParseLimits`
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum LimitError {
+    #[error("file is {size} bytes, exceeding the limit of {max} bytes")]
+    FileTooLarge { size: u64, max: u64 },
+    #[error("file has {lines} lines, exceeding the limit of {max} lines")]
+    TooManyLines { lines: usize, max: usize },
+    #[error("instruction at line {line} continues over more than {max} lines")]
+    ContinuationTooLong { line: usize, max: usize },
+}
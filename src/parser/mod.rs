@@ -1,7 +1,9 @@
 mod dockerfile;
 mod compose;
 mod dockerignore;
+mod limits;
 
-pub use dockerfile::{DockerfileParser, Instruction};
-pub use compose::{ComposeParser, ComposeFile, Service, Environment};
-pub use dockerignore::check_dockerignore;
+pub use dockerfile::{DockerfileParser, ExposedPort, Instruction, Stage, parse_directive, parse_expose_entries, parse_exposed_ports};
+pub use compose::{ComposeParser, ComposeFile, ComposeFormatError, ComposeParseError, Service, BuildConfig, Environment, LongPort, LongServiceSecret, PortEntry, PortMapping, SecretDefinition, ServiceSecret};
+pub use dockerignore::{check_dockerignore, is_excluded, parse_dockerignore};
+pub use limits::{ParseLimits, LimitError};
@@ -0,0 +1,132 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+
+/// Well-known admin UI ports: (container port, product, image hint).
+/// Ports that are also common application ports only count when the image matches the hint.
+const ADMIN_PORTS: &[(u16, &str, Option<&str>)] = &[
+    (15672, "RabbitMQ management UI", None),
+    (5601, "Kibana", None),
+    (8161, "ActiveMQ web console", None),
+    (8500, "Consul UI", None),
+    (19999, "Netdata dashboard", None),
+    (8080, "Traefik dashboard", Some("traefik")),
+    (9000, "Portainer", Some("portainer")),
+    (9443, "Portainer", Some("portainer")),
+    (9001, "MinIO console", Some("minio")),
+    (8081, "Mongo Express", Some("mongo-express")),
+    (5050, "pgAdmin", Some("pgadmin")),
+    (3000, "Grafana", Some("grafana")),
+];
+
+pub struct AdminPortsRule;
+
+impl Rule for AdminPortsRule {
+    fn id(&self) -> &'static str { "DC013" }
+    fn name(&self) -> &'static str { "Admin UI published" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "Service publishes a well-known admin interface port on all host interfaces"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Admin dashboards (RabbitMQ management, Kibana, Traefik, Portainer...) often ship \
+         with default or no credentials. Publishing them on every host interface makes \
+         them reachable from the network, where they are a frequent entry point for \
+         attackers."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Bind the port to localhost (e.g., \"127.0.0.1:15672:15672\") or put the UI behind an authenticating proxy")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Keeps admin interfaces off the network".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl ComposeRule for AdminPortsRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                let image = service.image.as_deref().unwrap_or("").to_lowercase();
+
+                for port in service.port_mappings() {
+                    if port.is_loopback() {
+                        continue;
+                    }
+                    let Some(target) = port.target_port() else {
+                        continue;
+                    };
+
+                    let admin = ADMIN_PORTS.iter().find(|(admin_port, _, hint)| {
+                        *admin_port == target && hint.is_none_or(|h| image.contains(h))
+                    });
+
+                    if let Some((_, product, _)) = admin {
+                        issues.push(Issue {
+                            rule_id: self.id().to_string(),
+                            rule_name: self.name().to_string(),
+                            severity: self.severity(),
+                            line_number: None,
+                            message: format!("Service '{}' publishes {} (port {}) on all interfaces", name, product, target),
+                            fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                            impact: self.impact(),
+                            params: message_params([("service", &name), ("product", &product), ("port", &target)]),
+                        });
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_rabbitmq_management() {
+        let content = r#"
+services:
+  queue:
+    image: rabbitmq:3.12-management
+    ports:
+      - "5672:5672"
+      - "15672:15672"
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = AdminPortsRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("RabbitMQ"));
+    }
+
+    #[test]
+    fn test_allows_app_port() {
+        let content = r#"
+services:
+  app:
+    image: myapp:1.0
+    ports:
+      - "8080:8080"
+  queue:
+    image: rabbitmq:3.12-management
+    ports:
+      - "127.0.0.1:15672:15672"
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = AdminPortsRule.check(&compose);
+        assert_eq!(issues.len(), 0);
+    }
+}
@@ -0,0 +1,129 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::{BuildConfig, ComposeFile};
+use crate::rules::Rule;
+
+pub struct BuildContextEscapeRule;
+
+/// Whether a relative context climbs above the compose file's directory
+/// (`../x` does, `./app/../lib` does not). Remote contexts are ignored.
+fn escapes_project(context: &str) -> bool {
+    if context.contains("://") || context.starts_with("git@") {
+        return false;
+    }
+    let mut depth: i32 = 0;
+    for component in context.split(['/', '\\']) {
+        match component {
+            "" | "." => {}
+            ".." => {
+                depth -= 1;
+                if depth < 0 {
+                    return true;
+                }
+            }
+            _ => depth += 1,
+        }
+    }
+    false
+}
+
+impl Rule for BuildContextEscapeRule {
+    fn id(&self) -> &'static str { "DC020" }
+    fn name(&self) -> &'static str { "Build context outside project" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "Service build context points outside the compose file's directory"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A context like '../../other-project' only works when the neighbouring checkout \
+         happens to exist at that path. CI runners and fresh clones usually have just this \
+         repository, and the whole parent directory may be sent to the Docker daemon as \
+         build context."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Keep the build context inside the project, or build the other project's image separately and reference it with 'image:'")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Builds work from a fresh clone of the repository".to_string()),
+        })
+    }
+}
+
+impl ComposeRule for BuildContextEscapeRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                let context = match &service.build {
+                    Some(BuildConfig::Simple(context)) => Some(context),
+                    Some(BuildConfig::Extended { context, .. }) => context.as_ref(),
+                    None => None,
+                };
+                let Some(context) = context.filter(|c| escapes_project(c)) else {
+                    continue;
+                };
+
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: None,
+                    message: format!("Service '{}' builds from '{}', outside the project directory", name, context),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: message_params([("service", &name), ("context", &context)]),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_parent_context() {
+        let content = r#"
+services:
+  api:
+    build: ../sibling
+  worker:
+    build:
+      context: ../../other-project
+      dockerfile: Dockerfile.worker
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = BuildContextEscapeRule.check(&compose);
+        assert_eq!(issues.len(), 2);
+        assert!(issues.iter().any(|i| i.message.contains("'api'") && i.message.contains("../sibling")));
+        assert!(issues.iter().any(|i| i.message.contains("'worker'")));
+    }
+
+    #[test]
+    fn test_allows_contexts_inside_project() {
+        let content = r#"
+services:
+  app:
+    build:
+      context: ./app
+  lib:
+    build: ./app/../lib
+  remote:
+    build: https://github.com/example/repo.git#main
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        assert_eq!(BuildContextEscapeRule.check(&compose).len(), 0);
+    }
+}
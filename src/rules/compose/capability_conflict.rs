@@ -0,0 +1,145 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+
+/// More capabilities than this added back after `cap_drop: [ALL]` defeats the drop
+const MAX_CAPS_AFTER_DROP_ALL: usize = 5;
+
+pub struct CapabilityConflictRule;
+
+/// `cap_net_admin`, `NET_ADMIN` and `CAP_NET_ADMIN` all name the same capability
+pub(super) fn normalize(capability: &str) -> String {
+    let upper = capability.trim().to_uppercase();
+    upper.strip_prefix("CAP_").map(str::to_string).unwrap_or(upper)
+}
+
+impl Rule for CapabilityConflictRule {
+    fn id(&self) -> &'static str { "DC021" }
+    fn name(&self) -> &'static str { "Conflicting capabilities" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "cap_add and cap_drop contradict each other"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Adding and dropping the same capability leaves the reader guessing which one \
+         wins. Dropping ALL and then adding many capabilities back gives the appearance \
+         of a hardened service while granting most of the default set anyway."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("List each capability once, and after 'cap_drop: [ALL]' add back only the few the service needs")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Capability settings reflect what the service is actually granted".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl CapabilityConflictRule {
+    fn issue(&self, service: &str, capability: &str, message: String) -> Issue {
+        Issue {
+            rule_id: self.id().to_string(),
+            rule_name: self.name().to_string(),
+            severity: self.severity(),
+            line_number: None,
+            message,
+            fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+            impact: self.impact(),
+            params: message_params([("service", &service), ("capability", &capability)]),
+        }
+    }
+}
+
+impl ComposeRule for CapabilityConflictRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                let added: Vec<String> = service.cap_add.iter().flatten().map(|c| normalize(c)).collect();
+                let dropped: Vec<String> = service.cap_drop.iter().flatten().map(|c| normalize(c)).collect();
+
+                for capability in added.iter().filter(|c| dropped.contains(c)) {
+                    issues.push(self.issue(
+                        name,
+                        capability,
+                        format!("Service '{}' both adds and drops capability {}", name, capability),
+                    ));
+                }
+
+                if dropped.iter().any(|c| c == "ALL") && added.len() > MAX_CAPS_AFTER_DROP_ALL {
+                    issues.push(self.issue(
+                        name,
+                        "ALL",
+                        format!(
+                            "Service '{}' drops ALL capabilities but adds {} back ({})",
+                            name,
+                            added.len(),
+                            added.join(", ")
+                        ),
+                    ));
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_drop_all_then_add_many() {
+        let content = r#"
+services:
+  app:
+    image: app:1.0
+    cap_drop: [ALL]
+    cap_add: [CHOWN, DAC_OVERRIDE, FOWNER, SETUID, SETGID, NET_BIND_SERVICE, NET_RAW]
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = CapabilityConflictRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'app'"));
+        assert!(issues[0].message.contains("adds 7 back"));
+    }
+
+    #[test]
+    fn test_detects_same_capability_added_and_dropped() {
+        let content = r#"
+services:
+  proxy:
+    image: proxy:1.0
+    cap_add: [NET_ADMIN]
+    cap_drop: [CAP_NET_ADMIN, MKNOD]
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = CapabilityConflictRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'proxy'"));
+        assert!(issues[0].message.contains("NET_ADMIN"));
+    }
+
+    #[test]
+    fn test_allows_drop_all_with_few_additions() {
+        let content = r#"
+services:
+  web:
+    image: web:1.0
+    cap_drop: [ALL]
+    cap_add: [NET_BIND_SERVICE]
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        assert_eq!(CapabilityConflictRule.check(&compose).len(), 0);
+    }
+}
@@ -0,0 +1,107 @@
+use super::capability_conflict::normalize;
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+
+/// Capabilities that let a container take over the host, with what they allow
+const DANGEROUS_CAPABILITIES: &[(&str, &str)] = &[
+    ("ALL", "every capability, equivalent to privileged mode"),
+    ("SYS_ADMIN", "mounting filesystems and most other admin operations"),
+    ("SYS_MODULE", "loading kernel modules"),
+    ("SYS_PTRACE", "tracing and injecting code into other processes"),
+    ("NET_ADMIN", "reconfiguring interfaces, routes and firewall rules"),
+];
+
+pub struct DangerousCapabilitiesRule;
+
+impl Rule for DangerousCapabilitiesRule {
+    fn id(&self) -> &'static str { "DC008" }
+    fn name(&self) -> &'static str { "Dangerous capability added" }
+    fn severity(&self) -> Severity { Severity::Critical }
+
+    fn description(&self) -> &'static str {
+        "Service adds a high-risk Linux capability such as SYS_ADMIN"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "SYS_ADMIN, SYS_MODULE and SYS_PTRACE each have known container escapes, and \
+         NET_ADMIN lets a container rewrite the networking that isolates it. Adding ALL \
+         is privileged mode under another name. A compromised service holding one of \
+         these can usually reach the host."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Remove the capability from cap_add and grant only narrow ones the service needs (e.g., NET_BIND_SERVICE)")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Critical - removes known container escape paths".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl ComposeRule for DangerousCapabilitiesRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                for capability in service.cap_add.iter().flatten() {
+                    let capability = normalize(capability);
+                    let Some((_, allows)) = DANGEROUS_CAPABILITIES.iter().find(|(cap, _)| *cap == capability) else {
+                        continue;
+                    };
+                    issues.push(Issue {
+                        rule_id: self.id().to_string(),
+                        rule_name: self.name().to_string(),
+                        severity: self.severity(),
+                        line_number: None,
+                        message: format!("Service '{}' adds capability {} ({})", name, capability, allows),
+                        fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                        impact: self.impact(),
+                        params: message_params([("service", &name), ("capability", &capability)]),
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_sys_admin() {
+        let content = r#"
+services:
+  fuse:
+    image: app:1.0
+    cap_add: [SYS_ADMIN, CAP_SYS_PTRACE, CHOWN]
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = DangerousCapabilitiesRule.check(&compose);
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message.contains("'fuse' adds capability SYS_ADMIN"));
+        assert!(issues[1].message.contains("SYS_PTRACE"));
+    }
+
+    #[test]
+    fn test_ignores_narrow_capabilities() {
+        let content = r#"
+services:
+  web:
+    image: nginx:1.25
+    cap_add: [NET_BIND_SERVICE]
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        assert_eq!(DangerousCapabilitiesRule.check(&compose).len(), 0);
+    }
+}
@@ -0,0 +1,150 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+
+/// Variables selecting the application environment
+const ENV_NAME_VARS: &[&str] = &[
+    "NODE_ENV", "APP_ENV", "RAILS_ENV", "RACK_ENV", "FLASK_ENV", "ENVIRONMENT",
+    "ASPNETCORE_ENVIRONMENT", "DOTNET_ENVIRONMENT", "SPRING_PROFILES_ACTIVE",
+];
+
+/// Variables that switch on debug mode
+const DEBUG_VARS: &[&str] = &["DEBUG", "APP_DEBUG", "FLASK_DEBUG", "DJANGO_DEBUG"];
+
+pub struct DevEnvironmentRule;
+
+impl Rule for DevEnvironmentRule {
+    fn id(&self) -> &'static str { "DC018" }
+    fn name(&self) -> &'static str { "Development mode in production" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "Production compose file sets a development or debug environment"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Frameworks change behavior based on variables like NODE_ENV or DEBUG. Development \
+         mode typically disables caching and optimizations, and debug mode can expose stack \
+         traces, interactive debuggers or verbose logs to users. In a file meant for \
+         production these values are almost always a copy-paste mistake."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Set the variable to its production value (e.g., NODE_ENV=production, DEBUG=false)")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Avoids exposing debug information in production".to_string()),
+            reliability_improvement: Some("Production optimizations stay enabled".to_string()),
+        })
+    }
+}
+
+fn is_development_value(key: &str, value: &str) -> bool {
+    let key = key.to_uppercase();
+    let value = value.trim().trim_matches(['"', '\'']).to_lowercase();
+
+    if ENV_NAME_VARS.contains(&key.as_str()) {
+        return matches!(value.as_str(), "development" | "dev" | "local");
+    }
+    if DEBUG_VARS.contains(&key.as_str()) {
+        return matches!(value.as_str(), "true" | "1" | "yes" | "on");
+    }
+    false
+}
+
+impl ComposeRule for DevEnvironmentRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let production_file = compose.is_production_file();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                if !production_file && !service.has_production_profile() {
+                    continue;
+                }
+                let Some(environment) = &service.environment else {
+                    continue;
+                };
+
+                let mut pairs = environment.pairs();
+                pairs.sort();
+                for (key, value) in pairs {
+                    let Some(value) = value else {
+                        continue;
+                    };
+                    if is_development_value(&key, &value) {
+                        issues.push(Issue {
+                            rule_id: self.id().to_string(),
+                            rule_name: self.name().to_string(),
+                            severity: self.severity(),
+                            line_number: None,
+                            message: format!("Service '{}' sets {}={} in a production configuration", name, key, value),
+                            fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                            impact: self.impact(),
+                            params: message_params([("service", &name), ("key", &key), ("value", &value)]),
+                        });
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+    use std::path::PathBuf;
+
+    const CONTENT: &str = r#"
+services:
+  web:
+    image: myapp:1.0
+    environment:
+      - NODE_ENV=development
+      - PORT=3000
+  worker:
+    image: worker:1.0
+    environment:
+      DEBUG: true
+      APP_ENV: production
+"#;
+
+    #[test]
+    fn test_detects_dev_values_in_prod_file() {
+        let mut compose = ComposeParser::parse_content(CONTENT).unwrap();
+        compose.source_path = Some(PathBuf::from("docker-compose.prod.yml"));
+        let mut issues = DevEnvironmentRule.check(&compose);
+        issues.sort_by(|a, b| a.message.cmp(&b.message));
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message.contains("'web' sets NODE_ENV=development"));
+        assert!(issues[1].message.contains("'worker' sets DEBUG=true"));
+    }
+
+    #[test]
+    fn test_ignores_non_production_file() {
+        let mut compose = ComposeParser::parse_content(CONTENT).unwrap();
+        compose.source_path = Some(PathBuf::from("docker-compose.yml"));
+        assert_eq!(DevEnvironmentRule.check(&compose).len(), 0);
+    }
+
+    #[test]
+    fn test_detects_production_profile() {
+        let content = r#"
+services:
+  web:
+    image: myapp:1.0
+    profiles: [production]
+    environment:
+      RAILS_ENV: development
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        assert_eq!(DevEnvironmentRule.check(&compose).len(), 1);
+    }
+}
@@ -0,0 +1,110 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+
+/// Host paths of the Docker daemon socket (`/var/run` links to `/run` on most hosts)
+const DOCKER_SOCKETS: &[&str] = &["/var/run/docker.sock", "/run/docker.sock"];
+
+pub struct DockerSockRule;
+
+impl Rule for DockerSockRule {
+    fn id(&self) -> &'static str { "DC006" }
+    fn name(&self) -> &'static str { "Docker socket mounted" }
+    fn severity(&self) -> Severity { Severity::Critical }
+
+    fn description(&self) -> &'static str {
+        "Service mounts the Docker daemon socket"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Whoever can talk to the Docker socket can start a privileged container with the \
+         host filesystem mounted, which is root on the host. Mounting it read-only does not \
+         help, since API calls are writes on the socket, not the file. A compromised \
+         service with the socket is a full host compromise."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Remove the docker.sock mount, or put a filtering proxy (e.g. docker-socket-proxy) in front that allows only the API calls the service needs")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Critical - prevents container escape through the Docker API".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl ComposeRule for DockerSockRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                let mounts = service.volumes.iter().flatten();
+                // Short syntax: source:target or source:target:mode
+                let Some(source) = mounts
+                    .filter_map(|volume| volume.split(':').next())
+                    .find(|source| DOCKER_SOCKETS.contains(&source.trim_end_matches('/')))
+                else {
+                    continue;
+                };
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: None,
+                    message: format!("Service '{}' mounts the Docker socket ({}), giving it root on the host", name, source),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: message_params([("service", &name), ("path", &source)]),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_docker_sock_mount() {
+        let content = r#"
+services:
+  traefik:
+    image: traefik:v3.0
+    volumes:
+      - /var/run/docker.sock:/var/run/docker.sock:ro
+  db:
+    image: postgres:16
+    volumes:
+      - db-data:/var/lib/postgresql/data
+volumes:
+  db-data:
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = DockerSockRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'traefik'"));
+    }
+
+    #[test]
+    fn test_allows_normal_volumes() {
+        let content = r#"
+services:
+  app:
+    image: app:1.0
+    volumes:
+      - ./config:/etc/app:ro
+      - /var/run/app:/var/run/app
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        assert_eq!(DockerSockRule.check(&compose).len(), 0);
+    }
+}
@@ -0,0 +1,130 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+use std::collections::BTreeMap;
+
+pub struct DuplicateEnvironmentRule;
+
+impl Rule for DuplicateEnvironmentRule {
+    fn id(&self) -> &'static str { "DC023" }
+    fn name(&self) -> &'static str { "Duplicated environment values" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "The same environment variable and value is repeated across services"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A value copied into several services has to be changed in every copy, and the \
+         copies drift apart when one is missed. Shared settings and credentials belong in \
+         one place, such as the .env file used for interpolation, a shared env_file or a \
+         YAML anchor, so a change reaches every service at once."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Define the value once in .env and reference it as ${VAR}, or share it through an env_file or a YAML anchor")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Shared settings cannot drift between services".to_string()),
+        })
+    }
+}
+
+impl ComposeRule for DuplicateEnvironmentRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let Some(services) = &compose.services else {
+            return issues;
+        };
+
+        // (key, value) -> services setting it
+        let mut users: BTreeMap<(String, String), Vec<&str>> = BTreeMap::new();
+        for (name, service) in services {
+            let Some(environment) = &service.environment else {
+                continue;
+            };
+            for (key, value) in environment.pairs() {
+                // Pass-through and interpolated values already come from one place
+                let Some(value) = value.filter(|v| !v.is_empty() && !v.contains('$')) else {
+                    continue;
+                };
+                users.entry((key, value)).or_default().push(name.as_str());
+            }
+        }
+
+        for ((key, _), mut names) in users {
+            if names.len() < 2 {
+                continue;
+            }
+            names.sort();
+            let services = names.join(", ");
+            issues.push(Issue {
+                rule_id: self.id().to_string(),
+                rule_name: self.name().to_string(),
+                severity: self.severity(),
+                line_number: None,
+                message: format!("{} is set to the same value in services {}", key, services),
+                fix_suggestion: Some(format!(
+                    "Define {} once in .env and use '{}: ${{{}}}' in each service, or share it via an env_file",
+                    key, key, key
+                )),
+                impact: self.impact(),
+                params: message_params([("key", &key), ("services", &services)]),
+            });
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_duplicated_tz() {
+        let content = r#"
+services:
+  api:
+    image: api:1.0
+    environment:
+      - TZ=UTC
+      - PORT=3000
+  worker:
+    image: worker:1.0
+    environment:
+      TZ: UTC
+      PORT: 4000
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = DuplicateEnvironmentRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].message, "TZ is set to the same value in services api, worker");
+    }
+
+    #[test]
+    fn test_ignores_interpolated_and_single_service_values() {
+        let content = r#"
+services:
+  api:
+    image: api:1.0
+    environment:
+      TZ: ${TZ}
+      LOG_LEVEL: info
+      HOME:
+  worker:
+    image: worker:1.0
+    environment:
+      TZ: ${TZ}
+      HOME:
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        assert_eq!(DuplicateEnvironmentRule.check(&compose).len(), 0);
+    }
+}
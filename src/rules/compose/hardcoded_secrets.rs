@@ -1,4 +1,4 @@
-use super::{ComposeRule, Issue, Severity, ImpactEstimate};
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
 use crate::parser::{ComposeFile, Environment};
 use crate::rules::Rule;
 use regex::Regex;
@@ -82,6 +82,7 @@ impl ComposeRule for HardcodedSecretsRule {
                             message: format!("Service '{}' has hardcoded secret in environment", name),
                             fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                             impact: self.impact(),
+                            params: message_params([("service", &name)]),
                         });
                     }
                 }
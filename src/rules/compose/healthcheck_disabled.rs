@@ -0,0 +1,103 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+
+pub struct HealthcheckDisabledRule;
+
+impl Rule for HealthcheckDisabledRule {
+    fn id(&self) -> &'static str { "DC024" }
+    fn name(&self) -> &'static str { "Healthcheck disabled" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "Service turns off the healthcheck defined by its image"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "'disable: true' (or test: [\"NONE\"]) removes the HEALTHCHECK the image author \
+         shipped. The container is then reported healthy as soon as it starts, so \
+         'depends_on: condition: service_healthy' and orchestrators stop noticing a hung \
+         process. Sometimes that is intended, e.g. for a one-off task, but it should be a \
+         deliberate choice."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Remove 'disable: true' to keep the image's healthcheck, or define a 'test' that fits how the service is run")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Unhealthy containers are detected and restarted".to_string()),
+        })
+    }
+}
+
+impl ComposeRule for HealthcheckDisabledRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                if !service.healthcheck.as_ref().is_some_and(|h| h.is_disabled()) {
+                    continue;
+                }
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: None,
+                    message: format!("Service '{}' disables its healthcheck; confirm this is intended", name),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: message_params([("service", &name)]),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_disabled_healthcheck() {
+        let content = r#"
+services:
+  migrate:
+    image: app:1.0
+    healthcheck: { disable: true }
+  worker:
+    image: app:1.0
+    healthcheck:
+      test: ["NONE"]
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let mut issues = HealthcheckDisabledRule.check(&compose);
+        issues.sort_by(|a, b| a.message.cmp(&b.message));
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message.contains("'migrate'"));
+        assert!(issues[1].message.contains("'worker'"));
+    }
+
+    #[test]
+    fn test_allows_real_healthcheck() {
+        let content = r#"
+services:
+  web:
+    image: nginx:1.25
+    healthcheck:
+      test: ["CMD", "curl", "-f", "http://localhost/"]
+      interval: 30s
+      disable: false
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        assert_eq!(HealthcheckDisabledRule.check(&compose).len(), 0);
+    }
+}
@@ -0,0 +1,104 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+static LOCALHOST: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\blocalhost\b").unwrap()
+});
+
+pub struct ComposeHealthcheckLocalhostRule;
+
+impl Rule for ComposeHealthcheckLocalhostRule {
+    fn id(&self) -> &'static str { "DC017" }
+    fn name(&self) -> &'static str { "Healthcheck uses localhost" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "Service healthcheck targets 'localhost' instead of an explicit loopback address"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "'localhost' may resolve to the IPv6 address ::1 first. A service listening only \
+         on IPv4 then refuses the healthcheck connection, the container is marked \
+         unhealthy, and services using 'depends_on: condition: service_healthy' never start."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Use 127.0.0.1 explicitly (e.g., test: [\"CMD\", \"curl\", \"-f\", \"http://127.0.0.1:8080/health\"])")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Avoids false unhealthy status from IPv6 resolution".to_string()),
+        })
+    }
+}
+
+impl ComposeRule for ComposeHealthcheckLocalhostRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                let Some(command) = service.healthcheck.as_ref().and_then(|h| h.command()) else {
+                    continue;
+                };
+
+                if LOCALHOST.is_match(&command) {
+                    issues.push(Issue {
+                        rule_id: self.id().to_string(),
+                        rule_name: self.name().to_string(),
+                        severity: self.severity(),
+                        line_number: None,
+                        message: format!("Service '{}' healthcheck targets 'localhost', which may resolve to IPv6 ::1", name),
+                        fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                        impact: self.impact(),
+                        params: message_params([("service", &name)]),
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_localhost_healthcheck() {
+        let content = r#"
+services:
+  api:
+    image: api:1.0
+    healthcheck:
+      test: ["CMD", "curl", "-f", "http://localhost:8080/health"]
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = ComposeHealthcheckLocalhostRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'api'"));
+    }
+
+    #[test]
+    fn test_allows_loopback_ip() {
+        let content = r#"
+services:
+  api:
+    image: api:1.0
+    healthcheck:
+      test: curl -f http://127.0.0.1:8080/health
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = ComposeHealthcheckLocalhostRule.check(&compose);
+        assert_eq!(issues.len(), 0);
+    }
+}
@@ -0,0 +1,115 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+
+pub struct HostNamespacesRule;
+
+impl Rule for HostNamespacesRule {
+    fn id(&self) -> &'static str { "DC019" }
+    fn name(&self) -> &'static str { "Host PID or IPC namespace" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "Service shares the host's PID or IPC namespace"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "With 'pid: host' the container sees and can signal every process on the host, \
+         and with 'ipc: host' it can read and write the host's shared memory segments. \
+         Both remove a layer of isolation between the container and the host, much like \
+         privileged mode does."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Remove 'pid: host' / 'ipc: host', or share a namespace with a single container (e.g., 'pid: service:app')")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("High - keeps host processes and shared memory isolated".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl ComposeRule for HostNamespacesRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                for (setting, value) in [("pid", &service.pid), ("ipc", &service.ipc)] {
+                    if value.as_deref() == Some("host") {
+                        issues.push(Issue {
+                            rule_id: self.id().to_string(),
+                            rule_name: self.name().to_string(),
+                            severity: self.severity(),
+                            line_number: None,
+                            message: format!("Service '{}' sets '{}: host' and shares the host's {} namespace", name, setting, setting.to_uppercase()),
+                            fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                            impact: self.impact(),
+                            params: message_params([("service", &name), ("setting", &setting)]),
+                        });
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_pid_host() {
+        let content = r#"
+services:
+  monitor:
+    image: monitor:1.0
+    pid: host
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = HostNamespacesRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("monitor"));
+        assert!(issues[0].message.contains("pid: host"));
+    }
+
+    #[test]
+    fn test_detects_ipc_host() {
+        let content = r#"
+services:
+  worker:
+    image: worker:1.0
+    ipc: host
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = HostNamespacesRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("worker"));
+        assert!(issues[0].message.contains("ipc: host"));
+    }
+
+    #[test]
+    fn test_allows_container_scoped_namespaces() {
+        let content = r#"
+services:
+  app:
+    image: app:1.0
+    ipc: shareable
+  sidecar:
+    image: sidecar:1.0
+    pid: "service:app"
+    ipc: "container:app"
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = HostNamespacesRule.check(&compose);
+        assert_eq!(issues.len(), 0);
+    }
+}
@@ -0,0 +1,111 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+
+pub struct HostNetworkRule;
+
+impl Rule for HostNetworkRule {
+    fn id(&self) -> &'static str { "DC007" }
+    fn name(&self) -> &'static str { "Host or shared network mode" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "Service uses 'network_mode: host' or joins another container's network stack"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "With 'network_mode: host' the container has no network isolation: it can bind \
+         any host port, reach services listening only on the host's localhost and see all \
+         host interfaces. 'container:<name>' shares another container's stack, so each \
+         can reach whatever the other listens on. A user-defined network with published \
+         ports is almost always enough."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Remove 'network_mode' and attach the service to a user-defined network, publishing only the ports it needs")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Keeps the service's network isolated from the host".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl ComposeRule for HostNetworkRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                let Some(mode) = service.network_mode.as_deref() else {
+                    continue;
+                };
+                let message = if mode == "host" {
+                    format!(
+                        "Service '{}' uses host networking, so it shares the host's interfaces and ports with no network isolation",
+                        name
+                    )
+                } else if let Some(container) = mode.strip_prefix("container:") {
+                    format!(
+                        "Service '{}' shares the network stack of container '{}', so neither is isolated from the other",
+                        name, container
+                    )
+                } else {
+                    continue;
+                };
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: None,
+                    message,
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: message_params([("service", &name), ("mode", &mode)]),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_host_network_mode() {
+        let content = r#"
+services:
+  monitor:
+    image: netdata/netdata:v1.44
+    network_mode: host
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = HostNetworkRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'monitor'"));
+        assert!(issues[0].message.contains("no network isolation"));
+    }
+
+    #[test]
+    fn test_allows_named_network() {
+        let content = r#"
+services:
+  api:
+    image: api:1.0
+    networks:
+      - backend
+networks:
+  backend:
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        assert_eq!(HostNetworkRule.check(&compose).len(), 0);
+    }
+}
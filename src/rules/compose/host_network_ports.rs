@@ -0,0 +1,99 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+
+pub struct HostNetworkPortsRule;
+
+impl Rule for HostNetworkPortsRule {
+    fn id(&self) -> &'static str { "DC011" }
+    fn name(&self) -> &'static str { "Ports ignored with host networking" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "Service publishes ports while using 'network_mode: host'"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "With 'network_mode: host' the container shares the host's network stack, so \
+         Docker ignores any 'ports' mappings. Their presence suggests the author expected \
+         port publishing to apply, which hides the fact that the service binds directly \
+         on the host."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Remove 'ports' when using host networking, or drop 'network_mode: host' to use port mappings")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Configuration reflects actual network behavior".to_string()),
+        })
+    }
+}
+
+impl ComposeRule for HostNetworkPortsRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                let is_host_network = service.network_mode.as_deref() == Some("host");
+                let has_ports = service.ports.as_ref().is_some_and(|p| !p.is_empty());
+
+                if is_host_network && has_ports {
+                    issues.push(Issue {
+                        rule_id: self.id().to_string(),
+                        rule_name: self.name().to_string(),
+                        severity: self.severity(),
+                        line_number: None,
+                        message: format!("Service '{}' uses host networking, so its port mappings are ignored", name),
+                        fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                        impact: self.impact(),
+                        params: message_params([("service", &name)]),
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_ports_with_host_network() {
+        let content = r#"
+services:
+  web:
+    image: nginx:1.25
+    network_mode: host
+    ports:
+      - "80:80"
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = HostNetworkPortsRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("web"));
+    }
+
+    #[test]
+    fn test_allows_ports_without_host_network() {
+        let content = r#"
+services:
+  web:
+    image: nginx:1.25
+    ports:
+      - "80:80"
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = HostNetworkPortsRule.check(&compose);
+        assert_eq!(issues.len(), 0);
+    }
+}
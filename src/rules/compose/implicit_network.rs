@@ -0,0 +1,110 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+
+pub struct ImplicitNetworkRule;
+
+impl Rule for ImplicitNetworkRule {
+    fn id(&self) -> &'static str { "DC016" }
+    fn name(&self) -> &'static str { "Service without explicit networks" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "Service does not set 'networks' in a file that declares several networks"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A service without 'networks' joins only the project's default network. When a \
+         file splits services across several named networks, an unassigned service often \
+         cannot reach the services it needs (or can reach ones it should not), and the \
+         resulting connectivity bugs only show up at runtime."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("List the networks the service should join under 'networks:'")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Intended network topology is explicit".to_string()),
+        })
+    }
+}
+
+impl ComposeRule for ImplicitNetworkRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        let declared = compose.networks.as_ref().map_or(0, |n| n.len());
+        if declared < 2 {
+            return issues;
+        }
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                if service.networks.is_none() && service.network_mode.is_none() {
+                    issues.push(Issue {
+                        rule_id: self.id().to_string(),
+                        rule_name: self.name().to_string(),
+                        severity: self.severity(),
+                        line_number: None,
+                        message: format!("Service '{}' has no 'networks' and will only join the default network", name),
+                        fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                        impact: self.impact(),
+                        params: message_params([("service", &name)]),
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_unassigned_service() {
+        let content = r#"
+services:
+  web:
+    image: nginx:1.25
+    networks: [frontend]
+  api:
+    image: api:1.0
+    networks: [frontend, backend]
+  worker:
+    image: worker:1.0
+networks:
+  frontend:
+  backend:
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = ImplicitNetworkRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'worker'"));
+    }
+
+    #[test]
+    fn test_ignores_single_network_file() {
+        let content = r#"
+services:
+  web:
+    image: nginx:1.25
+  api:
+    image: api:1.0
+    networks: [backend]
+networks:
+  backend:
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = ImplicitNetworkRule.check(&compose);
+        assert_eq!(issues.len(), 0);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ComposeRule, Issue, Severity, ImpactEstimate};
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
 use crate::parser::ComposeFile;
 use crate::rules::Rule;
 
@@ -50,6 +50,7 @@ impl ComposeRule for ComposeLatestTagRule {
                             message: format!("Service '{}' uses image with ':latest' tag: {}", name, image),
                             fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                             impact: self.impact(),
+                            params: message_params([("service", &name), ("image", &image)]),
                         });
                     }
                     // Check for missing tag (implicit latest)
@@ -62,6 +63,7 @@ impl ComposeRule for ComposeLatestTagRule {
                             message: format!("Service '{}' uses image without tag (implicitly 'latest'): {}", name, image),
                             fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                             impact: self.impact(),
+                            params: message_params([("service", &name), ("image", &image)]),
                         });
                     }
                 }
@@ -0,0 +1,103 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+
+pub struct ComposeHealthcheckRule;
+
+impl Rule for ComposeHealthcheckRule {
+    fn id(&self) -> &'static str { "DC010" }
+    fn name(&self) -> &'static str { "No healthcheck" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "Service defines no healthcheck"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Without a healthcheck, Compose considers a service ready as soon as its process \
+         starts, so dependents using 'condition: service_healthy' cannot wait for it and a \
+         hung process is never noticed. The image may ship its own HEALTHCHECK, which this \
+         check cannot see, so it is only a suggestion."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Add a healthcheck (e.g., test: [\"CMD\", \"pg_isready\"]) or confirm the image defines a HEALTHCHECK")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Dependents start only once the service is ready".to_string()),
+        })
+    }
+}
+
+impl ComposeRule for ComposeHealthcheckRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                // Definitions without an image or build (e.g. volume holders) run nothing to check
+                if service.image.is_none() && service.build.is_none() {
+                    continue;
+                }
+                if service.healthcheck.is_some() {
+                    continue;
+                }
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: None,
+                    message: format!("Service '{}' has no healthcheck", name),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: message_params([("service", &name)]),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_missing_healthcheck() {
+        let content = r#"
+services:
+  api:
+    build: .
+  data:
+    volumes:
+      - data:/data
+volumes:
+  data:
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = ComposeHealthcheckRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].message, "Service 'api' has no healthcheck");
+    }
+
+    #[test]
+    fn test_allows_inline_healthcheck() {
+        let content = r#"
+services:
+  db:
+    image: postgres:16
+    healthcheck:
+      test: ["CMD", "pg_isready", "-U", "postgres"]
+      interval: 10s
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        assert_eq!(ComposeHealthcheckRule.check(&compose).len(), 0);
+    }
+}
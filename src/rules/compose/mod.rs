@@ -3,14 +3,54 @@ mod privileged;
 mod resource_limits;
 mod latest_tag;
 mod hardcoded_secrets;
+mod host_network_ports;
+mod volumes_from;
+mod admin_ports;
+mod unused_resources;
+mod unpublished_entrypoint;
+mod implicit_network;
+mod healthcheck_localhost;
+mod dev_environment;
+mod host_namespaces;
+mod build_context_escape;
+mod capability_conflict;
+mod port_syntax;
+mod duplicate_environment;
+mod docker_sock;
+mod healthcheck_disabled;
+mod host_network;
+mod dangerous_capabilities;
+mod unbuilt_image;
+mod port_binding;
+mod missing_healthcheck;
 
 pub use restart_policy::RestartPolicyRule;
 pub use privileged::PrivilegedRule;
 pub use resource_limits::ResourceLimitsRule;
 pub use latest_tag::ComposeLatestTagRule;
 pub use hardcoded_secrets::HardcodedSecretsRule;
+pub use host_network_ports::HostNetworkPortsRule;
+pub use volumes_from::VolumesFromRule;
+pub use admin_ports::AdminPortsRule;
+pub use unused_resources::UnusedResourcesRule;
+pub use unpublished_entrypoint::UnpublishedEntrypointRule;
+pub use implicit_network::ImplicitNetworkRule;
+pub use healthcheck_localhost::ComposeHealthcheckLocalhostRule;
+pub use dev_environment::DevEnvironmentRule;
+pub use host_namespaces::HostNamespacesRule;
+pub use build_context_escape::BuildContextEscapeRule;
+pub use capability_conflict::CapabilityConflictRule;
+pub use port_syntax::PortSyntaxRule;
+pub use duplicate_environment::DuplicateEnvironmentRule;
+pub use docker_sock::DockerSockRule;
+pub use healthcheck_disabled::HealthcheckDisabledRule;
+pub use host_network::HostNetworkRule;
+pub use dangerous_capabilities::DangerousCapabilitiesRule;
+pub use unbuilt_image::UnbuiltImageRule;
+pub use missing_healthcheck::ComposeHealthcheckRule;
+pub use port_binding::{PortBindingRule, DEFAULT_SENSITIVE_PORTS};
 
-use super::{Rule, Issue, Severity, ImpactEstimate};
+use super::{Rule, Issue, Severity, ImpactEstimate, message_params};
 use crate::parser::ComposeFile;
 
 /// Trait for Compose-specific rules
@@ -0,0 +1,144 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+use std::borrow::Cow;
+
+/// Database, cache and admin ports that should not be reachable from the network
+pub const DEFAULT_SENSITIVE_PORTS: &[u16] = &[
+    1433,  // SQL Server
+    3306,  // MySQL / MariaDB
+    5432,  // PostgreSQL
+    5984,  // CouchDB
+    6379,  // Redis
+    9042,  // Cassandra
+    9200,  // Elasticsearch
+    11211, // Memcached
+    27017, // MongoDB
+];
+
+/// Flags sensitive container ports published on all host interfaces
+pub struct PortBindingRule {
+    pub ports: Cow<'static, [u16]>,
+}
+
+impl PortBindingRule {
+    pub fn new(ports: Vec<u16>) -> Self {
+        Self { ports: Cow::Owned(ports) }
+    }
+}
+
+impl Default for PortBindingRule {
+    fn default() -> Self {
+        Self { ports: Cow::Borrowed(DEFAULT_SENSITIVE_PORTS) }
+    }
+}
+
+impl Rule for PortBindingRule {
+    fn id(&self) -> &'static str { "DC009" }
+    fn name(&self) -> &'static str { "Sensitive port on all interfaces" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "Service publishes a database or cache port without binding it to 127.0.0.1"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "\"5432:5432\" is published on 0.0.0.0, so the database is reachable from every \
+         network the host is on, not just from the machine itself. Docker also inserts its \
+         own firewall rules, so host firewalls like ufw do not block it. Databases and \
+         caches often run with weak or no authentication in development setups."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Prefix the mapping with 127.0.0.1 (e.g., \"127.0.0.1:5432:5432\"), or drop 'ports' and reach it over the compose network")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Keeps data stores off the network".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl ComposeRule for PortBindingRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                for port in service.port_mappings() {
+                    if port.is_loopback() {
+                        continue;
+                    }
+                    let Some(target) = port.target_port().filter(|t| self.ports.contains(t)) else {
+                        continue;
+                    };
+                    issues.push(Issue {
+                        rule_id: self.id().to_string(),
+                        rule_name: self.name().to_string(),
+                        severity: self.severity(),
+                        line_number: None,
+                        message: format!("Service '{}' publishes port {} on all host interfaces", name, target),
+                        fix_suggestion: Some(format!(
+                            "Bind it to localhost: \"127.0.0.1:{}:{}\"",
+                            port.published.as_deref().unwrap_or(&target.to_string()),
+                            target
+                        )),
+                        impact: self.impact(),
+                        params: message_params([("service", &name), ("port", &target)]),
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_database_on_all_interfaces() {
+        let content = r#"
+services:
+  db:
+    image: postgres:16
+    ports:
+      - "5432:5432"
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = PortBindingRule::default().check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].message, "Service 'db' publishes port 5432 on all host interfaces");
+    }
+
+    #[test]
+    fn test_allows_loopback_binding() {
+        let content = r#"
+services:
+  db:
+    image: postgres:16
+    ports:
+      - "127.0.0.1:5432:5432"
+  web:
+    image: nginx:1.25
+    ports:
+      - "8080:80"
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        assert_eq!(PortBindingRule::default().check(&compose).len(), 0);
+    }
+
+    #[test]
+    fn test_custom_port_list() {
+        let content = "services:\n  app:\n    image: app:1.0\n    ports:\n      - \"8080:80\"\n";
+        let compose = ComposeParser::parse_content(content).unwrap();
+        assert_eq!(PortBindingRule::new(vec![80]).check(&compose).len(), 1);
+    }
+}
@@ -0,0 +1,191 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::{ComposeFile, PortEntry};
+use crate::rules::Rule;
+
+/// Well-known ports whose services speak UDP
+const UDP_SERVICES: &[(u16, &str)] = &[
+    (53, "DNS"),
+    (67, "DHCP"),
+    (69, "TFTP"),
+    (123, "NTP"),
+    (161, "SNMP"),
+    (514, "syslog"),
+    (1194, "OpenVPN"),
+    (1812, "RADIUS"),
+    (3478, "STUN/TURN"),
+    (5353, "mDNS"),
+    (8125, "StatsD"),
+    (51820, "WireGuard"),
+];
+
+pub struct PortSyntaxRule;
+
+impl Rule for PortSyntaxRule {
+    fn id(&self) -> &'static str { "DC022" }
+    fn name(&self) -> &'static str { "Unclear port syntax" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "Long-syntax port without protocol for a UDP service, or short and long port syntax mixed"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A long-syntax port entry without 'protocol' is published as TCP, so a DNS, NTP or \
+         similar UDP service is unreachable on that port. Mixing the short (\"8080:80\") and \
+         long ({ target, published }) syntax in one file makes the port layout harder to \
+         read and review."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Set 'protocol: udp' for UDP services and use one port syntax throughout the file")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("UDP services are published with the right protocol".to_string()),
+        })
+    }
+}
+
+impl ComposeRule for PortSyntaxRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let Some(services) = &compose.services else {
+            return issues;
+        };
+
+        let mut names: Vec<&String> = services.keys().collect();
+        names.sort();
+
+        let mut short_users = Vec::new();
+        let mut long_users = Vec::new();
+
+        for name in names {
+            let ports = services[name].ports.iter().flatten();
+            let mut uses_short = false;
+            let mut uses_long = false;
+
+            for entry in ports {
+                let port = match entry {
+                    PortEntry::Short(_) => {
+                        uses_short = true;
+                        continue;
+                    }
+                    PortEntry::Long(port) => port,
+                };
+                uses_long = true;
+
+                let udp_service = port.target.parse::<u16>().ok()
+                    .and_then(|target| UDP_SERVICES.iter().find(|(p, _)| *p == target));
+                if let (None, Some((target, protocol))) = (&port.protocol, udp_service) {
+                    issues.push(Issue {
+                        rule_id: self.id().to_string(),
+                        rule_name: self.name().to_string(),
+                        severity: self.severity(),
+                        line_number: None,
+                        message: format!(
+                            "Service '{}' publishes port {} ({}) without 'protocol', so it defaults to tcp",
+                            name, target, protocol
+                        ),
+                        fix_suggestion: Some(format!("Add 'protocol: udp' to the port {} entry", target)),
+                        impact: self.impact(),
+                        params: message_params([("service", &name), ("port", &target)]),
+                    });
+                }
+            }
+
+            if uses_short {
+                short_users.push(name.as_str());
+            }
+            if uses_long {
+                long_users.push(name.as_str());
+            }
+        }
+
+        if !short_users.is_empty() && !long_users.is_empty() {
+            let short = short_users.join(", ");
+            let long = long_users.join(", ");
+            issues.push(Issue {
+                rule_id: self.id().to_string(),
+                rule_name: self.name().to_string(),
+                severity: self.severity(),
+                line_number: None,
+                message: format!("Ports mix short syntax ({}) and long syntax ({})", short, long),
+                fix_suggestion: Some("Use the same port syntax for every service in the file".to_string()),
+                impact: self.impact(),
+                params: message_params([("short", &short), ("long", &long)]),
+            });
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_long_syntax_udp_without_protocol() {
+        let content = r#"
+services:
+  dns:
+    image: coredns/coredns:1.11.1
+    ports:
+      - target: 53
+        published: 53
+      - target: 53
+        published: 53
+        protocol: tcp
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = PortSyntaxRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'dns'"));
+        assert!(issues[0].message.contains("53 (DNS)"));
+    }
+
+    #[test]
+    fn test_detects_mixed_syntax() {
+        let content = r#"
+services:
+  web:
+    image: nginx:1.25
+    ports:
+      - "8080:80"
+  api:
+    image: api:1.0
+    ports:
+      - target: 3000
+        published: 3000
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = PortSyntaxRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].message, "Ports mix short syntax (web) and long syntax (api)");
+    }
+
+    #[test]
+    fn test_allows_consistent_long_syntax_with_protocol() {
+        let content = r#"
+services:
+  ntp:
+    image: cturra/ntp:latest
+    ports:
+      - target: 123
+        published: 123
+        protocol: udp
+  web:
+    image: nginx:1.25
+    ports:
+      - target: 80
+        published: 8080
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        assert_eq!(PortSyntaxRule.check(&compose).len(), 0);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ComposeRule, Issue, Severity, ImpactEstimate};
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
 use crate::parser::ComposeFile;
 use crate::rules::Rule;
 
@@ -48,6 +48,7 @@ impl ComposeRule for PrivilegedRule {
                         message: format!("Service '{}' runs in privileged mode", name),
                         fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                         impact: self.impact(),
+                        params: message_params([("service", &name)]),
                     });
                 }
             }
@@ -1,4 +1,4 @@
-use super::{ComposeRule, Issue, Severity, ImpactEstimate};
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
 use crate::parser::ComposeFile;
 use crate::rules::Rule;
 
@@ -56,6 +56,7 @@ impl ComposeRule for ResourceLimitsRule {
                         message: format!("Service '{}' has no resource limits", name),
                         fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                         impact: self.impact(),
+                        params: message_params([("service", &name)]),
                     });
                 }
             }
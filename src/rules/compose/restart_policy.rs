@@ -1,4 +1,4 @@
-use super::{ComposeRule, Issue, Severity, ImpactEstimate};
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
 use crate::parser::ComposeFile;
 use crate::rules::Rule;
 
@@ -54,6 +54,7 @@ impl ComposeRule for RestartPolicyRule {
                         message: format!("Service '{}' has no restart policy", name),
                         fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                         impact: self.impact(),
+                        params: message_params([("service", &name)]),
                     });
                 }
             }
@@ -0,0 +1,156 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+
+/// Docker Official Images, which are pulled from Docker Hub without a namespace
+const OFFICIAL_IMAGES: &[&str] = &[
+    "adminer", "almalinux", "alpine", "amazoncorretto", "amazonlinux", "arangodb", "backdrop",
+    "bash", "bonita", "buildpack-deps", "busybox", "caddy", "cassandra", "centos", "chronograf",
+    "clickhouse", "clojure", "composer", "consul", "couchbase", "couchdb", "crate", "debian",
+    "docker", "drupal", "eclipse-mosquitto", "eclipse-temurin", "elasticsearch", "elixir",
+    "erlang", "fedora", "flink", "fluentd", "gcc", "ghost", "golang", "gradle", "groovy",
+    "haproxy", "haskell", "hello-world", "httpd", "ibmjava", "influxdb", "irssi", "jetty",
+    "joomla", "jruby", "julia", "kapacitor", "kibana", "kong", "logstash", "mariadb", "matomo",
+    "maven", "mediawiki", "memcached", "mongo", "mongo-express", "mono", "mysql", "nats",
+    "neo4j", "nextcloud", "nginx", "node", "odoo", "openjdk", "oraclelinux", "perl", "php",
+    "php-zendserver", "phpmyadmin", "postfixadmin", "postgres", "pypy", "python", "rabbitmq",
+    "redis", "redmine", "registry", "rethinkdb", "rocket.chat", "rockylinux", "ruby", "rust",
+    "sapmachine", "solr", "sonarqube", "spark", "swift", "telegraf", "tomcat", "traefik",
+    "ubuntu", "varnish", "wordpress", "xwiki", "yourls", "zookeeper",
+];
+
+/// Repository name of an image reference, without tag or digest
+fn repository(image: &str) -> &str {
+    let without_digest = image.split('@').next().unwrap_or(image);
+    match without_digest.rsplit_once(':') {
+        // A colon after the last slash separates the tag; before it, a registry port
+        Some((name, tag)) if !tag.contains('/') => name,
+        _ => without_digest,
+    }
+}
+
+pub struct UnbuiltImageRule;
+
+impl Rule for UnbuiltImageRule {
+    fn id(&self) -> &'static str { "DC025" }
+    fn name(&self) -> &'static str { "Custom image without build or registry" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "Service uses an unqualified custom image that nothing in the file builds"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "An image like 'myapp:1.0' with no namespace and no registry host is looked up as \
+         an official image on Docker Hub. If it was meant to be built locally, 'docker \
+         compose up' works only on machines that happen to have it and fails to pull \
+         everywhere else - or pulls an unrelated public image of the same name."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Add a 'build:' section for locally built images, or use the full registry reference (e.g., ghcr.io/org/myapp:1.0)")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Avoids pulling an unrelated public image by name".to_string()),
+            reliability_improvement: Some("The image resolves the same way on every machine".to_string()),
+        })
+    }
+}
+
+impl ComposeRule for UnbuiltImageRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let Some(services) = &compose.services else {
+            return issues;
+        };
+
+        // Images tagged by a service that builds them are available to the others
+        let built: Vec<&str> = services
+            .values()
+            .filter(|s| s.build.is_some())
+            .filter_map(|s| s.image.as_deref())
+            .map(repository)
+            .collect();
+
+        for (name, service) in services {
+            let Some(image) = service.image.as_deref() else {
+                continue;
+            };
+            if service.build.is_some() || image.contains('$') {
+                continue;
+            }
+            let repo = repository(image);
+            if repo.contains('/') || OFFICIAL_IMAGES.contains(&repo) || built.contains(&repo) {
+                continue;
+            }
+
+            issues.push(Issue {
+                rule_id: self.id().to_string(),
+                rule_name: self.name().to_string(),
+                severity: self.severity(),
+                line_number: None,
+                message: format!(
+                    "Service '{}' uses image '{}', which is not an official image and has no build section or registry",
+                    name, image
+                ),
+                fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                impact: self.impact(),
+                params: message_params([("service", &name), ("image", &image)]),
+            });
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_unqualified_custom_image() {
+        let content = r#"
+services:
+  app:
+    image: myapp:1.0
+  web:
+    image: nginx:1.25
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = UnbuiltImageRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'myapp:1.0'"));
+    }
+
+    #[test]
+    fn test_allows_registry_and_built_images() {
+        let content = r#"
+services:
+  api:
+    build: ./api
+    image: myapi:dev
+  worker:
+    image: myapi:dev
+  cache:
+    image: redis:7.2
+  app:
+    image: ghcr.io/acme/app:1.0
+  local:
+    image: localhost:5000/app
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        assert_eq!(UnbuiltImageRule.check(&compose).len(), 0);
+    }
+
+    #[test]
+    fn test_repository_strips_tag_and_digest() {
+        assert_eq!(repository("myapp:1.0"), "myapp");
+        assert_eq!(repository("localhost:5000/app"), "localhost:5000/app");
+        assert_eq!(repository("redis@sha256:abc"), "redis");
+    }
+}
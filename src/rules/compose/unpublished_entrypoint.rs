@@ -0,0 +1,151 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+use std::collections::HashSet;
+
+/// Images that normally act as the public entrypoint of a stack
+const PROXY_IMAGES: &[&str] = &["nginx", "traefik", "caddy", "haproxy", "envoy"];
+
+pub struct UnpublishedEntrypointRule;
+
+impl Rule for UnpublishedEntrypointRule {
+    fn id(&self) -> &'static str { "DC015" }
+    fn name(&self) -> &'static str { "Entrypoint only exposes ports" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "Reverse proxy that nothing depends on uses 'expose' without publishing 'ports'"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "'expose' only makes a port reachable from other containers on the same network; \
+         it does not publish it on the host. A reverse proxy that no other service depends \
+         on is usually the stack's public entrypoint, so with only 'expose' it cannot be \
+         reached from outside at all."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Publish the port with 'ports' (e.g., \"80:80\"), or ignore this if another proxy fronts the service")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Entrypoint is reachable from the host".to_string()),
+        })
+    }
+}
+
+/// Image repository name without registry, namespace, tag or digest
+fn image_base_name(image: &str) -> &str {
+    let without_digest = image.split('@').next().unwrap_or(image);
+    let name = without_digest.rsplit('/').next().unwrap_or(without_digest);
+    name.split(':').next().unwrap_or(name)
+}
+
+impl ComposeRule for UnpublishedEntrypointRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            let depended_on: HashSet<&str> = services.values().flat_map(|s| s.dependencies()).collect();
+
+            for (name, service) in services {
+                let Some(image) = service.image.as_deref() else {
+                    continue;
+                };
+                let base = image_base_name(image).to_lowercase();
+                let is_proxy = PROXY_IMAGES.iter().any(|p| base.starts_with(p));
+
+                let exposed = service.exposed_ports();
+                let has_ports = service.ports.as_ref().is_some_and(|p| !p.is_empty());
+
+                if is_proxy
+                    && !exposed.is_empty()
+                    && !has_ports
+                    && service.network_mode.is_none()
+                    && !depended_on.contains(name.as_str())
+                {
+                    issues.push(Issue {
+                        rule_id: self.id().to_string(),
+                        rule_name: self.name().to_string(),
+                        severity: self.severity(),
+                        line_number: None,
+                        message: format!(
+                            "Service '{}' looks like the public entrypoint but only exposes port(s) {} without publishing them",
+                            name,
+                            exposed.join(", ")
+                        ),
+                        fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                        impact: self.impact(),
+                        params: message_params([("service", &name), ("ports", &exposed.join(", "))]),
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_proxy_with_only_expose() {
+        let content = r#"
+services:
+  proxy:
+    image: nginx:1.25-alpine
+    expose:
+      - 80
+      - "443"
+    depends_on: [app]
+  app:
+    image: myapp:1.0
+    expose: ["8080"]
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = UnpublishedEntrypointRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'proxy'"));
+        assert!(issues[0].message.contains("80, 443"));
+    }
+
+    #[test]
+    fn test_allows_published_proxy() {
+        let content = r#"
+services:
+  proxy:
+    image: traefik:v3.0
+    ports:
+      - "80:80"
+    expose:
+      - 8080
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = UnpublishedEntrypointRule.check(&compose);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_allows_proxy_behind_another_service() {
+        let content = r#"
+services:
+  edge:
+    image: traefik:v3.0
+    ports: ["443:443"]
+    depends_on: [static]
+  static:
+    image: nginx:1.25
+    expose: [80]
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = UnpublishedEntrypointRule.check(&compose);
+        assert_eq!(issues.len(), 0);
+    }
+}
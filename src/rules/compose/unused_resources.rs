@@ -0,0 +1,159 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::{ComposeFile, Service};
+use crate::rules::Rule;
+use std::collections::HashSet;
+
+pub struct UnusedResourcesRule;
+
+impl Rule for UnusedResourcesRule {
+    fn id(&self) -> &'static str { "DC014" }
+    fn name(&self) -> &'static str { "Unused volume or network" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "Top-level volume or network is declared but no service uses it"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Named volumes and networks that no service references are dead configuration. \
+         They still get created on 'docker compose up', accumulate over time as services \
+         are removed, and make it harder to see which resources a deployment really needs."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Remove the unused declaration or reference it from the service that needs it")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Leaner, easier to audit configuration".to_string()),
+        })
+    }
+}
+
+/// Named volumes referenced by a service's short-syntax `volumes`
+fn referenced_volumes(service: &Service) -> impl Iterator<Item = &str> {
+    service.volumes.iter().flatten().filter_map(|spec| {
+        let source = spec.split(':').next()?;
+        let is_path = source.starts_with('/') || source.starts_with('.') || source.starts_with('~');
+        (spec.contains(':') && !is_path && !source.is_empty()).then_some(source)
+    })
+}
+
+impl ComposeRule for UnusedResourcesRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        let services: Vec<&Service> = compose.services.iter().flat_map(|s| s.values()).collect();
+
+        let used_volumes: HashSet<&str> = services.iter().flat_map(|s| referenced_volumes(s)).collect();
+
+        let mut used_networks: HashSet<&str> = services.iter().flat_map(|s| s.network_names()).collect();
+        // Services without explicit networks join the implicit "default" network
+        if services.iter().any(|s| s.networks.is_none() && s.network_mode.is_none()) {
+            used_networks.insert("default");
+        }
+
+        let mut declared_volumes: Vec<&String> = compose.volumes.iter().flat_map(|v| v.keys()).collect();
+        declared_volumes.sort();
+        for volume in declared_volumes {
+            if !used_volumes.contains(volume.as_str()) {
+                issues.push(self.unused_issue("Volume", volume));
+            }
+        }
+
+        let mut declared_networks: Vec<&String> = compose.networks.iter().flat_map(|n| n.keys()).collect();
+        declared_networks.sort();
+        for network in declared_networks {
+            if !used_networks.contains(network.as_str()) {
+                issues.push(self.unused_issue("Network", network));
+            }
+        }
+
+        issues
+    }
+}
+
+impl UnusedResourcesRule {
+    fn unused_issue(&self, kind: &str, name: &str) -> Issue {
+        Issue {
+            rule_id: self.id().to_string(),
+            rule_name: self.name().to_string(),
+            severity: self.severity(),
+            line_number: None,
+            message: format!("{} '{}' is declared but not used by any service", kind, name),
+            fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+            impact: self.impact(),
+            params: message_params([("kind", &kind), ("name", &name)]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_unused_volume() {
+        let content = r#"
+services:
+  db:
+    image: postgres:16
+    volumes:
+      - pgdata:/var/lib/postgresql/data
+volumes:
+  pgdata:
+  cache:
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = UnusedResourcesRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'cache'"));
+    }
+
+    #[test]
+    fn test_allows_referenced_volume_and_network() {
+        let content = r#"
+services:
+  db:
+    image: postgres:16
+    volumes:
+      - pgdata:/var/lib/postgresql/data:rw
+    networks:
+      backend:
+        aliases: [database]
+  app:
+    image: myapp:1.0
+    networks:
+      - backend
+volumes:
+  pgdata:
+networks:
+  backend:
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = UnusedResourcesRule.check(&compose);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_detects_unused_network() {
+        let content = r#"
+services:
+  app:
+    image: myapp:1.0
+    networks: [frontend]
+networks:
+  frontend:
+  legacy:
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = UnusedResourcesRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("Network 'legacy'"));
+    }
+}
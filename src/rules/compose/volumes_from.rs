@@ -0,0 +1,100 @@
+use super::{ComposeRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::ComposeFile;
+use crate::rules::Rule;
+
+pub struct VolumesFromRule;
+
+impl Rule for VolumesFromRule {
+    fn id(&self) -> &'static str { "DC012" }
+    fn name(&self) -> &'static str { "Deprecated volumes_from" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "Service uses the deprecated 'volumes_from' option"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "'volumes_from' couples a service to every volume of another container and is \
+         deprecated in the Compose Specification. Named volumes declared at the top level \
+         make sharing explicit and keep working when services are scaled or renamed."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Declare a named volume under top-level 'volumes' and mount it in each service that needs it")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Explicit, future-proof volume sharing".to_string()),
+        })
+    }
+}
+
+impl ComposeRule for VolumesFromRule {
+    fn check(&self, compose: &ComposeFile) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if let Some(services) = &compose.services {
+            for (name, service) in services {
+                if let Some(volumes_from) = &service.volumes_from {
+                    if !volumes_from.is_empty() {
+                        issues.push(Issue {
+                            rule_id: self.id().to_string(),
+                            rule_name: self.name().to_string(),
+                            severity: self.severity(),
+                            line_number: None,
+                            message: format!("Service '{}' uses deprecated volumes_from ({})", name, volumes_from.join(", ")),
+                            fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                            impact: self.impact(),
+                            params: message_params([("service", &name), ("volumes_from", &volumes_from.join(", "))]),
+                        });
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ComposeParser;
+
+    #[test]
+    fn test_detects_volumes_from() {
+        let content = r#"
+services:
+  app:
+    image: myapp:1.0
+    volumes_from:
+      - data
+  data:
+    image: busybox:1.36
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = VolumesFromRule.check(&compose);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'app'"));
+    }
+
+    #[test]
+    fn test_allows_named_volumes() {
+        let content = r#"
+services:
+  app:
+    image: myapp:1.0
+    volumes:
+      - data:/data
+volumes:
+  data:
+"#;
+        let compose = ComposeParser::parse_content(content).unwrap();
+        let issues = VolumesFromRule.check(&compose);
+        assert_eq!(issues.len(), 0);
+    }
+}
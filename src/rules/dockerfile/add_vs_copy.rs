@@ -0,0 +1,96 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+
+/// Archives that ADD extracts automatically
+const ARCHIVE_EXTENSIONS: &[&str] = &[".tar", ".tgz", ".tar.gz", ".tar.bz2", ".tbz2", ".tar.xz", ".txz"];
+
+pub struct AddVsCopyRule;
+
+fn is_remote(source: &str) -> bool {
+    source.contains("://") || source.starts_with("git@")
+}
+
+fn is_archive(source: &str) -> bool {
+    let source = source.to_lowercase();
+    ARCHIVE_EXTENSIONS.iter().any(|ext| source.ends_with(ext))
+}
+
+impl Rule for AddVsCopyRule {
+    fn id(&self) -> &'static str { "DF012" }
+    fn name(&self) -> &'static str { "ADD instead of COPY" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "ADD is used to copy plain local files"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "ADD has extra behavior: it fetches URLs and silently unpacks local tar archives. \
+         Using it for ordinary files hides that intent and can surprise readers when a file \
+         later becomes an archive. Docker's best practices recommend COPY unless the \
+         extraction or remote fetch is actually wanted."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Replace ADD with COPY for local files and directories")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Copy behavior is explicit and predictable".to_string()),
+        })
+    }
+}
+
+impl DockerfileRule for AddVsCopyRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for instruction in parser.get_instructions("ADD") {
+            let sources = instruction.copy_sources();
+            if sources.is_empty() || sources.iter().any(|s| is_remote(s) || is_archive(s)) {
+                continue;
+            }
+
+            let sources = sources.join(", ");
+            issues.push(Issue {
+                rule_id: self.id().to_string(),
+                rule_name: self.name().to_string(),
+                severity: self.severity(),
+                line_number: Some(instruction.line_number),
+                message: format!("ADD copies local '{}' - use COPY instead", sources),
+                fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                impact: self.impact(),
+                params: message_params([("sources", &sources)]),
+            });
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_add_of_local_file() {
+        let content = "FROM alpine:3.18\nADD ./file /dest";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = AddVsCopyRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+        assert!(issues[0].message.contains("./file"));
+    }
+
+    #[test]
+    fn test_allows_remote_url_and_archives() {
+        let content = "FROM alpine:3.18\nADD https://example.com/tool.bin /usr/local/bin/tool\nADD app.tar.gz /\nADD --chown=app:app vendor.tgz /opt/";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(AddVsCopyRule.check(&parser, None).len(), 0);
+    }
+}
@@ -0,0 +1,116 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+
+/// Base images whose purpose is to persist data, where VOLUME is expected
+const DATA_IMAGES: &[&str] = &[
+    "mysql",
+    "mariadb",
+    "postgres",
+    "mongo",
+    "redis",
+    "elasticsearch",
+    "opensearch",
+    "cassandra",
+    "couchdb",
+    "influxdb",
+    "neo4j",
+    "rabbitmq",
+    "minio",
+];
+
+pub struct AppVolumeRule;
+
+impl Rule for AppVolumeRule {
+    fn id(&self) -> &'static str { "DF028" }
+    fn name(&self) -> &'static str { "VOLUME in application image" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "Application image declares VOLUME instead of leaving storage to runtime configuration"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Every container started from an image with VOLUME gets a new anonymous volume, \
+         which silently accumulates on the host and hides changes made to that path in \
+         later build steps. Data stores ship VOLUME in their official images; application \
+         images are better off letting compose or the orchestrator decide what to mount."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Remove VOLUME and configure a named volume or bind mount at runtime (docker run -v or compose 'volumes')")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Avoids orphaned anonymous volumes".to_string()),
+        })
+    }
+}
+
+impl DockerfileRule for AppVolumeRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        let is_data_image = parser.get_instructions("FROM").iter().any(|from| {
+            let image = from.base_image().unwrap_or("");
+            let name = image.split([':', '@']).next().unwrap_or("").to_lowercase();
+            let base = name.rsplit('/').next().unwrap_or(&name);
+            DATA_IMAGES.contains(&base)
+        });
+
+        if is_data_image {
+            return issues;
+        }
+
+        for instruction in parser.get_instructions("VOLUME") {
+            let paths = instruction.arguments
+                .trim_matches(|c| c == '[' || c == ']')
+                .split([',', ' '])
+                .map(|p| p.trim().trim_matches('"'))
+                .filter(|p| !p.is_empty())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            issues.push(Issue {
+                rule_id: self.id().to_string(),
+                rule_name: self.name().to_string(),
+                severity: self.severity(),
+                line_number: Some(instruction.line_number),
+                message: format!("VOLUME '{}' declared in an application image - configure volumes at runtime instead", paths),
+                fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                impact: self.impact(),
+                params: message_params([("paths", &paths)]),
+            });
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_volume_in_app_image() {
+        let content = "FROM node:18-alpine\nVOLUME /var/lib/mysql\nCMD [\"node\", \"app.js\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = AppVolumeRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("/var/lib/mysql"));
+    }
+
+    #[test]
+    fn test_allows_volume_in_database_image() {
+        let content = "FROM postgres:16-alpine\nVOLUME [\"/var/lib/postgresql/data\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = AppVolumeRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+}
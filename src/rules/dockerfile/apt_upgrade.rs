@@ -0,0 +1,97 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+/// `apt-get upgrade`, `apt dist-upgrade`, `apk upgrade`, allowing flags before the subcommand
+static UPGRADE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(apt-get|apt|apk)\s+(?:-\S+\s+)*(upgrade|dist-upgrade|full-upgrade)\b").unwrap()
+});
+
+pub struct AptUpgradeRule;
+
+impl Rule for AptUpgradeRule {
+    fn id(&self) -> &'static str { "DF013" }
+    fn name(&self) -> &'static str { "Package upgrade in image" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "RUN upgrades all installed OS packages"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "'apt-get upgrade', 'dist-upgrade' and 'apk upgrade' install whatever package \
+         versions the mirror serves on the day of the build, so two builds of the same \
+         Dockerfile produce different images. The upgraded files are also written into a \
+         new layer on top of the originals, growing the image. Security fixes are better \
+         picked up by moving to a newer, pinned base image."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Remove the upgrade and pin a newer base image version (e.g. 'FROM debian:12.5-slim') that already contains the fixes")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: Some("No full package upgrade during the build".to_string()),
+            image_size_reduction: Some("Avoids duplicating upgraded packages in a new layer".to_string()),
+            security_improvement: None,
+            reliability_improvement: Some("Reproducible package versions".to_string()),
+        })
+    }
+}
+
+impl DockerfileRule for AptUpgradeRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for instruction in parser.get_instructions("RUN") {
+            for captures in UPGRADE.captures_iter(&instruction.arguments) {
+                let command = format!("{} {}", &captures[1], &captures[2]);
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: Some(instruction.line_at(captures.get(0).map_or(0, |m| m.start()))),
+                    message: format!("'{}' makes the image depend on the build date", command),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: message_params([("command", &command)]),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_upgrade_commands() {
+        let content = "FROM debian:12\n\
+                       RUN apt-get update && apt-get upgrade -y\n\
+                       RUN apt-get update && \\\n    apt-get -y dist-upgrade\n\
+                       FROM alpine:3.18\n\
+                       RUN apk upgrade --no-cache";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = AptUpgradeRule.check(&parser, None);
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[0].line_number, Some(2));
+        assert!(issues[0].message.contains("apt-get upgrade"));
+        assert_eq!(issues[1].line_number, Some(4));
+        assert!(issues[1].message.contains("apt-get dist-upgrade"));
+        assert_eq!(issues[2].line_number, Some(6));
+        assert!(issues[2].message.contains("apk upgrade"));
+    }
+
+    #[test]
+    fn test_allows_update_and_install() {
+        let content = "FROM debian:12\nRUN apt-get update && apt-get install -y --no-install-recommends curl";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(AptUpgradeRule.check(&parser, None).len(), 0);
+    }
+}
@@ -0,0 +1,140 @@
+use super::secrets_in_env::is_secret_name;
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::{DockerfileParser, Instruction};
+use crate::rules::Rule;
+
+pub struct BuildSecretArgRule;
+
+/// Names declared by an ARG (`ARG NAME`, `ARG NAME=default`, `ARG A B`)
+fn arg_names(instruction: &Instruction) -> Vec<&str> {
+    instruction
+        .arguments
+        .split_whitespace()
+        .map(|declaration| declaration.split('=').next().unwrap_or(declaration))
+        .collect()
+}
+
+/// DF006's secret names, plus generic `*_TOKEN` build args such as `NPM_TOKEN`
+fn is_build_secret(name: &str) -> bool {
+    let upper = name.to_uppercase();
+    is_secret_name(name) || upper == "TOKEN" || upper.ends_with("_TOKEN")
+}
+
+fn references(instruction: &Instruction, name: &str) -> bool {
+    instruction.arguments.contains(&format!("${}", name)) || instruction.arguments.contains(&format!("${{{}", name))
+}
+
+impl Rule for BuildSecretArgRule {
+    fn id(&self) -> &'static str { "DF022" }
+    fn name(&self) -> &'static str { "Secret passed as build ARG" }
+    fn severity(&self) -> Severity { Severity::Critical }
+
+    fn description(&self) -> &'static str {
+        "A build ARG that holds a secret is available to RUN instructions"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Build arguments are set in the environment of every RUN that follows them, and \
+         their values are recorded with those steps in the image metadata, where anyone \
+         who can pull the image reads them with 'docker history'. A token passed this way \
+         leaks even when no file in the image contains it. Secret mounts are only visible \
+         to the one RUN that needs them and are never stored in the image."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Pass the secret with 'docker build --secret id=token,env=TOKEN' and read it in 'RUN --mount=type=secret,id=token'")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Critical - keeps build secrets out of the image history".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl DockerfileRule for BuildSecretArgRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        // ARGs are scoped to their stage; one declared before the first FROM never reaches a RUN
+        let stages = parser.instructions.split(|i| i.name.eq_ignore_ascii_case("FROM")).skip(1);
+        for stage in stages {
+            for (index, arg) in stage.iter().enumerate().filter(|(_, i)| i.name.eq_ignore_ascii_case("ARG")) {
+                let runs: Vec<&Instruction> = stage[index + 1..]
+                    .iter()
+                    .filter(|i| i.name.eq_ignore_ascii_case("RUN"))
+                    .collect();
+
+                for name in arg_names(arg).into_iter().filter(|name| is_build_secret(name)) {
+                    // Prefer the RUN that uses the value explicitly
+                    let Some(run) = runs.iter().find(|run| references(run, name)).or(runs.first()) else {
+                        continue;
+                    };
+                    let secret_id = name.to_lowercase();
+                    issues.push(Issue {
+                        rule_id: self.id().to_string(),
+                        rule_name: self.name().to_string(),
+                        severity: self.severity(),
+                        line_number: Some(arg.line_number),
+                        message: format!(
+                            "Build arg '{}' is passed to the RUN on line {}, which records it in the image history",
+                            name, run.line_number
+                        ),
+                        fix_suggestion: Some(format!(
+                            "Remove 'ARG {}' and use 'RUN --mount=type=secret,id={},env={}' with 'docker build --secret id={},env={}'",
+                            name, secret_id, name, secret_id, name
+                        )),
+                        impact: self.impact(),
+                        params: message_params([("arg", &name), ("run_line", &run.line_number)]),
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_token_arg_used_by_run() {
+        let content = "FROM node:20-slim\nARG NPM_TOKEN\nCOPY .npmrc package.json ./\nRUN npm install\nUSER node";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = BuildSecretArgRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+        assert!(issues[0].message.contains("'NPM_TOKEN'"));
+        assert!(issues[0].message.contains("line 4"));
+    }
+
+    #[test]
+    fn test_points_at_run_that_references_the_arg() {
+        let content = "FROM alpine:3.19\nARG VERSION DB_PASSWORD=changeme\nRUN apk add --no-cache curl\nRUN echo \"${DB_PASSWORD}\" | login";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = BuildSecretArgRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("line 4"));
+    }
+
+    #[test]
+    fn test_token_names_are_build_secrets() {
+        assert!(is_build_secret("NPM_TOKEN"));
+        assert!(is_build_secret("token"));
+        assert!(is_build_secret("DB_PASSWORD"));
+        assert!(!is_build_secret("TOKENIZER_MODEL"));
+        assert!(!is_secret_name("NPM_TOKEN"));
+    }
+
+    #[test]
+    fn test_allows_plain_args_and_unused_secrets() {
+        let content = "ARG GITHUB_TOKEN\nFROM node:20-slim\nARG NODE_VERSION=20\nRUN npm ci\nARG API_KEY\nUSER node";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(BuildSecretArgRule.check(&parser, None).len(), 0);
+    }
+}
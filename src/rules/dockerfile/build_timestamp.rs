@@ -0,0 +1,103 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+/// `$(date ...)` or `` `date ...` `` command substitution
+static DATE_SUBSTITUTION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\$\(\s*date\b|`\s*date\b").unwrap()
+});
+
+static WRITES_FILE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r">|\btee\b|\bsed\s+-i").unwrap()
+});
+
+pub struct BuildTimestampRule;
+
+impl Rule for BuildTimestampRule {
+    fn id(&self) -> &'static str { "DF033" }
+    fn name(&self) -> &'static str { "Build timestamp in layer" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "RUN writes the current date into a file"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Writing $(date) into a file makes the layer different on every build, so the \
+         same source never produces the same image digest. It defeats reproducible builds \
+         and invalidates the cache for every layer that follows when the layer is rebuilt."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Pass the timestamp in as a build arg (ARG BUILD_DATE) or derive it from SOURCE_DATE_EPOCH")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: Some("Later layers stay cacheable".to_string()),
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Reproducible image digests".to_string()),
+        })
+    }
+}
+
+impl DockerfileRule for BuildTimestampRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for instruction in parser.get_instructions("RUN") {
+            let args = &instruction.arguments;
+            if args.contains("SOURCE_DATE_EPOCH") || !WRITES_FILE.is_match(args) {
+                continue;
+            }
+
+            if let Some(found) = DATE_SUBSTITUTION.find(args) {
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: Some(instruction.line_at(found.start())),
+                    message: "Current date is written into the image, making the build non-reproducible".to_string(),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: MessageParams::new(),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_date_written_to_file() {
+        let content = "FROM alpine:3.18\nRUN echo $(date) > build_time";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = BuildTimestampRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+    }
+
+    #[test]
+    fn test_detects_backtick_date() {
+        let content = "FROM alpine:3.18\nRUN echo \"built `date -u +%FT%TZ`\" | tee /etc/build-info";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = BuildTimestampRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+    }
+
+    #[test]
+    fn test_allows_clean_run() {
+        let content = "FROM alpine:3.18\nARG BUILD_DATE\nRUN echo \"$BUILD_DATE\" > build_time\nRUN date -d @$SOURCE_DATE_EPOCH > /etc/build-date";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = BuildTimestampRule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+}
@@ -0,0 +1,143 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams, message_params};
+use crate::parser::{DockerfileParser, Instruction};
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+static RANDOM_VAR: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\$\{?RANDOM\b").unwrap()
+});
+
+/// An `echo` of the current date that is not written anywhere (DF033 covers files)
+static DATE_NOOP: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*echo\b[^>|]*(?:\$\(\s*date\b|`\s*date\b)[^>|]*$").unwrap()
+});
+
+static CACHEBUST_ARG: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^cache[_-]?bust").unwrap()
+});
+
+pub struct CacheBustRule;
+
+impl Rule for CacheBustRule {
+    fn id(&self) -> &'static str { "DF037" }
+    fn name(&self) -> &'static str { "Cache-busting hack" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "A RUN or ARG exists only to invalidate the build cache"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Tricks like 'RUN echo $RANDOM', echoing $(date +%s) or an 'ARG CACHEBUST' fed a \
+         new value on every build force all following layers to rebuild. The result \
+         depends on when the build ran rather than on its inputs, which undermines \
+         reproducible builds and hides what actually needed refreshing."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Use 'docker build --no-cache' or '--no-cache-filter <stage>' when a fresh build is needed, or pin the inputs that should trigger a rebuild")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: Some("Layers after the hack become cacheable".to_string()),
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Builds depend on their inputs, not the clock".to_string()),
+        })
+    }
+}
+
+impl CacheBustRule {
+    fn issue(&self, instruction: &Instruction, message: String, params: MessageParams) -> Issue {
+        Issue {
+            rule_id: self.id().to_string(),
+            rule_name: self.name().to_string(),
+            severity: self.severity(),
+            line_number: Some(instruction.line_number),
+            message,
+            fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+            impact: self.impact(),
+            params,
+        }
+    }
+}
+
+impl DockerfileRule for CacheBustRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for instruction in &parser.instructions {
+            match instruction.name.to_uppercase().as_str() {
+                "RUN" => {
+                    let args = &instruction.arguments;
+                    if RANDOM_VAR.is_match(args) {
+                        issues.push(self.issue(
+                            instruction,
+                            "RUN uses $RANDOM, so the layer is rebuilt on every build".to_string(),
+                            MessageParams::new(),
+                        ));
+                    } else if args.split("&&").any(|command| DATE_NOOP.is_match(command)) {
+                        issues.push(self.issue(
+                            instruction,
+                            "RUN echoes the current date only to invalidate the cache".to_string(),
+                            MessageParams::new(),
+                        ));
+                    }
+                }
+                "ARG" => {
+                    let name = instruction.arguments.split('=').next().unwrap_or("").trim();
+                    if CACHEBUST_ARG.is_match(name) {
+                        issues.push(self.issue(
+                            instruction,
+                            format!("ARG {} is used to force cache invalidation", name),
+                            message_params([("arg", &name)]),
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_echo_random() {
+        let content = "FROM alpine:3.18\nRUN echo $RANDOM\nRUN apk add --no-cache curl";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = CacheBustRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+    }
+
+    #[test]
+    fn test_detects_cachebust_arg() {
+        let content = "FROM alpine:3.18\nARG CACHEBUST=1\nRUN git clone https://example.com/repo.git";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = CacheBustRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("CACHEBUST"));
+    }
+
+    #[test]
+    fn test_detects_date_noop() {
+        let content = "FROM alpine:3.18\nRUN echo \"$(date +%s)\" && apk add --no-cache curl";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(CacheBustRule.check(&parser, None).len(), 1);
+    }
+
+    #[test]
+    fn test_allows_regular_args_and_date_files() {
+        // Writing the date to a file is DF033's concern
+        let content = "FROM alpine:3.18\nARG VERSION=1.2.3\nRUN echo $(date) > /build-date\nRUN echo done";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(CacheBustRule.check(&parser, None).len(), 0);
+    }
+}
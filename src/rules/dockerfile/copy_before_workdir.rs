@@ -0,0 +1,123 @@
+use super::privileged_port::is_root;
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::{DockerfileParser, Instruction};
+use crate::rules::Rule;
+
+pub struct CopyBeforeWorkdirRule;
+
+impl Rule for CopyBeforeWorkdirRule {
+    fn id(&self) -> &'static str { "DF036" }
+    fn name(&self) -> &'static str { "COPY before WORKDIR" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "The build context is copied to a relative path before any WORKDIR is set, in a stage that runs as a non-root user"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Without a WORKDIR the working directory is '/', so 'COPY . .' scatters the \
+         project across the root filesystem next to /etc and /usr. The files end up \
+         root-owned in system locations, which breaks a later non-root USER that needs \
+         to write to them and makes the image layout hard to reason about."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Set WORKDIR before copying (e.g., WORKDIR /app then COPY . .)")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Application files live in a dedicated directory".to_string()),
+        })
+    }
+}
+
+impl DockerfileRule for CopyBeforeWorkdirRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        root_owned_copies(parser)
+            .into_iter()
+            .map(|(instruction, user)| Issue {
+                rule_id: self.id().to_string(),
+                rule_name: self.name().to_string(),
+                severity: self.severity(),
+                line_number: Some(instruction.line_number),
+                message: format!(
+                    "{} of the build context runs before WORKDIR and leaves root-owned files in '/' for USER '{}'",
+                    instruction.name.to_uppercase(),
+                    user
+                ),
+                fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                impact: self.impact(),
+                params: message_params([("user", &user)]),
+            })
+            .collect()
+    }
+}
+
+/// Per stage that ends as a non-root user, its first broad COPY/ADD to a relative
+/// destination before any WORKDIR, with that user. DF014 leaves these to this rule.
+pub(super) fn root_owned_copies(parser: &DockerfileParser) -> Vec<(&Instruction, &str)> {
+    parser
+        .stages()
+        .into_iter()
+        .zip(parser.before_workdir())
+        .filter_map(|(stage, before_workdir)| {
+            let user = stage
+                .instructions
+                .iter()
+                .rev()
+                .find(|i| i.name.eq_ignore_ascii_case("USER"))
+                .map(|i| i.arguments.trim())
+                .filter(|user| !is_root(user))?;
+            before_workdir
+                .iter()
+                .find(|i| i.copies_build_context() && i.copy_destination().is_some_and(|d| !d.starts_with('/')))
+                .map(|copy| (copy, user))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_copy_before_workdir() {
+        let content = "FROM node:18-alpine\nCOPY . .\nWORKDIR /app\nUSER node\nCMD [\"node\", \"app.js\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = CopyBeforeWorkdirRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+    }
+
+    #[test]
+    fn test_root_stage_is_left_to_missing_workdir() {
+        let content = "FROM node:18-alpine\nCOPY . .\nWORKDIR /app\nCMD [\"node\", \"app.js\"]";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(CopyBeforeWorkdirRule.check(&parser, None).len(), 0);
+    }
+
+    #[test]
+    fn test_allows_workdir_first() {
+        let content = "FROM node:18-alpine\nWORKDIR /app\nCOPY . .\nUSER node\nCMD [\"node\", \"app.js\"]";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(CopyBeforeWorkdirRule.check(&parser, None).len(), 0);
+    }
+
+    #[test]
+    fn test_from_platform_flag_keeps_stage_tracking() {
+        let content = "FROM --platform=$BUILDPLATFORM node:18 AS build\nWORKDIR /src\nCOPY . .\nFROM build AS test\nCOPY . .\nUSER node";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(CopyBeforeWorkdirRule.check(&parser, None).len(), 0);
+    }
+
+    #[test]
+    fn test_allows_absolute_destination_and_inherited_workdir() {
+        let content = "FROM golang:1.21 AS build\nWORKDIR /src\nCOPY . .\nFROM build AS test\nCOPY . .\nFROM alpine:3.18\nCOPY . /app\nUSER app";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(CopyBeforeWorkdirRule.check(&parser, None).len(), 0);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{DockerfileRule, Issue, Severity, ImpactEstimate};
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
 use crate::parser::DockerfileParser;
 use crate::rules::Rule;
 use regex::Regex;
@@ -45,15 +45,16 @@ impl DockerfileRule for CurlBashRule {
         let mut issues = Vec::new();
         
         for instruction in parser.get_instructions("RUN") {
-            if CURL_BASH_PATTERN.is_match(&instruction.arguments) {
+            if let Some(found) = CURL_BASH_PATTERN.find(&instruction.arguments) {
                 issues.push(Issue {
                     rule_id: self.id().to_string(),
                     rule_name: self.name().to_string(),
                     severity: self.severity(),
-                    line_number: Some(instruction.line_number),
+                    line_number: Some(instruction.line_at(found.start())),
                     message: "Piping curl/wget to shell - remote code execution risk".to_string(),
                     fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                     impact: self.impact(),
+                    params: MessageParams::new(),
                 });
             }
         }
@@ -0,0 +1,117 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+
+/// Directories that package managers and build tools fill on the host
+const DEPENDENCY_DIRS: &[(&str, &str)] = &[
+    ("node_modules", "npm ci"),
+    ("vendor", "composer install or bundle install"),
+    ("target", "cargo build or mvn package"),
+];
+
+pub struct DependencyDirectoryRule;
+
+impl Rule for DependencyDirectoryRule {
+    fn id(&self) -> &'static str { "DF041" }
+    fn name(&self) -> &'static str { "Host dependency directory copied" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "COPY/ADD takes node_modules, vendor or target from the build context"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Dependency and build output directories are produced for the host's OS, CPU and \
+         libc. Native modules built on a Mac or a glibc host crash on Alpine or arm64, and \
+         the directories carry dev dependencies and caches the image does not need. \
+         Installing inside the build makes the image match its own platform and keeps the \
+         result reproducible from the lock file."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Copy the manifest and lock file, install dependencies in a RUN, and add the directory to .dockerignore")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: Some("Smaller build context".to_string()),
+            image_size_reduction: Some("Drops dev dependencies and host build caches".to_string()),
+            security_improvement: None,
+            reliability_improvement: Some("Native dependencies match the image platform".to_string()),
+        })
+    }
+}
+
+/// The dependency directory named by a COPY source, if any
+fn dependency_dir(source: &str) -> Option<&'static (&'static str, &'static str)> {
+    let source = source.trim_start_matches("./");
+    DEPENDENCY_DIRS
+        .iter()
+        .find(|(dir, _)| source.split('/').any(|component| component == *dir))
+}
+
+impl DockerfileRule for DependencyDirectoryRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        // Go modules vendored into the repository are source code, not host build output
+        let go_build = parser.get_instructions("FROM").iter().any(|from| from.arguments.contains("golang"));
+
+        for instruction in &parser.instructions {
+            if !instruction.name.eq_ignore_ascii_case("COPY") && !instruction.name.eq_ignore_ascii_case("ADD") {
+                continue;
+            }
+            // Artifacts from an earlier stage were built for the image platform
+            if instruction.arguments.contains("--from=") {
+                continue;
+            }
+
+            for source in instruction.copy_sources() {
+                let Some((dir, install)) = dependency_dir(&source) else {
+                    continue;
+                };
+                if *dir == "vendor" && go_build {
+                    continue;
+                }
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: Some(instruction.line_number),
+                    message: format!("'{}' copies host-built {} into the image", source, dir),
+                    fix_suggestion: Some(format!(
+                        "Run {} inside the build instead, and add {} to .dockerignore",
+                        install, dir
+                    )),
+                    impact: self.impact(),
+                    params: message_params([("path", &source), ("directory", dir)]),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_node_modules_copy() {
+        let content = "FROM node:20-slim\nWORKDIR /app\nCOPY node_modules /app/node_modules\nCOPY ./target/release/app /usr/local/bin/";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = DependencyDirectoryRule.check(&parser, None);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].line_number, Some(3));
+        assert!(issues[0].message.contains("node_modules"));
+        assert!(issues[1].message.contains("target"));
+    }
+
+    #[test]
+    fn test_allows_manifest_and_stage_copies() {
+        let content = "FROM node:20-slim AS deps\nCOPY package.json package-lock.json ./\nRUN npm ci\n\
+                       FROM node:20-slim\nCOPY package.json .\nCOPY --from=deps /app/node_modules ./node_modules";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(DependencyDirectoryRule.check(&parser, None).len(), 0);
+    }
+}
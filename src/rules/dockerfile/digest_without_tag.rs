@@ -0,0 +1,99 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+
+pub struct DigestWithoutTagRule;
+
+impl Rule for DigestWithoutTagRule {
+    fn id(&self) -> &'static str { "DF027" }
+    fn name(&self) -> &'static str { "Digest without tag" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "FROM pins an image by digest without a human-readable tag"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Pinning by digest guarantees reproducible builds, but a bare digest says nothing \
+         about which version it refers to. Keeping the tag alongside the digest \
+         (image:tag@sha256:...) preserves reproducibility while letting reviewers and \
+         update tools see what is actually being used."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Include the tag with the digest (e.g., FROM node:18.17.0-alpine@sha256:...)")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Easier to tell when a pinned base image is outdated".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl DockerfileRule for DigestWithoutTagRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for instruction in parser.get_instructions("FROM") {
+            let image_ref = instruction.arguments
+                .split_whitespace()
+                .find(|part| !part.starts_with("--"))
+                .unwrap_or("");
+
+            if let Some((name, _digest)) = image_ref.split_once('@') {
+                // Only the last path segment can carry a tag (the registry may have a port)
+                let repository = name.rsplit('/').next().unwrap_or(name);
+                if !repository.contains(':') {
+                    issues.push(Issue {
+                        rule_id: self.id().to_string(),
+                        rule_name: self.name().to_string(),
+                        severity: self.severity(),
+                        line_number: Some(instruction.line_number),
+                        message: format!("Image '{}' is pinned by digest without a tag", name),
+                        fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                        impact: self.impact(),
+                        params: message_params([("image", &name)]),
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_bare_digest() {
+        let content = "FROM node@sha256:abc123\nRUN npm install";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = DigestWithoutTagRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+    }
+
+    #[test]
+    fn test_allows_tag_with_digest() {
+        let content = "FROM node:18@sha256:abc123\nRUN npm install";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = DigestWithoutTagRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_registry_port_is_not_a_tag() {
+        let content = "FROM registry.example.com:5000/node@sha256:abc123";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = DigestWithoutTagRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+    }
+}
@@ -0,0 +1,99 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::{DockerfileParser, Instruction};
+use crate::rules::Rule;
+
+pub struct DuplicateCmdRule;
+
+impl Rule for DuplicateCmdRule {
+    fn id(&self) -> &'static str { "DF015" }
+    fn name(&self) -> &'static str { "Multiple CMD or ENTRYPOINT" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "A build stage has more than one CMD or ENTRYPOINT"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Only the last CMD and the last ENTRYPOINT in a stage take effect; earlier ones \
+         are silently ignored. A container that starts something other than what the \
+         first instruction says is confusing to debug, and the dead instruction is \
+         usually a leftover from an edit."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Keep a single CMD and a single ENTRYPOINT per stage and remove the earlier ones")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("The start command is the one that is written".to_string()),
+        })
+    }
+}
+
+impl DuplicateCmdRule {
+    fn issue(&self, shadowed: &Instruction, later: &Instruction) -> Issue {
+        let name = shadowed.name.to_uppercase();
+        Issue {
+            rule_id: self.id().to_string(),
+            rule_name: self.name().to_string(),
+            severity: self.severity(),
+            line_number: Some(shadowed.line_number),
+            message: format!("{} is overridden by the {} on line {} and never runs", name, name, later.line_number),
+            fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+            impact: self.impact(),
+            params: message_params([("instruction", &name), ("line", &later.line_number)]),
+        }
+    }
+}
+
+impl DockerfileRule for DuplicateCmdRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let mut last_cmd: Option<&Instruction> = None;
+        let mut last_entrypoint: Option<&Instruction> = None;
+
+        for instruction in &parser.instructions {
+            let previous = match instruction.name.to_uppercase().as_str() {
+                "FROM" => {
+                    last_cmd = None;
+                    last_entrypoint = None;
+                    continue;
+                }
+                "CMD" => last_cmd.replace(instruction),
+                "ENTRYPOINT" => last_entrypoint.replace(instruction),
+                _ => continue,
+            };
+            if let Some(shadowed) = previous {
+                issues.push(self.issue(shadowed, instruction));
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_two_cmds_in_stage() {
+        let content = "FROM node:18-alpine\nCMD [\"npm\", \"start\"]\nENTRYPOINT [\"tini\", \"--\"]\nCMD [\"node\", \"server.js\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = DuplicateCmdRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+        assert!(issues[0].message.contains("line 4"));
+    }
+
+    #[test]
+    fn test_allows_one_cmd_per_stage() {
+        let content = "FROM golang:1.21 AS build\nCMD [\"go\", \"test\"]\nFROM alpine:3.18\nCMD [\"/app\"]";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(DuplicateCmdRule.check(&parser, None).len(), 0);
+    }
+}
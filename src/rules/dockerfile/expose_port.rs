@@ -0,0 +1,110 @@
+use super::privileged_port::FIRST_UNPRIVILEGED_PORT;
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::{parse_expose_entries, DockerfileParser};
+use crate::rules::Rule;
+
+pub struct ExposePortRule;
+
+impl Rule for ExposePortRule {
+    fn id(&self) -> &'static str { "DF020" }
+    fn name(&self) -> &'static str { "Invalid or privileged EXPOSE port" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "EXPOSE lists an invalid port, or a privileged port below 1024"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A port outside 1-65535 or an unknown protocol fails the build or documents a port \
+         nothing can listen on. Ports below 1024 can only be bound by root or with \
+         CAP_NET_BIND_SERVICE, so a service listening there needs more privilege than it \
+         should. Listening on a high port and mapping it at run time avoids that."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Expose a port between 1024 and 65535 (e.g. 8080 instead of 80) with an optional /tcp or /udp suffix")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Service can listen without root privileges".to_string()),
+            reliability_improvement: Some("Declared ports are valid".to_string()),
+        })
+    }
+}
+
+impl DockerfileRule for ExposePortRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for expose in parser.get_instructions("EXPOSE") {
+            for entry in parse_expose_entries(&expose.arguments) {
+                match entry {
+                    Err(entry) => issues.push(Issue {
+                        rule_id: self.id().to_string(),
+                        rule_name: self.name().to_string(),
+                        severity: Severity::Warning,
+                        line_number: Some(expose.line_number),
+                        message: format!("EXPOSE '{}' is not a valid port (1-65535, optionally /tcp or /udp)", entry),
+                        fix_suggestion: Some("Use a port number between 1 and 65535, e.g. 'EXPOSE 8080/tcp'".to_string()),
+                        impact: self.impact(),
+                        params: message_params([("port", &entry)]),
+                    }),
+                    Ok(port) if port.start < FIRST_UNPRIVILEGED_PORT => issues.push(Issue {
+                        rule_id: self.id().to_string(),
+                        rule_name: self.name().to_string(),
+                        severity: Severity::Suggestion,
+                        line_number: Some(expose.line_number),
+                        message: format!("Port {} is privileged and needs root or CAP_NET_BIND_SERVICE to bind", port.start),
+                        fix_suggestion: Some(format!(
+                            "Listen on a high port (e.g. 8080 instead of {}) and publish it as {} at run time",
+                            port.start, port.start
+                        )),
+                        impact: self.impact(),
+                        params: message_params([("port", &port.start)]),
+                    }),
+                    Ok(_) => {}
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_privileged_ports_are_suggestions() {
+        let parser = DockerfileParser::parse_content("FROM nginx:1.25\nEXPOSE 80 443");
+        let issues = ExposePortRule.check(&parser, None);
+        assert_eq!(issues.len(), 2);
+        assert!(issues.iter().all(|i| i.severity == Severity::Suggestion && i.line_number == Some(2)));
+        assert!(issues[1].message.contains("443"));
+    }
+
+    #[test]
+    fn test_privileged_port_flagged_for_any_user() {
+        let parser = DockerfileParser::parse_content("FROM nginx:1.25\nUSER nginx\nEXPOSE 80");
+        assert_eq!(ExposePortRule.check(&parser, None).len(), 1);
+    }
+
+    #[test]
+    fn test_allows_high_port_with_protocol() {
+        let parser = DockerfileParser::parse_content("FROM node:20-slim\nEXPOSE 8080/tcp");
+        assert_eq!(ExposePortRule.check(&parser, None).len(), 0);
+    }
+
+    #[test]
+    fn test_invalid_port_is_warning() {
+        let parser = DockerfileParser::parse_content("FROM node:20-slim\nEXPOSE 70000");
+        let issues = ExposePortRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, Severity::Warning);
+        assert!(issues[0].message.contains("'70000'"));
+    }
+}
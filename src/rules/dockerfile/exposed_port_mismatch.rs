@@ -0,0 +1,137 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::{parse_exposed_ports, DockerfileParser, ExposedPort, Instruction};
+use crate::rules::Rule;
+
+pub struct ExposedPortMismatchRule;
+
+impl Rule for ExposedPortMismatchRule {
+    fn id(&self) -> &'static str { "DF031" }
+    fn name(&self) -> &'static str { "Listen port not exposed" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "CMD/ENTRYPOINT passes a --port that no EXPOSE declares"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "EXPOSE documents which port the container listens on, and tooling such as \
+         'docker run -P' and many orchestrators rely on it. When the start command \
+         passes an explicit port that EXPOSE does not mention, one of the two is \
+         usually stale and users will connect to the wrong port."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Make EXPOSE match the port the application listens on (or update the --port argument)")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Documented port matches the running service".to_string()),
+        })
+    }
+}
+
+/// Port passed as `--port N`, `--port=N` or `-p N` in exec-form arguments
+fn listen_port(args: &[String]) -> Option<u16> {
+    args.iter().enumerate().find_map(|(i, arg)| {
+        if let Some(value) = arg.strip_prefix("--port=") {
+            return value.parse().ok();
+        }
+        if arg == "--port" || arg == "-p" {
+            return args.get(i + 1)?.parse().ok();
+        }
+        None
+    })
+}
+
+impl ExposedPortMismatchRule {
+    fn check_stage(&self, exposed: &[ExposedPort], commands: &[&Instruction], issues: &mut Vec<Issue>) {
+        // Stages without EXPOSE may inherit it from the base image; stay quiet
+        if exposed.is_empty() {
+            return;
+        }
+
+        for instruction in commands {
+            let Some(port) = instruction.exec_args().as_deref().and_then(listen_port) else {
+                continue;
+            };
+            if !exposed.iter().any(|p| p.contains(port)) {
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: Some(instruction.line_number),
+                    message: format!("{} listens on port {} but it is not declared by EXPOSE", instruction.name.to_uppercase(), port),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: message_params([("instruction", &instruction.name.to_uppercase()), ("port", &port)]),
+                });
+            }
+        }
+    }
+}
+
+impl DockerfileRule for ExposedPortMismatchRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        let mut exposed: Vec<ExposedPort> = Vec::new();
+        // Last CMD and ENTRYPOINT of the stage; earlier ones are overridden
+        let mut cmd: Option<&Instruction> = None;
+        let mut entrypoint: Option<&Instruction> = None;
+
+        for instruction in &parser.instructions {
+            match instruction.name.to_uppercase().as_str() {
+                "FROM" => {
+                    let commands: Vec<_> = entrypoint.into_iter().chain(cmd).collect();
+                    self.check_stage(&exposed, &commands, &mut issues);
+                    exposed.clear();
+                    cmd = None;
+                    entrypoint = None;
+                }
+                "EXPOSE" => exposed.extend(parse_exposed_ports(&instruction.arguments)),
+                "CMD" => cmd = Some(instruction),
+                "ENTRYPOINT" => entrypoint = Some(instruction),
+                _ => {}
+            }
+        }
+        let commands: Vec<_> = entrypoint.into_iter().chain(cmd).collect();
+        self.check_stage(&exposed, &commands, &mut issues);
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_port_mismatch() {
+        let content = "FROM node:20-slim\nEXPOSE 8080\nCMD [\"app\", \"--port\", \"3000\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = ExposedPortMismatchRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(3));
+        assert!(issues[0].message.contains("3000"));
+    }
+
+    #[test]
+    fn test_allows_matching_port() {
+        let content = "FROM node:20-slim\nEXPOSE 3000\nENTRYPOINT [\"app\"]\nCMD [\"--port=3000\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = ExposedPortMismatchRule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_ignores_shell_form_and_missing_expose() {
+        let content = "FROM node:20-slim\nEXPOSE 8080\nCMD app --port 3000\nFROM node:20-slim\nCMD [\"app\", \"-p\", \"3000\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = ExposedPortMismatchRule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+}
@@ -0,0 +1,82 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+
+pub struct GitDirectoryRule;
+
+impl Rule for GitDirectoryRule {
+    fn id(&self) -> &'static str { "DF035" }
+    fn name(&self) -> &'static str { ".git directory copied" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "COPY/ADD puts the .git directory into the image"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "The .git directory contains the full history of the repository, including any \
+         secret that was ever committed and later removed. Shipping it in an image leaks \
+         that history to anyone who can pull the image, and it often adds megabytes that \
+         nothing at runtime needs."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Remove the .git source and copy only the files the image needs")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: Some("Smaller build context".to_string()),
+            image_size_reduction: Some("Saves the size of the repository history".to_string()),
+            security_improvement: Some("Prevents leaking commit history and old secrets".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+fn is_git_path(source: &str) -> bool {
+    let source = source.trim_start_matches("./").trim_end_matches('/');
+    source == ".git" || source.starts_with(".git/") || source.ends_with("/.git") || source.contains("/.git/")
+}
+
+impl DockerfileRule for GitDirectoryRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        // Broad copies of the build context are left to DF023
+        let mut issues = Vec::new();
+
+        for instruction in &parser.instructions {
+            if !instruction.name.eq_ignore_ascii_case("COPY") && !instruction.name.eq_ignore_ascii_case("ADD") {
+                continue;
+            }
+
+            if let Some(source) = instruction.copy_sources().into_iter().find(|s| is_git_path(s)) {
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: Some(instruction.line_number),
+                    message: format!("'{}' is copied into the image", source),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: message_params([("path", &source)]),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_explicit_git_copy() {
+        let content = "FROM alpine:3.18\nCOPY .git /app/.git\nCOPY src/ /app/src/";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = GitDirectoryRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+    }
+}
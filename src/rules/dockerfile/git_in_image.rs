@@ -0,0 +1,110 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
+use crate::parser::{is_excluded, parse_dockerignore, DockerfileParser};
+use crate::rules::Rule;
+
+pub struct GitInImageRule;
+
+impl Rule for GitInImageRule {
+    fn id(&self) -> &'static str { "DF023" }
+    fn name(&self) -> &'static str { "Build context copied with .git" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "COPY . or ADD . copies the build context while .dockerignore does not exclude .git"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A broad COPY takes everything .dockerignore lets through. Without a .git entry \
+         that includes the repository history: branch names, old commits and any secret \
+         that was committed and later removed, baked into a layer anyone who pulls the \
+         image can read."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Add '.git' to .dockerignore")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: Some("Smaller build context".to_string()),
+            image_size_reduction: Some("Saves the size of the repository history".to_string()),
+            security_improvement: Some("Keeps version control history out of the image".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl DockerfileRule for GitInImageRule {
+    fn check(&self, parser: &DockerfileParser, context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let Some(copy) = parser.instructions.iter().find(|i| i.copies_build_context()) else {
+            return Vec::new();
+        };
+
+        // Without the build context there is no .dockerignore to rule .git out
+        let dockerignore = context_dir.map(|dir| dir.join(".dockerignore"));
+        let message = match dockerignore {
+            None => format!("{} copies the whole build context, which includes .git unless .dockerignore excludes it", copy.name.to_uppercase()),
+            Some(path) if !path.exists() => {
+                format!("{} copies the whole build context, including .git, and there is no .dockerignore", copy.name.to_uppercase())
+            }
+            Some(path) if is_excluded(&parse_dockerignore(&path).unwrap_or_default(), ".git") => return Vec::new(),
+            Some(_) => format!("{} copies the whole build context and .dockerignore does not exclude .git", copy.name.to_uppercase()),
+        };
+
+        vec![Issue {
+            rule_id: self.id().to_string(),
+            rule_name: self.name().to_string(),
+            severity: self.severity(),
+            line_number: Some(copy.line_number),
+            message,
+            fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+            impact: self.impact(),
+            params: MessageParams::new(),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_detects_broad_copy_without_git_entry() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(".dockerignore"), "node_modules\n").unwrap();
+
+        let parser = DockerfileParser::parse_content("FROM node:20-slim\nCOPY . .\nCOPY . /backup");
+        let issues = GitInImageRule.check(&parser, Some(dir.path()));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+        assert!(issues[0].message.contains("does not exclude .git"));
+    }
+
+    #[test]
+    fn test_detects_broad_add_without_dockerignore() {
+        let dir = tempfile::tempdir().unwrap();
+        let parser = DockerfileParser::parse_content("FROM node:20-slim\nADD . /app");
+        let issues = GitInImageRule.check(&parser, Some(dir.path()));
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.starts_with("ADD"));
+    }
+
+    #[test]
+    fn test_detects_broad_copy_without_build_context() {
+        let parser = DockerfileParser::parse_content("FROM node:20-slim\nWORKDIR /app\nCOPY . .");
+        let issues = GitInImageRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(3));
+        assert!(issues[0].message.contains("unless .dockerignore excludes it"));
+    }
+
+    #[test]
+    fn test_allows_broad_copy_when_git_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(".dockerignore"), ".git\nnode_modules\n").unwrap();
+
+        let parser = DockerfileParser::parse_content("FROM node:20-slim\nCOPY . .");
+        assert_eq!(GitInImageRule.check(&parser, Some(dir.path())).len(), 0);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{DockerfileRule, Issue, Severity, ImpactEstimate};
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
 use crate::parser::DockerfileParser;
 use crate::rules::Rule;
 
@@ -46,6 +46,7 @@ impl DockerfileRule for HealthcheckRule {
                 message: "No HEALTHCHECK instruction - container health cannot be monitored".to_string(),
                 fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                 impact: self.impact(),
+                params: MessageParams::new(),
             });
         }
         
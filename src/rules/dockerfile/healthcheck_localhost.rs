@@ -0,0 +1,90 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+static LOCALHOST: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\blocalhost\b").unwrap()
+});
+
+pub struct HealthcheckLocalhostRule;
+
+impl Rule for HealthcheckLocalhostRule {
+    fn id(&self) -> &'static str { "DF034" }
+    fn name(&self) -> &'static str { "Healthcheck uses localhost" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "HEALTHCHECK targets 'localhost' instead of an explicit loopback address"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "'localhost' may resolve to the IPv6 address ::1 first. An application listening \
+         only on IPv4 (0.0.0.0 or 127.0.0.1) then refuses the connection and the container \
+         is reported unhealthy even though it works. Using 127.0.0.1 makes the check \
+         independent of resolver and IPv6 settings."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Use 127.0.0.1 explicitly (e.g., HEALTHCHECK CMD curl -f http://127.0.0.1:8080/health)")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Avoids false unhealthy status from IPv6 resolution".to_string()),
+        })
+    }
+}
+
+impl DockerfileRule for HealthcheckLocalhostRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for instruction in parser.get_instructions("HEALTHCHECK") {
+            let Some(command) = instruction.healthcheck_command() else {
+                continue;
+            };
+
+            if LOCALHOST.is_match(&command) {
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: Some(instruction.line_number),
+                    message: "HEALTHCHECK targets 'localhost', which may resolve to IPv6 ::1".to_string(),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: MessageParams::new(),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_localhost_healthcheck() {
+        let content = "FROM alpine\nHEALTHCHECK --interval=30s CMD curl -f http://localhost:8080/health || exit 1";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = HealthcheckLocalhostRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+    }
+
+    #[test]
+    fn test_allows_loopback_ip() {
+        let content = "FROM alpine\nHEALTHCHECK CMD [\"curl\", \"-f\", \"http://127.0.0.1:8080/health\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = HealthcheckLocalhostRule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+}
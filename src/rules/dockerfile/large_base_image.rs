@@ -1,4 +1,4 @@
-use super::{DockerfileRule, Issue, Severity, ImpactEstimate};
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
 use crate::parser::DockerfileParser;
 use crate::rules::Rule;
 
@@ -64,7 +64,7 @@ impl DockerfileRule for LargeBaseImageRule {
             let base_name = image_name_lower.rsplit('/').next().unwrap_or(&image_name_lower);
             
             // Check for known large images
-            if LARGE_IMAGES.iter().any(|&img| base_name == img) {
+            if LARGE_IMAGES.contains(&base_name) {
                 issues.push(Issue {
                     rule_id: self.id().to_string(),
                     rule_name: self.name().to_string(),
@@ -73,6 +73,7 @@ impl DockerfileRule for LargeBaseImageRule {
                     message: format!("'{}' is a large base image - consider alpine or slim variants", image_name),
                     fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                     impact: self.impact(),
+                    params: message_params([("image", &image_name)]),
                 });
             }
             // Check for non-slim/alpine variants of common images
@@ -86,6 +87,7 @@ impl DockerfileRule for LargeBaseImageRule {
                         message: format!("Consider using {} for '{}' to reduce image size", suggestion, image_name),
                         fix_suggestion: Some(format!("Use {}", suggestion)),
                         impact: self.impact(),
+                        params: message_params([("image", &image_name), ("suggestion", &suggestion)]),
                     });
                 }
             }
@@ -1,13 +1,9 @@
-use super::{DockerfileRule, Issue, Severity, ImpactEstimate};
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
 use crate::parser::DockerfileParser;
 use crate::rules::Rule;
 use regex::Regex;
 use once_cell::sync::Lazy;
 
-static LATEST_TAG_RE: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"^([^:@\s]+)(?::latest)?(?:\s|$)").unwrap()
-});
-
 static EXPLICIT_TAG_RE: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"^[^:@\s]+:[^@\s]+").unwrap()
 });
@@ -78,6 +74,7 @@ impl DockerfileRule for LatestTagRule {
                     message: format!("Image '{}' explicitly uses ':latest' tag", image_ref),
                     fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                     impact: self.impact(),
+                    params: message_params([("image", &image_ref)]),
                 });
             }
             // Check for missing tag (implicit latest)
@@ -90,6 +87,7 @@ impl DockerfileRule for LatestTagRule {
                     message: format!("Image '{}' has no tag (implicitly uses 'latest')", image_ref),
                     fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                     impact: self.impact(),
+                    params: message_params([("image", &image_ref)]),
                 });
             }
         }
@@ -1,4 +1,4 @@
-use super::{DockerfileRule, Issue, Severity, ImpactEstimate};
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
 use crate::parser::DockerfileParser;
 use crate::rules::Rule;
 
@@ -79,6 +79,7 @@ impl DockerfileRule for LayerOptimizationRule {
                     message: "Cleanup in separate layer doesn't save space - combine with install command".to_string(),
                     fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                     impact: self.impact(),
+                    params: MessageParams::new(),
                 });
             }
             
@@ -93,6 +94,7 @@ impl DockerfileRule for LayerOptimizationRule {
                     message: "apt-get update in separate RUN - combine with apt-get install using &&".to_string(),
                     fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                     impact: self.impact(),
+                    params: MessageParams::new(),
                 });
             }
         }
@@ -107,6 +109,7 @@ impl DockerfileRule for LayerOptimizationRule {
                 message: "apt-get install without --no-install-recommends may install unnecessary packages".to_string(),
                 fix_suggestion: Some("Add --no-install-recommends flag to apt-get install".to_string()),
                 impact: self.impact(),
+                params: MessageParams::new(),
             });
         }
         
@@ -1,4 +1,4 @@
-use super::{DockerfileRule, Issue, Severity, ImpactEstimate};
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
 use crate::parser::DockerfileParser;
 use crate::rules::Rule;
 
@@ -56,22 +56,21 @@ impl DockerfileRule for LayerOrderRule {
                         copy_line = Some(instruction.line_number);
                     }
                 }
-                "RUN" => {
-                    if found_broad_copy {
-                        let args = instruction.arguments.to_lowercase();
-                        // Check if this is a package install command
-                        if is_package_install(&args) {
-                            issues.push(Issue {
-                                rule_id: self.id().to_string(),
-                                rule_name: self.name().to_string(),
-                                severity: self.severity(),
-                                line_number: copy_line,
-                                message: "COPY of source files before package installation invalidates cache".to_string(),
-                                fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
-                                impact: self.impact(),
-                            });
-                            break; // Only report once
-                        }
+                "RUN" if found_broad_copy => {
+                    let args = instruction.arguments.to_lowercase();
+                    // Check if this is a package install command
+                    if is_package_install(&args) {
+                        issues.push(Issue {
+                            rule_id: self.id().to_string(),
+                            rule_name: self.name().to_string(),
+                            severity: self.severity(),
+                            line_number: copy_line,
+                            message: "COPY of source files before package installation invalidates cache".to_string(),
+                            fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                            impact: self.impact(),
+                            params: MessageParams::new(),
+                        });
+                        break; // Only report once
                     }
                 }
                 _ => {}
@@ -0,0 +1,150 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+
+/// Steps allowed in one RUN before it is reported
+pub const DEFAULT_MAX_RUN_STEPS: usize = 10;
+
+/// Flags RUN instructions chaining more than `max_steps` commands with `&&`
+pub struct LongRunChainRule {
+    pub max_steps: usize,
+}
+
+impl LongRunChainRule {
+    pub fn new(max_steps: usize) -> Self {
+        Self { max_steps }
+    }
+}
+
+impl Default for LongRunChainRule {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_RUN_STEPS)
+    }
+}
+
+/// Commands joined by top-level `&&`, ignoring any inside quotes, `$(...)` or subshells
+fn count_steps(script: &str) -> usize {
+    let mut steps = 1;
+    let mut quote: Option<char> = None;
+    let mut depth = 0usize;
+    let mut chars = script.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some('"'), '\\') => {
+                chars.next();
+            }
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '\\') => {
+                chars.next();
+            }
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, '&') if depth == 0 && chars.peek() == Some(&'&') => {
+                chars.next();
+                steps += 1;
+            }
+            _ => {}
+        }
+    }
+
+    steps
+}
+
+impl Rule for LongRunChainRule {
+    fn id(&self) -> &'static str { "DF040" }
+    fn name(&self) -> &'static str { "Long RUN chain" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "A RUN instruction chains too many commands with &&"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "When one command in a chain of dozens fails, the build log points at the whole \
+         RUN and the failing step has to be found by hand. Long chains are also hard to \
+         review and usually mix unrelated work. Grouping related steps keeps the layer \
+         count low without a single opaque instruction."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Split the chain into a few RUN instructions by purpose (e.g. install packages, build, clean up), or move it into a script with 'set -eux'")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Build failures point at a smaller step".to_string()),
+        })
+    }
+}
+
+impl DockerfileRule for LongRunChainRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for instruction in parser.get_instructions("RUN") {
+            if instruction.is_exec_form() {
+                continue;
+            }
+            let steps = count_steps(&instruction.arguments);
+            if steps <= self.max_steps {
+                continue;
+            }
+
+            issues.push(Issue {
+                rule_id: self.id().to_string(),
+                rule_name: self.name().to_string(),
+                severity: self.severity(),
+                line_number: Some(instruction.line_number),
+                message: format!("RUN chains {} commands with && (more than {})", steps, self.max_steps),
+                fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                impact: self.impact(),
+                params: message_params([("steps", &steps), ("max", &self.max_steps)]),
+            });
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(steps: usize) -> String {
+        let commands: Vec<String> = (1..=steps).map(|i| format!("echo {}", i)).collect();
+        format!("FROM alpine:3.18\nRUN {}", commands.join(" && \\\n    "))
+    }
+
+    #[test]
+    fn test_threshold_boundary() {
+        let at_limit = DockerfileParser::parse_content(&chain(10));
+        assert_eq!(LongRunChainRule::default().check(&at_limit, None).len(), 0);
+
+        let over_limit = DockerfileParser::parse_content(&chain(11));
+        let issues = LongRunChainRule::default().check(&over_limit, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+        assert!(issues[0].message.contains("11 commands"));
+    }
+
+    #[test]
+    fn test_custom_threshold() {
+        let parser = DockerfileParser::parse_content(&chain(4));
+        assert_eq!(LongRunChainRule::new(3).check(&parser, None).len(), 1);
+        assert_eq!(LongRunChainRule::new(4).check(&parser, None).len(), 0);
+    }
+
+    #[test]
+    fn test_counts_only_top_level_and() {
+        assert_eq!(count_steps("a && b && c"), 3);
+        assert_eq!(count_steps("sh -c 'a && b && c' && d"), 2);
+        assert_eq!(count_steps("x=$(a && b) && (c && d)"), 2);
+        assert_eq!(count_steps("echo \"a && b\" & wait"), 1);
+    }
+}
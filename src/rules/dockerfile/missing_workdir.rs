@@ -0,0 +1,138 @@
+use super::copy_before_workdir::root_owned_copies;
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::{DockerfileParser, Instruction};
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+/// A `./` or `../` path at the start of a shell word
+static RELATIVE_PATH: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?:^|[\s;&|('"=])\.\.?/"#).unwrap()
+});
+
+pub struct WorkdirRule;
+
+/// What in the instruction depends on the working directory, if anything
+fn relative_use(instruction: &Instruction) -> Option<&'static str> {
+    match instruction.name.to_uppercase().as_str() {
+        "COPY" | "ADD" => instruction
+            .copy_destination()
+            .filter(|dest| !dest.starts_with('/') && !dest.starts_with('$'))
+            .map(|_| "copies to a relative destination"),
+        "RUN" if !instruction.is_exec_form() && RELATIVE_PATH.is_match(&instruction.arguments) => {
+            Some("uses a relative path")
+        }
+        _ => None,
+    }
+}
+
+impl Rule for WorkdirRule {
+    fn id(&self) -> &'static str { "DF014" }
+    fn name(&self) -> &'static str { "Missing WORKDIR" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "Relative paths are used before any WORKDIR is set"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Until a WORKDIR is set, relative paths resolve against the base image's working \
+         directory, which is usually '/' but can change when the base image does. Files \
+         copied to '.' or scripts run as './build.sh' then depend on a default nobody \
+         wrote down. An explicit WORKDIR documents where the application lives."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Add 'WORKDIR /app' (or another absolute path) before the first relative COPY, ADD or RUN")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Paths no longer depend on the base image's default directory".to_string()),
+        })
+    }
+}
+
+impl DockerfileRule for WorkdirRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        // DF036 reports the broad copies that leave root-owned files for a non-root USER
+        let reported_by_df036: Vec<usize> = root_owned_copies(parser).iter().map(|(i, _)| i.line_number).collect();
+        parser
+            .before_workdir()
+            .into_iter()
+            .filter_map(|stage| {
+                stage
+                    .iter()
+                    .filter(|i| !reported_by_df036.contains(&i.line_number))
+                    .find_map(|i| relative_use(i).map(|usage| (i, usage)))
+            })
+            .map(|(instruction, usage)| {
+                let name = instruction.name.to_uppercase();
+                Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: Some(instruction.line_number),
+                    message: format!("{} {} before any WORKDIR is set", name, usage),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: message_params([("instruction", &name)]),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::dockerfile::CopyBeforeWorkdirRule;
+
+    #[test]
+    fn test_detects_relative_copy_without_workdir() {
+        let content = "FROM python:3.12-slim\nCOPY . .\nRUN ./install.sh\nCMD [\"python\", \"app.py\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = WorkdirRule.check(&parser, None);
+        // Reported once per stage
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+    }
+
+    #[test]
+    fn test_root_owned_copy_is_left_to_copy_before_workdir() {
+        let content = "FROM node:18.17.0-slim\nCOPY . .\nWORKDIR /app\nUSER node";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(WorkdirRule.check(&parser, None).len(), 0);
+
+        let issues = CopyBeforeWorkdirRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+    }
+
+    #[test]
+    fn test_detects_relative_run() {
+        let content = "FROM alpine:3.18\nRUN apk add --no-cache make\nRUN cd src && ../configure\nFROM alpine:3.18\nCOPY app.sh ./";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = WorkdirRule.check(&parser, None);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].line_number, Some(3));
+        assert_eq!(issues[1].line_number, Some(5));
+    }
+
+    #[test]
+    fn test_allows_workdir_first() {
+        let content = "FROM python:3.12-slim\nWORKDIR /app\nCOPY . .\nRUN ./install.sh\nFROM python:3.12-slim\nCOPY requirements.txt /tmp/";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(WorkdirRule.check(&parser, None).len(), 0);
+    }
+
+    #[test]
+    fn test_heredoc_body_is_not_the_destination() {
+        let content = "FROM nginx:1.25\nCOPY <<EOF /etc/nginx/conf.d/app.conf\nserver {\n  root html;\nEOF";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(WorkdirRule.check(&parser, None).len(), 0);
+    }
+}
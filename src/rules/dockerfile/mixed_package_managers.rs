@@ -0,0 +1,129 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+/// Distro package managers that never coexist in one base image
+static PACKAGE_MANAGERS: Lazy<Vec<(&'static str, Regex)>> = Lazy::new(|| {
+    vec![
+        ("apt", Regex::new(r"\bapt(?:-get)?\s+(?:-\S+\s+)*(?:install|update|upgrade|remove|purge)\b").unwrap()),
+        ("apk", Regex::new(r"\bapk\s+(?:-\S+\s+)*(?:add|update|upgrade|del)\b").unwrap()),
+        ("yum/dnf", Regex::new(r"\b(?:yum|dnf|microdnf)\s+(?:-\S+\s+)*(?:install|update|upgrade|remove|makecache)\b").unwrap()),
+        ("zypper", Regex::new(r"\bzypper\s+(?:-\S+\s+)*(?:install|in|refresh|ref|update|up)\b").unwrap()),
+    ]
+});
+
+pub struct MixedPackageManagersRule;
+
+impl Rule for MixedPackageManagersRule {
+    fn id(&self) -> &'static str { "DF026" }
+    fn name(&self) -> &'static str { "Mixed package managers" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "A build stage uses package managers from different Linux distributions"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "apt, apk and yum/dnf belong to different distributions and are never all available \
+         in the same base image. Using more than one in a stage usually means instructions \
+         were copied from another Dockerfile, and one of them will fail or silently do \
+         nothing depending on the base image."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Use only the package manager that matches the stage's base image (apt for Debian/Ubuntu, apk for Alpine, dnf/yum for RHEL)")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Prevents builds that fail on the actual base image".to_string()),
+        })
+    }
+}
+
+impl DockerfileRule for MixedPackageManagersRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        // Managers seen in the current stage, with the line they first appeared on
+        let mut seen: Vec<(&str, usize)> = Vec::new();
+        let mut reported = false;
+
+        for instruction in &parser.instructions {
+            if instruction.name == "FROM" {
+                seen.clear();
+                reported = false;
+                continue;
+            }
+
+            if instruction.name != "RUN" || reported {
+                continue;
+            }
+
+            for (manager, pattern) in PACKAGE_MANAGERS.iter() {
+                if pattern.is_match(&instruction.arguments) && !seen.iter().any(|(m, _)| m == manager) {
+                    seen.push((manager, instruction.line_number));
+                }
+            }
+
+            if seen.len() > 1 {
+                let (first, first_line) = seen[0];
+                let (second, _) = seen[1];
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: Some(instruction.line_number),
+                    message: format!(
+                        "Stage uses both '{}' (line {}) and '{}' - these belong to different base distributions",
+                        first, first_line, second
+                    ),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: message_params([("first", &first), ("first_line", &first_line), ("second", &second)]),
+                });
+                reported = true;
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_apt_and_apk() {
+        let content = "FROM debian:12-slim\nRUN apt-get update && apt-get install -y curl\nRUN apk add --no-cache git";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = MixedPackageManagersRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(3));
+    }
+
+    #[test]
+    fn test_allows_single_manager() {
+        let content = "FROM alpine:3.18\nRUN apk update\nRUN apk add --no-cache curl git";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = MixedPackageManagersRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_allows_different_managers_in_separate_stages() {
+        let content = "FROM golang:1.21-alpine AS builder\nRUN apk add --no-cache git\nFROM debian:12-slim\nRUN apt-get update && apt-get install -y ca-certificates";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = MixedPackageManagersRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+}
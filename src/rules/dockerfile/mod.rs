@@ -9,6 +9,36 @@ mod multistage;
 mod large_base_image;
 mod curl_bash;
 mod layer_optimization;
+mod temp_files;
+mod oci_labels;
+mod mixed_package_managers;
+mod digest_without_tag;
+mod app_volume;
+mod unverified_download;
+mod shell_entrypoint_cmd;
+mod exposed_port_mismatch;
+mod runtime_install;
+mod build_timestamp;
+mod healthcheck_localhost;
+mod git_directory;
+mod copy_before_workdir;
+mod cache_bust;
+mod add_vs_copy;
+mod non_root_privileged_port;
+mod apt_upgrade;
+mod missing_workdir;
+mod duplicate_cmd;
+mod shell_form;
+mod world_writable;
+mod sudo;
+mod long_run_chain;
+mod package_cache;
+mod dependency_directory;
+mod expose_port;
+mod recursive_copy;
+mod build_secret_arg;
+mod privileged_port;
+mod git_in_image;
 
 pub use latest_tag::LatestTagRule;
 pub use root_user::RootUserRule;
@@ -21,8 +51,38 @@ pub use multistage::MultistageRule;
 pub use large_base_image::LargeBaseImageRule;
 pub use curl_bash::CurlBashRule;
 pub use layer_optimization::LayerOptimizationRule;
+pub use temp_files::TempFilesRule;
+pub use oci_labels::OciLabelsRule;
+pub use mixed_package_managers::MixedPackageManagersRule;
+pub use digest_without_tag::DigestWithoutTagRule;
+pub use app_volume::AppVolumeRule;
+pub use unverified_download::UnverifiedDownloadRule;
+pub use shell_entrypoint_cmd::ShellEntrypointCmdRule;
+pub use exposed_port_mismatch::ExposedPortMismatchRule;
+pub use runtime_install::RuntimeInstallRule;
+pub use build_timestamp::BuildTimestampRule;
+pub use healthcheck_localhost::HealthcheckLocalhostRule;
+pub use git_directory::GitDirectoryRule;
+pub use copy_before_workdir::CopyBeforeWorkdirRule;
+pub use cache_bust::CacheBustRule;
+pub use add_vs_copy::AddVsCopyRule;
+pub use non_root_privileged_port::NonRootPrivilegedPortRule;
+pub use apt_upgrade::AptUpgradeRule;
+pub use missing_workdir::WorkdirRule;
+pub use duplicate_cmd::DuplicateCmdRule;
+pub use shell_form::ShellFormRule;
+pub use world_writable::WorldWritableRule;
+pub use sudo::SudoRule;
+pub use package_cache::PackageCacheRule;
+pub use dependency_directory::DependencyDirectoryRule;
+pub use expose_port::ExposePortRule;
+pub use recursive_copy::RecursiveCopyRule;
+pub use build_secret_arg::BuildSecretArgRule;
+pub use privileged_port::PrivilegedPortRule;
+pub use git_in_image::GitInImageRule;
+pub use long_run_chain::{LongRunChainRule, DEFAULT_MAX_RUN_STEPS};
 
-use super::{Rule, Issue, Severity, ImpactEstimate};
+use super::{Rule, Issue, Severity, ImpactEstimate, MessageParams, message_params};
 use crate::parser::DockerfileParser;
 
 /// Trait for Dockerfile-specific rules
@@ -1,4 +1,4 @@
-use super::{DockerfileRule, Issue, Severity, ImpactEstimate};
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
 use crate::parser::DockerfileParser;
 use crate::rules::Rule;
 
@@ -69,6 +69,7 @@ impl DockerfileRule for MultistageRule {
                     message: "Single-stage build with compilation detected - consider multi-stage build".to_string(),
                     fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                     impact: self.impact(),
+                    params: MessageParams::new(),
                 });
             }
         }
@@ -1,4 +1,4 @@
-use super::{DockerfileRule, Issue, Severity, ImpactEstimate};
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
 use crate::parser::DockerfileParser;
 use crate::rules::Rule;
 use std::path::Path;
@@ -48,6 +48,7 @@ impl DockerfileRule for NoDockerignoreRule {
                     message: "No .dockerignore file found in the build context".to_string(),
                     fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                     impact: self.impact(),
+                    params: MessageParams::new(),
                 });
             }
         }
@@ -0,0 +1,131 @@
+use super::privileged_port::{is_root, FIRST_UNPRIVILEGED_PORT};
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::{parse_exposed_ports, DockerfileParser, Instruction};
+use crate::rules::Rule;
+
+pub struct NonRootPrivilegedPortRule;
+
+impl Rule for NonRootPrivilegedPortRule {
+    fn id(&self) -> &'static str { "DF038" }
+    fn name(&self) -> &'static str { "Privileged port with non-root user" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "A port below 1024 is exposed while the container runs as a non-root user"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Binding a port below 1024 requires root or the CAP_NET_BIND_SERVICE capability. \
+         When the image switches to a non-root USER but EXPOSEs a privileged port, the \
+         application fails to start with 'permission denied' unless the runtime adds the \
+         capability - a failure that only shows up when the container is run."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Listen on a port >= 1024 (e.g., 8080) and map it at runtime, or grant CAP_NET_BIND_SERVICE (setcap 'cap_net_bind_service=+ep' on the binary)")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Container can bind its port without extra privileges".to_string()),
+        })
+    }
+}
+
+impl DockerfileRule for NonRootPrivilegedPortRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        // Only the final stage runs, so track USER and EXPOSE per stage
+        let mut user: Option<&str> = None;
+        let mut exposes: Vec<&Instruction> = Vec::new();
+
+        for instruction in &parser.instructions {
+            match instruction.name.to_uppercase().as_str() {
+                "FROM" => {
+                    user = None;
+                    exposes.clear();
+                }
+                "USER" => user = Some(instruction.arguments.trim()),
+                "EXPOSE" => exposes.push(instruction),
+                _ => {}
+            }
+        }
+
+        let Some(user) = user.filter(|u| !is_root(u)) else {
+            return Vec::new();
+        };
+        // Binaries given the capability with setcap are fine
+        let grants_capability = parser.get_instructions("RUN").iter().any(|run| {
+            let args = run.arguments.to_lowercase();
+            args.contains("setcap") && args.contains("cap_net_bind_service")
+        });
+        if grants_capability {
+            return Vec::new();
+        }
+
+        let mut issues = Vec::new();
+        for expose in exposes {
+            for port in parse_exposed_ports(&expose.arguments) {
+                if port.start >= FIRST_UNPRIVILEGED_PORT {
+                    continue;
+                }
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: Some(expose.line_number),
+                    message: format!("Port {} is privileged but the container runs as '{}'", port.start, user),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: message_params([("port", &port.start), ("user", &user)]),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_privileged_port_with_non_root_user() {
+        let content = "FROM nginx:1.25-alpine\nUSER app\nEXPOSE 80\nCMD [\"nginx\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = NonRootPrivilegedPortRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(3));
+        assert!(issues[0].message.contains("80"));
+        assert!(issues[0].message.contains("app"));
+    }
+
+    #[test]
+    fn test_allows_high_port() {
+        let content = "FROM nginx:1.25-alpine\nUSER app\nEXPOSE 8080\nCMD [\"nginx\"]";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(NonRootPrivilegedPortRule.check(&parser, None).len(), 0);
+    }
+
+    #[test]
+    fn test_allows_root_and_earlier_stages() {
+        let content = "FROM golang:1.21 AS build\nUSER builder\nEXPOSE 80\nFROM nginx:1.25-alpine\nEXPOSE 80\nUSER root";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(NonRootPrivilegedPortRule.check(&parser, None).len(), 0);
+    }
+
+    #[test]
+    fn test_setcap_grant_exempts_the_port() {
+        let content = "FROM nginx:1.25-alpine\nRUN setcap 'CAP_NET_BIND_SERVICE=+ep' /usr/sbin/nginx\nUSER app\nEXPOSE 80";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(NonRootPrivilegedPortRule.check(&parser, None).len(), 0);
+
+        // A mention outside a RUN setcap grants nothing
+        let content = "FROM nginx:1.25-alpine\n# needs cap_net_bind_service\nUSER app\nEXPOSE 80";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(NonRootPrivilegedPortRule.check(&parser, None).len(), 1);
+    }
+}
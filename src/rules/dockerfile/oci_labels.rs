@@ -0,0 +1,91 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+
+const OCI_LABEL_PREFIX: &str = "org.opencontainers.image.";
+
+const RECOMMENDED_LABELS: &[&str] = &[
+    "org.opencontainers.image.source",
+    "org.opencontainers.image.version",
+    "org.opencontainers.image.licenses",
+];
+
+pub struct OciLabelsRule;
+
+impl Rule for OciLabelsRule {
+    fn id(&self) -> &'static str { "DF025" }
+    fn name(&self) -> &'static str { "Missing OCI labels" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "Image does not declare standard OCI metadata labels"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "OCI image labels record where an image came from, which version it is and how \
+         it is licensed. Registries, scanners and humans rely on them to trace a running \
+         container back to its source repository and release."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Add LABEL org.opencontainers.image.source=<repo-url> org.opencontainers.image.version=<version> org.opencontainers.image.licenses=<spdx-id>")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Images can be traced back to their source".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl DockerfileRule for OciLabelsRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        let has_oci_label = parser.labels()
+            .iter()
+            .any(|(key, _)| key.starts_with(OCI_LABEL_PREFIX));
+
+        if !has_oci_label {
+            issues.push(Issue {
+                rule_id: self.id().to_string(),
+                rule_name: self.name().to_string(),
+                severity: self.severity(),
+                line_number: None,
+                message: format!("No OCI labels found - consider adding {}", RECOMMENDED_LABELS.join(", ")),
+                fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                impact: self.impact(),
+                params: MessageParams::new(),
+            });
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_missing_labels() {
+        let content = "FROM alpine:3.18\nCMD [\"./app\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = OciLabelsRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("org.opencontainers.image.source"));
+    }
+
+    #[test]
+    fn test_allows_source_label() {
+        let content = "FROM alpine:3.18\nLABEL org.opencontainers.image.source=\"https://github.com/example/app\"\nCMD [\"./app\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = OciLabelsRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+}
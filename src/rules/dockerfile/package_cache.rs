@@ -0,0 +1,168 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+static PIP_INSTALL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bpip3?\s+(?:-\S+\s+)*install\b").unwrap()
+});
+
+static NPM_INSTALL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bnpm\s+(?:-\S+\s+)*(?:install|i|ci)\b").unwrap()
+});
+
+static NPM_CACHE_CLEAN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bnpm\s+cache\s+clean\b").unwrap()
+});
+
+static APK_ADD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bapk\s+(?:-\S+\s+)*add\b").unwrap()
+});
+
+/// The rest of the shell command starting at `start`, up to the next `&&`, `||`, `;` or `|`
+fn command_at(script: &str, start: usize) -> &str {
+    let rest = &script[start..];
+    let end = rest.find(['&', ';', '|']).unwrap_or(rest.len());
+    &rest[..end]
+}
+
+pub struct PackageCacheRule;
+
+impl PackageCacheRule {
+    fn issue(&self, line_number: usize, manager: &str, message: &str, fix: &str) -> Issue {
+        Issue {
+            rule_id: self.id().to_string(),
+            rule_name: self.name().to_string(),
+            severity: self.severity(),
+            line_number: Some(line_number),
+            message: message.to_string(),
+            fix_suggestion: Some(fix.to_string()),
+            impact: self.impact(),
+            params: message_params([("manager", &manager)]),
+        }
+    }
+}
+
+impl Rule for PackageCacheRule {
+    fn id(&self) -> &'static str { "DF019" }
+    fn name(&self) -> &'static str { "Package manager cache kept" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "pip, npm or apk leaves its download cache in the image"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "pip and npm keep every downloaded package in a cache directory, and apk keeps its \
+         package index. None of it is used at runtime, but it is written to the layer of \
+         the RUN that installed the packages and ships with the image. Deleting it in a \
+         later instruction does not reclaim the space."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Use 'pip install --no-cache-dir', 'apk add --no-cache', and end npm installs with '&& npm cache clean --force'")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: Some("Typically 10-100MB of cached packages".to_string()),
+            security_improvement: None,
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl DockerfileRule for PackageCacheRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let pip_cache_disabled = parser
+            .get_instructions("ENV")
+            .iter()
+            .any(|env| env.arguments.contains("PIP_NO_CACHE_DIR"));
+
+        for instruction in parser.get_instructions("RUN") {
+            let args = &instruction.arguments;
+            // A cache mount keeps the cache out of the layer
+            if instruction.has_cache_mount() {
+                continue;
+            }
+
+            if !pip_cache_disabled {
+                if let Some(found) = PIP_INSTALL.find_iter(args).find(|m| !command_at(args, m.start()).contains("--no-cache-dir")) {
+                    issues.push(self.issue(
+                        instruction.line_at(found.start()),
+                        "pip",
+                        "pip install without --no-cache-dir keeps downloaded packages in the image",
+                        "Add --no-cache-dir to pip install (or set ENV PIP_NO_CACHE_DIR=1)",
+                    ));
+                }
+            }
+
+            if let Some(found) = NPM_INSTALL.find(args) {
+                if !NPM_CACHE_CLEAN.is_match(&args[found.end()..]) {
+                    issues.push(self.issue(
+                        instruction.line_at(found.start()),
+                        "npm",
+                        "npm install is not followed by 'npm cache clean --force' in the same RUN",
+                        "Append '&& npm cache clean --force' to the RUN that installs packages",
+                    ));
+                }
+            }
+
+            if !args.contains("/var/cache/apk") {
+                if let Some(found) = APK_ADD.find_iter(args).find(|m| !command_at(args, m.start()).contains("--no-cache")) {
+                    issues.push(self.issue(
+                        instruction.line_at(found.start()),
+                        "apk",
+                        "apk add without --no-cache keeps the package index in the image",
+                        "Use 'apk add --no-cache' instead of 'apk update && apk add'",
+                    ));
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(content: &str) -> Vec<Issue> {
+        PackageCacheRule.check(&DockerfileParser::parse_content(content), None)
+    }
+
+    #[test]
+    fn test_detects_pip_without_no_cache_dir() {
+        let issues = check("FROM python:3.12-slim\nRUN pip install requests");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+        assert!(issues[0].message.contains("--no-cache-dir"));
+    }
+
+    #[test]
+    fn test_allows_cache_free_installs() {
+        assert_eq!(check("FROM python:3.12-slim\nRUN pip install --no-cache-dir requests").len(), 0);
+        assert_eq!(check("FROM alpine:3.19\nRUN apk add --no-cache curl").len(), 0);
+        assert_eq!(check("FROM node:20-slim\nRUN npm ci && npm cache clean --force").len(), 0);
+        assert_eq!(check("FROM python:3.12-slim\nENV PIP_NO_CACHE_DIR=1\nRUN pip install requests").len(), 0);
+    }
+
+    #[test]
+    fn test_allows_cache_mount() {
+        let content = "FROM python:3.12-slim\nRUN --mount=type=cache,target=/root/.cache/pip pip install requests";
+        assert_eq!(check(content).len(), 0);
+        let content = "FROM node:20-alpine\nRUN --mount=type=cache,target=/root/.npm \\\n    npm ci";
+        assert_eq!(check(content).len(), 0);
+    }
+
+    #[test]
+    fn test_distinct_message_per_manager() {
+        let issues = check("FROM node:20-alpine\nRUN apk add curl\nRUN npm install && \\\n    pip3 install awscli");
+        let lines: Vec<_> = issues.iter().map(|i| (i.params["manager"].as_str(), i.line_number)).collect();
+        assert_eq!(lines, vec![("apk", Some(2)), ("pip", Some(4)), ("npm", Some(3))]);
+    }
+}
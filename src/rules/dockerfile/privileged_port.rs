@@ -0,0 +1,115 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::{parse_exposed_ports, DockerfileParser};
+use crate::rules::Rule;
+
+/// Ports below this need root or CAP_NET_BIND_SERVICE to bind
+pub(super) const FIRST_UNPRIVILEGED_PORT: u16 = 1024;
+
+pub struct PrivilegedPortRule;
+
+pub(super) fn is_root(user: &str) -> bool {
+    let name = user.split(':').next().unwrap_or("").trim();
+    name.eq_ignore_ascii_case("root") || name == "0"
+}
+
+impl Rule for PrivilegedPortRule {
+    fn id(&self) -> &'static str { "DF021" }
+    fn name(&self) -> &'static str { "Privileged port without non-root user" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "A port below 1024 is exposed and the image never switches to a non-root user"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Binding a port below 1024 needs root or the NET_BIND_SERVICE capability. An image \
+         that exposes one and keeps running as root usually does so only to bind that port, \
+         leaving every other part of the process with root privileges. Listening on a high \
+         port lets the image drop to a non-root user."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Listen on a high port (e.g. 8080), add a non-root USER, and put a reverse proxy or a port mapping in front of it")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Service no longer needs root to bind its port".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl DockerfileRule for PrivilegedPortRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        // Only the final stage's USER and EXPOSE end up in the image
+        let mut user: Option<&str> = None;
+        let mut low_ports: Vec<(u16, usize)> = Vec::new();
+
+        for instruction in &parser.instructions {
+            match instruction.name.to_uppercase().as_str() {
+                "FROM" => {
+                    user = None;
+                    low_ports.clear();
+                }
+                "USER" => user = Some(instruction.arguments.trim()),
+                "EXPOSE" => low_ports.extend(
+                    parse_exposed_ports(&instruction.arguments)
+                        .into_iter()
+                        .filter(|port| port.start < FIRST_UNPRIVILEGED_PORT)
+                        .map(|port| (port.start, instruction.line_number)),
+                ),
+                _ => {}
+            }
+        }
+
+        if user.is_some_and(|u| !is_root(u)) {
+            return Vec::new();
+        }
+        let Some(&(_, line_number)) = low_ports.first() else {
+            return Vec::new();
+        };
+
+        let ports: Vec<String> = low_ports.iter().map(|(port, _)| port.to_string()).collect();
+        let ports = ports.join(", ");
+        vec![Issue {
+            rule_id: self.id().to_string(),
+            rule_name: self.name().to_string(),
+            severity: self.severity(),
+            line_number: Some(line_number),
+            message: format!("Privileged port {} is exposed and the container runs as root", ports),
+            fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+            impact: self.impact(),
+            params: message_params([("port", &ports)]),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_privileged_port_without_user() {
+        let parser = DockerfileParser::parse_content("FROM nginx:1.25\nEXPOSE 80 443\nCMD [\"nginx\"]");
+        let issues = PrivilegedPortRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+        assert!(issues[0].message.contains("80, 443"));
+        assert!(issues[0].fix_suggestion.as_deref().unwrap().contains("reverse proxy"));
+    }
+
+    #[test]
+    fn test_allows_high_port_with_non_root_user() {
+        let parser = DockerfileParser::parse_content("FROM node:20-slim\nUSER app\nEXPOSE 8080");
+        assert_eq!(PrivilegedPortRule.check(&parser, None).len(), 0);
+    }
+
+    #[test]
+    fn test_explicit_root_user_is_flagged() {
+        let parser = DockerfileParser::parse_content("FROM node:20-slim AS build\nUSER node\nFROM nginx:1.25\nUSER 0:0\nEXPOSE 80");
+        assert_eq!(PrivilegedPortRule.check(&parser, None).len(), 1);
+    }
+}
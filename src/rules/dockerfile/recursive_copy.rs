@@ -0,0 +1,88 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+/// `cp` in command position with `-r` or `-R`, alone or leading a cluster such as `-rf`
+static RECURSIVE_CP: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|&&|\|\||[;|(])\s*cp\s+(?:-\S+\s+)*-[rR][a-zA-Z]*\s").unwrap()
+});
+
+pub struct RecursiveCopyRule;
+
+impl Rule for RecursiveCopyRule {
+    fn id(&self) -> &'static str { "DF042" }
+    fn name(&self) -> &'static str { "Directory duplicated with cp -r" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "RUN copies a directory tree inside the image with cp -r"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "The copy is written to a new layer while the original stays in the layer below, \
+         so the image carries the directory twice. Copying from the build context or an \
+         earlier stage to the final path, moving instead of copying, or a symlink keeps \
+         a single copy."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("COPY the directory straight to its final path (or COPY --from a build stage), or use mv or ln -s instead of cp -r")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: Some("Saves the size of the copied directory".to_string()),
+            security_improvement: None,
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl DockerfileRule for RecursiveCopyRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for instruction in parser.get_instructions("RUN") {
+            if let Some(found) = RECURSIVE_CP.find(&instruction.arguments) {
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: Some(instruction.line_at(found.start())),
+                    message: "RUN duplicates a directory with cp -r, storing it twice in the image".to_string(),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: MessageParams::new(),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_recursive_cp() {
+        let content = "FROM alpine:3.19\nRUN cp -r /src /dst\nRUN mkdir -p /out && cp -Rp /app /out/app";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = RecursiveCopyRule.check(&parser, None);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].line_number, Some(2));
+        assert_eq!(issues[1].line_number, Some(3));
+    }
+
+    #[test]
+    fn test_allows_single_file_cp() {
+        // Only -r/-R are matched; --recursive and -a are left alone to stay conservative
+        let content = "FROM alpine:3.19\nRUN cp /etc/app/default.conf /etc/app/app.conf\nRUN cp -f config.sample config\nRUN cp --recursive /src /dst\nRUN cp -ar /src /dst";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(RecursiveCopyRule.check(&parser, None).len(), 0);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{DockerfileRule, Issue, Severity, ImpactEstimate};
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
 use crate::parser::DockerfileParser;
 use crate::rules::Rule;
 
@@ -51,6 +51,7 @@ impl DockerfileRule for RootUserRule {
                 message: "No USER instruction found - container will run as root".to_string(),
                 fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                 impact: self.impact(),
+                params: MessageParams::new(),
             });
         } else {
             // Check if the last USER instruction sets root
@@ -65,6 +66,7 @@ impl DockerfileRule for RootUserRule {
                         message: "Container explicitly set to run as root".to_string(),
                         fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                         impact: self.impact(),
+                        params: MessageParams::new(),
                     });
                 }
             }
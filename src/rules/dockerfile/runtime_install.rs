@@ -0,0 +1,123 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+/// `echo`/`printf` output redirected or tee'd into a shell script
+static SCRIPT_WRITE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\b(?:echo|printf)\s+((?:'[^']*'|"[^"]*"|[^;&|>'"])*?)\s*(?:>>?|\|\s*tee\s+(?:-a\s+)?)\s*([^\s;&|'"]+\.sh)\b"#).unwrap()
+});
+
+static PACKAGE_INSTALL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:apt-get\s+(?:-\S+\s+)*install|apt\s+install|apk\s+add|yum\s+install|dnf\s+install|microdnf\s+install|zypper\s+(?:install|in)|pip3?\s+install|npm\s+install|gem\s+install)\b").unwrap()
+});
+
+pub struct RuntimeInstallRule;
+
+impl Rule for RuntimeInstallRule {
+    fn id(&self) -> &'static str { "DF032" }
+    fn name(&self) -> &'static str { "Packages installed at container start" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "Entrypoint script written during the build installs packages at runtime"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Installing packages from an ENTRYPOINT/CMD script repeats the install on every \
+         container start. Startup becomes slow and depends on package mirrors being \
+         reachable, and each container may end up with different package versions, so \
+         the image is no longer what you tested."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Move the package installation into a RUN instruction so it happens once at build time")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Faster, reproducible container startup".to_string()),
+        })
+    }
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+impl DockerfileRule for RuntimeInstallRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        // Scripts started by ENTRYPOINT or CMD, by file name
+        let started: Vec<String> = parser.instructions.iter()
+            .filter(|i| i.name.eq_ignore_ascii_case("ENTRYPOINT") || i.name.eq_ignore_ascii_case("CMD"))
+            .flat_map(|i| {
+                i.exec_args().unwrap_or_else(|| {
+                    i.arguments.split_whitespace().map(|s| s.to_string()).collect()
+                })
+            })
+            .filter(|arg| arg.ends_with(".sh"))
+            .map(|arg| file_name(&arg).to_string())
+            .collect();
+
+        if started.is_empty() {
+            return issues;
+        }
+
+        for instruction in parser.get_instructions("RUN") {
+            for captures in SCRIPT_WRITE.captures_iter(&instruction.arguments) {
+                let script = &captures[2];
+                if started.iter().any(|s| s == file_name(script)) && PACKAGE_INSTALL.is_match(&captures[1]) {
+                    issues.push(Issue {
+                        rule_id: self.id().to_string(),
+                        rule_name: self.name().to_string(),
+                        severity: self.severity(),
+                        line_number: Some(instruction.line_at(captures.get(0).map_or(0, |m| m.start()))),
+                        message: format!("Entrypoint script '{}' installs packages every time the container starts", script),
+                        fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                        impact: self.impact(),
+                        params: message_params([("script", &script)]),
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_install_in_generated_entrypoint() {
+        let content = "FROM debian:12-slim\nRUN printf '#!/bin/sh\\napt-get update && apt-get install -y curl\\nexec \"$@\"\\n' > /entrypoint.sh \\\n    && chmod +x /entrypoint.sh\nENTRYPOINT [\"/entrypoint.sh\"]\nCMD [\"app\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = RuntimeInstallRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+        assert!(issues[0].message.contains("/entrypoint.sh"));
+    }
+
+    #[test]
+    fn test_allows_build_time_install_with_entrypoint() {
+        let content = "FROM debian:12-slim\nRUN apt-get update && apt-get install -y curl && echo 'exec \"$@\"' > /entrypoint.sh\nENTRYPOINT [\"/entrypoint.sh\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = RuntimeInstallRule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_ignores_scripts_not_started() {
+        let content = "FROM debian:12-slim\nRUN echo 'apt-get install -y vim' > /usr/local/bin/setup.sh\nCMD [\"app\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = RuntimeInstallRule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+}
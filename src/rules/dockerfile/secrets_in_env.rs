@@ -1,13 +1,25 @@
-use super::{DockerfileRule, Issue, Severity, ImpactEstimate};
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
 use crate::parser::DockerfileParser;
 use crate::rules::Rule;
 use regex::Regex;
 use once_cell::sync::Lazy;
 
+/// Variable names that usually hold a secret
+const SECRET_NAMES: &str = "password|passwd|secret|api_key|apikey|auth_token|access_token|private_key|credentials?";
+
 static SECRET_PATTERN: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?i)(password|passwd|secret|api_key|apikey|auth_token|access_token|private_key|credentials?)\s*=").unwrap()
+    Regex::new(&format!(r"(?i)({})\s*=", SECRET_NAMES)).unwrap()
+});
+
+static SECRET_NAME: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(r"(?i){}", SECRET_NAMES)).unwrap()
 });
 
+/// Whether a variable name matches the names DF006 treats as secrets (e.g. `DB_PASSWORD`)
+pub(super) fn is_secret_name(name: &str) -> bool {
+    SECRET_NAME.is_match(name)
+}
+
 pub struct SecretsInEnvRule;
 
 impl Rule for SecretsInEnvRule {
@@ -53,6 +65,7 @@ impl DockerfileRule for SecretsInEnvRule {
                     message: "Potential secret detected in ENV instruction".to_string(),
                     fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                     impact: self.impact(),
+                    params: MessageParams::new(),
                 });
             }
         }
@@ -68,6 +81,7 @@ impl DockerfileRule for SecretsInEnvRule {
                     message: "Potential secret detected in ARG instruction (visible in image history)".to_string(),
                     fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                     impact: self.impact(),
+                    params: MessageParams::new(),
                 });
             }
         }
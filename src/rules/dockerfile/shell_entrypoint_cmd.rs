@@ -0,0 +1,96 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
+use crate::parser::{DockerfileParser, Instruction};
+use crate::rules::Rule;
+
+pub struct ShellEntrypointCmdRule;
+
+impl Rule for ShellEntrypointCmdRule {
+    fn id(&self) -> &'static str { "DF030" }
+    fn name(&self) -> &'static str { "CMD ignored by shell-form ENTRYPOINT" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "Shell-form ENTRYPOINT combined with CMD"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A shell-form ENTRYPOINT runs as '/bin/sh -c \"<command>\"', and anything from CMD \
+         or 'docker run' arguments is passed to the shell rather than to your program, so \
+         it is silently dropped. The CMD in this image never takes effect. Shell form also \
+         makes the shell PID 1, so your process does not receive SIGTERM on stop."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Use the exec form so CMD is appended as arguments: ENTRYPOINT [\"./app\"]")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Container starts with the intended arguments and stops cleanly".to_string()),
+        })
+    }
+}
+
+impl DockerfileRule for ShellEntrypointCmdRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        shell_entrypoints_with_cmd(parser)
+            .into_iter()
+            .map(|entrypoint| Issue {
+                rule_id: self.id().to_string(),
+                rule_name: self.name().to_string(),
+                severity: self.severity(),
+                line_number: Some(entrypoint.line_number),
+                message: "ENTRYPOINT uses shell form, so CMD arguments are never passed to it".to_string(),
+                fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                impact: self.impact(),
+                params: MessageParams::new(),
+            })
+            .collect()
+    }
+}
+
+/// Per stage, its effective ENTRYPOINT when it is in shell form and the stage sets a CMD.
+/// DF016 leaves these to this rule so the line is only reported once.
+pub(super) fn shell_entrypoints_with_cmd(parser: &DockerfileParser) -> Vec<&Instruction> {
+    parser
+        .stages()
+        .into_iter()
+        .filter(|stage| stage.instructions.iter().any(|i| i.name.eq_ignore_ascii_case("CMD")))
+        // Only the last ENTRYPOINT of a stage takes effect
+        .filter_map(|stage| stage.instructions.iter().rev().find(|i| i.name.eq_ignore_ascii_case("ENTRYPOINT")))
+        .filter(|entrypoint| !entrypoint.is_exec_form())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_shell_entrypoint_with_cmd() {
+        let content = "FROM alpine:3.18\nENTRYPOINT ./app\nCMD [\"--flag\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = ShellEntrypointCmdRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+    }
+
+    #[test]
+    fn test_allows_exec_entrypoint_with_cmd() {
+        let content = "FROM alpine:3.18\nENTRYPOINT [\"./app\"]\nCMD [\"--flag\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = ShellEntrypointCmdRule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_allows_shell_entrypoint_without_cmd() {
+        let content = "FROM alpine:3.18\nENTRYPOINT ./app --flag\nFROM alpine:3.18\nCMD [\"./other\"]";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = ShellEntrypointCmdRule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+}
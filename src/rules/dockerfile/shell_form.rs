@@ -0,0 +1,130 @@
+use super::shell_entrypoint_cmd::shell_entrypoints_with_cmd;
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::{DockerfileParser, Instruction};
+use crate::rules::Rule;
+
+/// Characters that need a shell, so the command cannot be split into an exec array as-is
+const SHELL_SYNTAX: &[char] = &['$', '&', '|', ';', '<', '>', '`', '*', '?', '(', ')', '"', '\'', '\\', '~'];
+
+pub struct ShellFormRule;
+
+/// The exec-form equivalent of a simple shell-form command (`node app.js` → `["node","app.js"]`)
+fn exec_form(instruction: &Instruction) -> Option<String> {
+    let command = instruction.arguments.trim();
+    if command.is_empty() || command.contains(SHELL_SYNTAX) {
+        return None;
+    }
+    let words: Vec<&str> = command.split_whitespace().collect();
+    serde_json::to_string(&words).ok()
+}
+
+impl Rule for ShellFormRule {
+    fn id(&self) -> &'static str { "DF016" }
+    fn name(&self) -> &'static str { "Shell-form CMD or ENTRYPOINT" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "CMD or ENTRYPOINT uses the shell form"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Shell form runs the command as '/bin/sh -c \"...\"', making the shell PID 1. The \
+         shell does not forward SIGTERM from 'docker stop' to your process, so it cannot \
+         shut down gracefully and is killed after the stop timeout. The exec form starts \
+         the process directly so it receives signals itself."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Use the JSON exec form (e.g. CMD [\"node\", \"app.js\"]); if shell features are needed, move them into a script that ends with 'exec'")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: None,
+            reliability_improvement: Some("Containers stop gracefully on SIGTERM".to_string()),
+        })
+    }
+}
+
+impl DockerfileRule for ShellFormRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        // DF030 already reports a shell-form ENTRYPOINT that swallows CMD
+        let reported_by_df030: Vec<usize> = shell_entrypoints_with_cmd(parser).iter().map(|i| i.line_number).collect();
+
+        for instruction in &parser.instructions {
+            let name = instruction.name.to_uppercase();
+            if !matches!(name.as_str(), "CMD" | "ENTRYPOINT") || instruction.arguments.trim_start().starts_with('[') {
+                continue;
+            }
+            if reported_by_df030.contains(&instruction.line_number) {
+                continue;
+            }
+
+            let fix = match exec_form(instruction) {
+                Some(exec) => format!("Use the exec form: {} {}", name, exec),
+                None => self.fix_suggestion().unwrap_or_default().to_string(),
+            };
+            issues.push(Issue {
+                rule_id: self.id().to_string(),
+                rule_name: self.name().to_string(),
+                severity: self.severity(),
+                line_number: Some(instruction.line_number),
+                message: format!("{} uses shell form, so /bin/sh is PID 1 and SIGTERM never reaches the process", name),
+                fix_suggestion: Some(fix),
+                impact: self.impact(),
+                params: message_params([("instruction", &name)]),
+            });
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_shell_form_cmd() {
+        let content = "FROM node:18-alpine\nCMD node app.js";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = ShellFormRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+        assert!(issues[0].message.contains("SIGTERM"));
+        assert_eq!(issues[0].fix_suggestion.as_deref(), Some("Use the exec form: CMD [\"node\",\"app.js\"]"));
+    }
+
+    #[test]
+    fn test_allows_exec_form() {
+        let content = "FROM node:18-alpine\nENTRYPOINT [\"tini\", \"--\"]\nCMD [\"node\",\"app.js\"]";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(ShellFormRule.check(&parser, None).len(), 0);
+    }
+
+    #[test]
+    fn test_shell_syntax_gets_generic_fix() {
+        let content = "FROM alpine:3.18\nENTRYPOINT ./migrate && ./serve";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = ShellFormRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].fix_suggestion.as_deref().unwrap().contains("exec"));
+    }
+
+    #[test]
+    fn test_entrypoint_swallowing_cmd_is_left_to_df030() {
+        let content = "FROM alpine:3.18\nENTRYPOINT ./app\nCMD [\"--flag\"]";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(ShellFormRule.check(&parser, None).len(), 0);
+
+        let report = crate::analyzer::Analyzer::new().analyze_dockerfile_str(content).unwrap();
+        let on_entrypoint: Vec<&str> = report.issues.iter()
+            .filter(|i| matches!(i.rule_id.as_str(), "DF016" | "DF030"))
+            .map(|i| i.rule_id.as_str())
+            .collect();
+        assert_eq!(on_entrypoint, vec!["DF030"]);
+    }
+}
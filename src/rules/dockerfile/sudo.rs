@@ -0,0 +1,88 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+/// `sudo` in command position: at the start of the script or after `&&`, `||`, `;`, `|` or `(`,
+/// so `apt-get install -y sudo` does not match
+static SUDO_COMMAND: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|&&|\|\||[;|(])\s*sudo(?:\s|$)").unwrap()
+});
+
+pub struct SudoRule;
+
+impl Rule for SudoRule {
+    fn id(&self) -> &'static str { "DF018" }
+    fn name(&self) -> &'static str { "sudo in RUN" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "RUN calls sudo"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Build steps already run as root unless a USER says otherwise, so sudo adds \
+         nothing but a dependency on the sudo package. After a non-root USER it fails or \
+         needs a passwordless sudoers entry, which hands root back to the runtime user. \
+         It is usually a sign of a script copied from a workstation."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Drop the 'sudo' prefix; switch with 'USER root' and back if the step really needs root after a USER")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("No sudo path from the runtime user to root".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl DockerfileRule for SudoRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for instruction in parser.get_instructions("RUN") {
+            if let Some(found) = SUDO_COMMAND.find(&instruction.arguments) {
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: Some(instruction.line_at(found.start())),
+                    message: "RUN uses sudo, but build steps already run as root".to_string(),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: MessageParams::new(),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_sudo() {
+        let content = "FROM ubuntu:22.04\nRUN sudo apt-get install -y curl\nRUN apt-get update && sudo make install";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = SudoRule.check(&parser, None);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].line_number, Some(2));
+        assert_eq!(issues[1].line_number, Some(3));
+    }
+
+    #[test]
+    fn test_allows_installing_sudo_package() {
+        let content = "FROM ubuntu:22.04\nRUN apt-get install -y sudo && echo 'app ALL=(ALL) NOPASSWD:ALL' > /etc/sudoers.d/app";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(SudoRule.check(&parser, None).len(), 0);
+    }
+}
@@ -0,0 +1,104 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+static DOWNLOAD_TO_TMP: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:curl|wget)\b[^;&|]*?\s(?:-o|-O|--output|--output-document|-P|--directory-prefix)[\s=]+((?:/var)?/tmp(?:/[^\s;&|]*)?)").unwrap()
+});
+
+static TMP_CLEANUP: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\brm\s+(?:-[a-zA-Z]+\s+)*[^;&|]*(?:/var)?/tmp").unwrap()
+});
+
+pub struct TempFilesRule;
+
+impl Rule for TempFilesRule {
+    fn id(&self) -> &'static str { "DF024" }
+    fn name(&self) -> &'static str { "Temporary files left in layer" }
+    fn severity(&self) -> Severity { Severity::Suggestion }
+
+    fn description(&self) -> &'static str {
+        "Files downloaded to /tmp or /var/tmp are not removed in the same RUN"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Anything written during a RUN is persisted in that layer. Downloading archives or \
+         installers to /tmp and not deleting them in the same RUN keeps them in the image \
+         forever - removing them in a later instruction does not reclaim the space."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Remove the downloaded files in the same RUN (e.g., ... && rm -rf /tmp/download)")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: Some("Saves the size of the downloaded files".to_string()),
+            security_improvement: None,
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl DockerfileRule for TempFilesRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for instruction in parser.get_instructions("RUN") {
+            let args = &instruction.arguments;
+
+            if let Some(captures) = DOWNLOAD_TO_TMP.captures(args) {
+                if !TMP_CLEANUP.is_match(args) {
+                    issues.push(Issue {
+                        rule_id: self.id().to_string(),
+                        rule_name: self.name().to_string(),
+                        severity: self.severity(),
+                        line_number: Some(instruction.line_at(captures.get(0).map_or(0, |m| m.start()))),
+                        message: format!("Download to '{}' is not cleaned up in the same layer", &captures[1]),
+                        fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                        impact: self.impact(),
+                        params: message_params([("path", &&captures[1])]),
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_download_without_cleanup() {
+        let content = "FROM alpine:3.18\nRUN curl -fsSL -o /tmp/tool.tar.gz https://example.com/tool.tar.gz && tar -xzf /tmp/tool.tar.gz -C /usr/local";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = TempFilesRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("/tmp/tool.tar.gz"));
+    }
+
+    #[test]
+    fn test_allows_download_with_cleanup() {
+        let content = "FROM alpine:3.18\nRUN wget -O /tmp/tool.tar.gz https://example.com/tool.tar.gz && tar -xzf /tmp/tool.tar.gz -C /usr/local && rm -f /tmp/tool.tar.gz";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = TempFilesRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_ignores_download_outside_tmp() {
+        let content = "FROM alpine:3.18\nRUN curl -o /usr/local/bin/tool https://example.com/tool";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = TempFilesRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+}
@@ -0,0 +1,166 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams, message_params};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+/// A single curl/wget command fetching a URL, up to the next shell separator or pipe
+static DOWNLOAD_COMMAND: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(curl|wget)\b[^;&|]*?\b(?:https?|ftp)://[^;&|]*").unwrap()
+});
+
+static CURL_TO_FILE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\s(?:-[a-zA-Z]*[oO]\b|--output\b|--remote-name\b)").unwrap()
+});
+
+static WGET_TO_STDOUT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\s(?:-[a-zA-Z]*O\s*-|--output-document[\s=]+-)(?:\s|$)").unwrap()
+});
+
+static VERIFICATION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:sha(?:1|224|256|384|512)sum|shasum|md5sum)\b|\bgpg\b[^;&|]*--verify|\bcosign\s+verify").unwrap()
+});
+
+static REMOTE_URL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"https?://\S+").unwrap()
+});
+
+pub struct UnverifiedDownloadRule;
+
+impl Rule for UnverifiedDownloadRule {
+    fn id(&self) -> &'static str { "DF029" }
+    fn name(&self) -> &'static str { "Download without checksum verification" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "Downloaded file is used without a checksum or signature check"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A binary or archive fetched at build time is only as trustworthy as the server \
+         and network it came from. Without verifying a known checksum or signature in the \
+         same layer, a compromised mirror or a silently replaced release ends up in your \
+         image. ADD <url> has the same problem unless --checksum is given."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Verify the download in the same RUN: echo \"<sha256>  file.tar.gz\" | sha256sum -c -")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("High - protects against tampered downloads".to_string()),
+            reliability_improvement: Some("Builds fail loudly when upstream artifacts change".to_string()),
+        })
+    }
+}
+
+impl UnverifiedDownloadRule {
+    fn issue(&self, line_number: usize, message: String, params: MessageParams) -> Issue {
+        Issue {
+            rule_id: self.id().to_string(),
+            rule_name: self.name().to_string(),
+            severity: self.severity(),
+            line_number: Some(line_number),
+            message,
+            fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+            impact: self.impact(),
+            params,
+        }
+    }
+}
+
+impl DockerfileRule for UnverifiedDownloadRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for instruction in parser.get_instructions("RUN") {
+            let args = &instruction.arguments;
+            if VERIFICATION.is_match(args) {
+                continue;
+            }
+
+            // Only downloads saved to disk; piping to a shell is covered by DF010
+            let saved_download = DOWNLOAD_COMMAND.captures_iter(args).find(|captures| {
+                let command = &captures[0];
+                match &captures[1] {
+                    "curl" => CURL_TO_FILE.is_match(command),
+                    _ => !WGET_TO_STDOUT.is_match(command),
+                }
+            });
+
+            if let Some(captures) = saved_download {
+                let start = captures.get(0).map_or(0, |m| m.start());
+                issues.push(self.issue(
+                    instruction.line_at(start),
+                    format!("File downloaded with {} is not verified with a checksum or signature", &captures[1]),
+                    message_params([("tool", &&captures[1])]),
+                ));
+            }
+        }
+
+        for instruction in parser.get_instructions("ADD") {
+            if instruction.arguments.contains("--checksum") {
+                continue;
+            }
+            if let Some(url) = REMOTE_URL.find(&instruction.arguments) {
+                issues.push(self.issue(
+                    instruction.line_at(url.start()),
+                    format!("ADD downloads '{}' without --checksum", url.as_str()),
+                    message_params([("url", &url.as_str())]),
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_unverified_download() {
+        let content = "FROM debian:12-slim\nRUN curl -fsSLo /usr/local/bin/tool https://example.com/tool && chmod +x /usr/local/bin/tool";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = UnverifiedDownloadRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("curl"));
+    }
+
+    #[test]
+    fn test_allows_verified_download() {
+        let content = "FROM debian:12-slim\nRUN wget https://example.com/tool.tar.gz \\\n    && echo \"abc123  tool.tar.gz\" | sha256sum -c - \\\n    && tar -xzf tool.tar.gz";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = UnverifiedDownloadRule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_ignores_download_to_stdout() {
+        let content = "FROM alpine:3.18\nRUN wget -qO- https://example.com/data.json | jq .version";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = UnverifiedDownloadRule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_ignores_installing_download_tools() {
+        let content = "FROM debian:12-slim\nRUN apt-get update && apt-get install -y wget curl\nRUN apk add wget; wget --version";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = UnverifiedDownloadRule.check(&parser, None);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_detects_add_url_without_checksum() {
+        let content = "FROM alpine:3.18\nADD https://example.com/tool.tar.gz /tmp/\nADD --checksum=sha256:abc123 https://example.com/other.tar.gz /tmp/";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = UnverifiedDownloadRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+    }
+}
@@ -1,4 +1,4 @@
-use super::{DockerfileRule, Issue, Severity, ImpactEstimate};
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, MessageParams};
 use crate::parser::DockerfileParser;
 use crate::rules::Rule;
 use regex::Regex;
@@ -8,10 +8,6 @@ static UNPINNED_APT: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"apt-get\s+install\s+(?:-[^\s]+\s+)*([a-zA-Z][a-zA-Z0-9._+-]*)(?:\s|$)").unwrap()
 });
 
-static UNPINNED_APK: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"apk\s+add\s+(?:--[^\s]+\s+)*([a-zA-Z][a-zA-Z0-9._+-]*)(?:\s|$)").unwrap()
-});
-
 static UNPINNED_PIP: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"pip\s+install\s+(?:-[^\s]+\s+)*([a-zA-Z][a-zA-Z0-9._-]*)(?:\s|$)").unwrap()
 });
@@ -52,34 +48,36 @@ impl DockerfileRule for VersionPinningRule {
         let mut issues = Vec::new();
         
         for instruction in parser.get_instructions("RUN") {
-            let args = instruction.arguments.to_lowercase();
+            let args = instruction.arguments.to_ascii_lowercase();
             
             // Check for unpinned apt-get packages
             if args.contains("apt-get install") && !args.contains("=") {
-                if UNPINNED_APT.is_match(&args) {
+                if let Some(unpinned) = UNPINNED_APT.captures(&args).and_then(|c| c.get(1)) {
                     issues.push(Issue {
                         rule_id: self.id().to_string(),
                         rule_name: self.name().to_string(),
                         severity: self.severity(),
-                        line_number: Some(instruction.line_number),
+                        line_number: Some(instruction.line_at(unpinned.start())),
                         message: "apt-get install without version pinning".to_string(),
                         fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                         impact: self.impact(),
+                        params: MessageParams::new(),
                     });
                 }
             }
             
             // Check for unpinned pip packages (not using -r requirements.txt)
             if args.contains("pip install") && !args.contains("-r ") && !args.contains("==") {
-                if UNPINNED_PIP.is_match(&args) {
+                if let Some(unpinned) = UNPINNED_PIP.captures(&args).and_then(|c| c.get(1)) {
                     issues.push(Issue {
                         rule_id: self.id().to_string(),
                         rule_name: self.name().to_string(),
                         severity: self.severity(),
-                        line_number: Some(instruction.line_number),
+                        line_number: Some(instruction.line_at(unpinned.start())),
                         message: "pip install without version pinning".to_string(),
                         fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
                         impact: self.impact(),
+                        params: MessageParams::new(),
                     });
                 }
             }
@@ -102,6 +100,15 @@ mod tests {
         assert_eq!(issues.len(), 1);
     }
 
+    #[test]
+    fn test_detects_unpinned_apt_in_heredoc() {
+        let content = "FROM ubuntu:22.04\nRUN <<EOF\nset -e\napt-get update\napt-get install -y curl\nEOF\nUSER app";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = VersionPinningRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(5));
+    }
+
     #[test]
     fn test_detects_unpinned_pip() {
         let content = "FROM python\nRUN pip install requests flask";
@@ -120,6 +127,16 @@ mod tests {
         assert_eq!(issues.len(), 0);
     }
 
+    #[test]
+    fn test_reports_line_of_unpinned_package() {
+        let content = "FROM ubuntu:22.04\nRUN apt-get update && \\\n    apt-get install -y \\\n        curl";
+        let parser = DockerfileParser::parse_content(content);
+        let rule = VersionPinningRule;
+        let issues = rule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(4));
+    }
+
     #[test]
     fn test_allows_requirements_file() {
         let content = "FROM python\nRUN pip install -r requirements.txt";
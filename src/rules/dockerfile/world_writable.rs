@@ -0,0 +1,143 @@
+use super::{DockerfileRule, Issue, Severity, ImpactEstimate, message_params};
+use crate::parser::DockerfileParser;
+use crate::rules::Rule;
+use regex::Regex;
+use once_cell::sync::Lazy;
+
+/// `chmod [flags] MODE`, capturing the mode
+static CHMOD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bchmod\s+(?:-\S+\s+)*(\S+)").unwrap()
+});
+
+pub struct WorldWritableRule;
+
+/// Whether a chmod mode grants write permission to "other" users. Sticky-bit modes
+/// (`1777`, `+t`) are left alone: that is the standard setup for shared temp directories,
+/// where users cannot delete each other's files.
+fn is_world_writable(mode: &str) -> bool {
+    if is_sticky(mode) {
+        return false;
+    }
+    if !mode.is_empty() && mode.chars().all(|c| c.is_digit(8)) {
+        // The last octal digit is the "other" permissions; 2 is the write bit
+        return mode.chars().last().and_then(|c| c.to_digit(8)).is_some_and(|other| other & 2 != 0);
+    }
+
+    // Symbolic clauses such as o+w, a+rwx or ugo=rw
+    mode.split(',').any(|clause| {
+        let Some(op) = clause.find(['+', '=']) else {
+            return false;
+        };
+        let (who, perms) = clause.split_at(op);
+        (who.contains('o') || who.contains('a')) && perms.contains('w')
+    })
+}
+
+/// Whether a chmod mode sets the sticky bit
+fn is_sticky(mode: &str) -> bool {
+    if !mode.is_empty() && mode.chars().all(|c| c.is_digit(8)) {
+        // The digit before the user/group/other digits holds setuid, setgid and sticky (1)
+        let digits: Vec<u32> = mode.chars().filter_map(|c| c.to_digit(8)).collect();
+        return digits.len() >= 4 && digits[digits.len() - 4] & 1 != 0;
+    }
+    mode.split(',').any(|clause| {
+        clause.find(['+', '=']).is_some_and(|op| clause[op..].contains('t'))
+    })
+}
+
+impl Rule for WorldWritableRule {
+    fn id(&self) -> &'static str { "DF017" }
+    fn name(&self) -> &'static str { "World-writable permissions" }
+    fn severity(&self) -> Severity { Severity::Warning }
+
+    fn description(&self) -> &'static str {
+        "chmod makes files writable by every user"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Modes like 777 or o+w let any user in the container modify the files, including \
+         application code and scripts that later run with more privilege. It is usually \
+         added to work around an ownership problem that is better solved by giving the \
+         runtime user ownership of just the paths it writes to."
+    }
+
+    fn fix_suggestion(&self) -> Option<&'static str> {
+        Some("Use the least permissive mode that works (e.g. 755 for directories and executables, 644 for files) and 'chown' writable paths to the runtime user")
+    }
+
+    fn impact(&self) -> Option<ImpactEstimate> {
+        Some(ImpactEstimate {
+            build_time_improvement: None,
+            image_size_reduction: None,
+            security_improvement: Some("Medium - only the owning user can modify the files".to_string()),
+            reliability_improvement: None,
+        })
+    }
+}
+
+impl DockerfileRule for WorldWritableRule {
+    fn check(&self, parser: &DockerfileParser, _context_dir: Option<&std::path::Path>) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for instruction in parser.get_instructions("RUN") {
+            for captures in CHMOD.captures_iter(&instruction.arguments) {
+                let mode = &captures[1];
+                if !is_world_writable(mode) {
+                    continue;
+                }
+                issues.push(Issue {
+                    rule_id: self.id().to_string(),
+                    rule_name: self.name().to_string(),
+                    severity: self.severity(),
+                    line_number: Some(instruction.line_at(captures.get(0).map_or(0, |m| m.start()))),
+                    message: format!("'chmod {}' makes files writable by any user", mode),
+                    fix_suggestion: self.fix_suggestion().map(|s| s.to_string()),
+                    impact: self.impact(),
+                    params: message_params([("mode", &mode)]),
+                });
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_chmod_777() {
+        let content = "FROM alpine:3.18\nRUN chmod -R 777 /app";
+        let parser = DockerfileParser::parse_content(content);
+        let issues = WorldWritableRule.check(&parser, None);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line_number, Some(2));
+        assert!(issues[0].message.contains("777"));
+    }
+
+    #[test]
+    fn test_detects_other_world_writable_modes() {
+        let content = "FROM alpine:3.18\nRUN chmod 666 /data/db && chmod o+w /tmp/cache && chmod a=rwx /srv";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(WorldWritableRule.check(&parser, None).len(), 3);
+    }
+
+    #[test]
+    fn test_allows_sticky_temp_directories() {
+        let content = "FROM alpine:3.18\nRUN mkdir -p /tmp/cache && chmod 1777 /tmp/cache && chmod 01777 /var/tmp && chmod a+rwxt /scratch";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(WorldWritableRule.check(&parser, None).len(), 0);
+
+        // The setgid bit alone does not protect a shared directory
+        let parser = DockerfileParser::parse_content("FROM alpine:3.18\nRUN chmod 2777 /shared");
+        assert_eq!(WorldWritableRule.check(&parser, None).len(), 1);
+    }
+
+    #[test]
+    fn test_allows_restrictive_modes() {
+        let content = "FROM alpine:3.18\nRUN chmod 755 /app/bin && chmod +x /entrypoint.sh && chmod u+w,g-w /app/config && chmod 0644 /app/app.conf";
+        let parser = DockerfileParser::parse_content(content);
+        assert_eq!(WorldWritableRule.check(&parser, None).len(), 0);
+    }
+}
@@ -6,5 +6,5 @@ pub mod dockerfile;
 pub mod compose;
 
 pub use severity::Severity;
-pub use rule::{Rule, Issue, ImpactEstimate};
+pub use rule::{Rule, Issue, ImpactEstimate, MessageParams, message_params, render_message};
 pub use registry::{get_all_rules, get_rule_by_id, print_all_rules, get_dockerfile_rules, get_compose_rules};
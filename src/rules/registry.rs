@@ -18,6 +18,36 @@ static DOCKERFILE_RULES: Lazy<Vec<Box<dyn Rule>>> = Lazy::new(|| {
         Box::new(LargeBaseImageRule),
         Box::new(CurlBashRule),
         Box::new(LayerOptimizationRule),
+        Box::new(TempFilesRule),
+        Box::new(OciLabelsRule),
+        Box::new(MixedPackageManagersRule),
+        Box::new(DigestWithoutTagRule),
+        Box::new(AppVolumeRule),
+        Box::new(UnverifiedDownloadRule),
+        Box::new(ShellEntrypointCmdRule),
+        Box::new(ExposedPortMismatchRule),
+        Box::new(RuntimeInstallRule),
+        Box::new(BuildTimestampRule),
+        Box::new(HealthcheckLocalhostRule),
+        Box::new(GitDirectoryRule),
+        Box::new(CopyBeforeWorkdirRule),
+        Box::new(CacheBustRule),
+        Box::new(AddVsCopyRule),
+        Box::new(NonRootPrivilegedPortRule),
+        Box::new(AptUpgradeRule),
+        Box::new(WorkdirRule),
+        Box::new(DuplicateCmdRule),
+        Box::new(ShellFormRule),
+        Box::new(WorldWritableRule),
+        Box::new(SudoRule),
+        Box::new(PackageCacheRule),
+        Box::new(DependencyDirectoryRule),
+        Box::new(ExposePortRule),
+        Box::new(RecursiveCopyRule),
+        Box::new(BuildSecretArgRule),
+        Box::new(PrivilegedPortRule),
+        Box::new(GitInImageRule),
+        Box::new(LongRunChainRule::default()),
     ]
 });
 
@@ -29,6 +59,26 @@ static COMPOSE_RULES: Lazy<Vec<Box<dyn Rule>>> = Lazy::new(|| {
         Box::new(ResourceLimitsRule),
         Box::new(ComposeLatestTagRule),
         Box::new(HardcodedSecretsRule),
+        Box::new(HostNetworkPortsRule),
+        Box::new(VolumesFromRule),
+        Box::new(AdminPortsRule),
+        Box::new(UnusedResourcesRule),
+        Box::new(UnpublishedEntrypointRule),
+        Box::new(ImplicitNetworkRule),
+        Box::new(ComposeHealthcheckLocalhostRule),
+        Box::new(DevEnvironmentRule),
+        Box::new(HostNamespacesRule),
+        Box::new(BuildContextEscapeRule),
+        Box::new(CapabilityConflictRule),
+        Box::new(PortSyntaxRule),
+        Box::new(DuplicateEnvironmentRule),
+        Box::new(DockerSockRule),
+        Box::new(HealthcheckDisabledRule),
+        Box::new(HostNetworkRule),
+        Box::new(DangerousCapabilitiesRule),
+        Box::new(UnbuiltImageRule),
+        Box::new(ComposeHealthcheckRule),
+        Box::new(PortBindingRule::default()),
     ]
 });
 
@@ -1,8 +1,47 @@
 use super::Severity;
+use crate::scoring::Category;
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+static QUOTED_VALUE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"'[^']*'").unwrap()
+});
+
+/// Unquoted numbers (ports, counts, versions) and paths
+static BARE_VALUE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\b\d+(?:\.\d+)*\b|(^|\s)\.{0,2}/[^\s,;'"]*"#).unwrap()
+});
+
+static PLACEHOLDER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\{([a-z_]+)\}").unwrap()
+});
+
+/// Where the rule reference lives; `Rule::doc_url` links into its sections
+const DOCS_URL: &str = env!("CARGO_PKG_REPOSITORY");
+
+/// Dynamic values behind an issue message (e.g. `image`, `service`), keyed by placeholder name
+pub type MessageParams = BTreeMap<String, String>;
+
+/// Build `MessageParams` from placeholder/value pairs
+pub fn message_params<const N: usize>(pairs: [(&str, &dyn std::fmt::Display); N]) -> MessageParams {
+    pairs.into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Substitute `{name}` placeholders in a message template. Unknown placeholders
+/// are left untouched so a typo in a template stays visible.
+pub fn render_message(template: &str, params: &MessageParams) -> String {
+    PLACEHOLDER_RE.replace_all(template, |caps: &regex::Captures| {
+        params.get(&caps[1]).cloned().unwrap_or_else(|| caps[0].to_string())
+    }).into_owned()
+}
 
 /// Represents a detected issue in a Docker configuration file
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Issue {
     pub rule_id: String,
     pub rule_name: String,
@@ -11,10 +50,89 @@ pub struct Issue {
     pub message: String,
     pub fix_suggestion: Option<String>,
     pub impact: Option<ImpactEstimate>,
+    /// Values substituted into custom message templates
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: MessageParams,
+}
+
+impl Issue {
+    pub fn new(rule_id: impl Into<String>, rule_name: impl Into<String>, severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            rule_id: rule_id.into(),
+            rule_name: rule_name.into(),
+            severity,
+            line_number: None,
+            message: message.into(),
+            fix_suggestion: None,
+            impact: None,
+            params: MessageParams::new(),
+        }
+    }
+
+    pub fn with_line_number(mut self, line_number: usize) -> Self {
+        self.line_number = Some(line_number);
+        self
+    }
+
+    pub fn with_fix_suggestion(mut self, fix_suggestion: impl Into<String>) -> Self {
+        self.fix_suggestion = Some(fix_suggestion.into());
+        self
+    }
+
+    pub fn with_impact(mut self, impact: ImpactEstimate) -> Self {
+        self.impact = Some(impact);
+        self
+    }
+
+    pub fn with_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.insert(key.into(), value.into());
+        self
+    }
+
+    /// Replace the message with `template`, filling placeholders from the issue's
+    /// params. `{message}` expands to the built-in message, which is kept in the
+    /// params so fingerprints do not change with the template.
+    pub fn apply_template(&mut self, template: &str) {
+        let builtin = self.builtin_message().to_string();
+        self.params.insert("message".to_string(), builtin);
+        self.message = render_message(template, &self.params);
+    }
+
+    /// The message the rule produced, before any template was applied
+    pub fn builtin_message(&self) -> &str {
+        self.params.get("message").unwrap_or(&self.message)
+    }
+
+    /// The built-in message with dynamic values (param values, quoted names, numbers,
+    /// paths) replaced by `{}`, so it stays stable when those values or templates change.
+    pub fn normalized_message(&self) -> String {
+        let mut message = self.builtin_message().to_string();
+        let mut values: Vec<&String> = self.params.iter()
+            .filter(|(key, value)| *key != "message" && !value.is_empty())
+            .map(|(_, value)| value)
+            .collect();
+        // Longer values first, so an image name is replaced before a tag inside it
+        values.sort_by_key(|value| std::cmp::Reverse(value.len()));
+        for value in values {
+            let standalone = Regex::new(&format!(r"(^|[^\w.\-/]){}($|[^\w.\-/])", regex::escape(value))).unwrap();
+            message = standalone.replace_all(&message, "${1}{}${2}").into_owned();
+        }
+
+        let quoted = QUOTED_VALUE_RE.replace_all(&message, "'{}'");
+        BARE_VALUE_RE.replace_all(&quoted, |caps: &regex::Captures| {
+            format!("{}{{}}", caps.get(1).map_or("", |space| space.as_str()))
+        }).into_owned()
+    }
+
+    /// Identifier used to match issues across runs for baselines and diffs
+    pub fn fingerprint(&self) -> String {
+        format!("{}:{}", self.rule_id, self.normalized_message())
+    }
 }
 
 /// Impact estimation for an issue
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct ImpactEstimate {
     pub build_time_improvement: Option<String>,
     pub image_size_reduction: Option<String>,
@@ -48,7 +166,18 @@ pub trait Rule: Send + Sync {
     fn impact(&self) -> Option<ImpactEstimate> {
         None
     }
+
+    /// Scoring category this rule deducts from
+    fn category(&self) -> Option<Category> {
+        Category::for_rule(self.id())
+    }
     
+    /// Documentation page for the rule
+    fn doc_url(&self) -> String {
+        let section = if self.id().starts_with("DC") { "docker-compose-rules" } else { "dockerfile-rules" };
+        format!("{}#{}", DOCS_URL, section)
+    }
+
     /// Generate a detailed explanation of the rule
     fn explain(&self) -> String {
         let mut explanation = String::new();
@@ -76,7 +205,80 @@ pub trait Rule: Send + Sync {
                 explanation.push_str(&format!("  Reliability: {}\n", reliability));
             }
         }
-        
+
+        explanation.push_str(&format!("\nDocs: {}\n", self.doc_url()));
         explanation
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(message: &str) -> Issue {
+        Issue {
+            rule_id: "DC001".to_string(),
+            rule_name: "No restart policy".to_string(),
+            severity: Severity::Warning,
+            line_number: None,
+            message: message.to_string(),
+            fix_suggestion: None,
+            impact: None,
+            params: MessageParams::new(),
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_service_name() {
+        let web = issue("Service 'web' has no restart policy");
+        let db = issue("Service 'db' has no restart policy");
+        assert_eq!(web.normalized_message(), "Service '{}' has no restart policy");
+        assert_eq!(web.fingerprint(), db.fingerprint());
+        assert_ne!(web.message, db.message);
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_unquoted_values() {
+        let low = issue("EXPOSE 80 is a privileged port");
+        let other = issue("EXPOSE 443 is a privileged port");
+        assert_eq!(low.normalized_message(), "EXPOSE {} is a privileged port");
+        assert_eq!(low.fingerprint(), other.fingerprint());
+
+        let volume = issue("VOLUME /var/lib/mysql is declared in an application image");
+        assert_eq!(volume.normalized_message(), "VOLUME {} is declared in an application image");
+        assert_eq!(issue("Service 'web' runs 3 replicas").normalized_message(), "Service '{}' runs {} replicas");
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_unquoted_param_values() {
+        let mut nginx = issue("Service 'web' uses image with ':latest' tag: nginx:latest");
+        nginx.params = message_params([("service", &"web"), ("image", &"nginx:latest")]);
+        let mut redis = issue("Service 'cache' uses image with ':latest' tag: redis:latest");
+        redis.params = message_params([("service", &"cache"), ("image", &"redis:latest")]);
+        assert_eq!(nginx.normalized_message(), "Service '{}' uses image with '{}' tag: {}");
+        assert_eq!(nginx.fingerprint(), redis.fingerprint());
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_template() {
+        let mut templated = issue("Service 'web' has no restart policy");
+        templated.params = message_params([("service", &"web")]);
+        let plain = templated.clone();
+        templated.apply_template("Dienst {service} hat keine Neustartrichtlinie");
+        assert_eq!(templated.message, "Dienst web hat keine Neustartrichtlinie");
+        assert_eq!(templated.fingerprint(), plain.fingerprint());
+    }
+
+    #[test]
+    fn test_fingerprint_differs_by_rule() {
+        let mut other = issue("Service 'web' has no restart policy");
+        other.rule_id = "DC003".to_string();
+        assert_ne!(issue("Service 'web' has no restart policy").fingerprint(), other.fingerprint());
+    }
+
+    #[test]
+    fn test_render_message_keeps_unknown_placeholders() {
+        let params = message_params([("service", &"web")]);
+        assert_eq!(render_message("{service} / {image}", &params), "web / {image}");
+    }
+}
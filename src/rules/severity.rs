@@ -24,6 +24,11 @@ impl fmt::Display for Severity {
 }
 
 impl Severity {
+    /// All severity levels, from least to most severe
+    pub fn all() -> &'static [Severity] {
+        &[Severity::Suggestion, Severity::Warning, Severity::Critical]
+    }
+
     pub fn color(&self) -> colored::Color {
         match self {
             Severity::Critical => colored::Color::Red,
@@ -39,4 +44,13 @@ impl Severity {
             Severity::Suggestion => "ℹ",
         }
     }
+
+    /// Plain-ASCII counterpart of `symbol` for terminals and logs without Unicode
+    pub fn ascii_symbol(&self) -> &'static str {
+        match self {
+            Severity::Critical => "X",
+            Severity::Warning => "!",
+            Severity::Suggestion => "i",
+        }
+    }
 }
@@ -1,15 +1,69 @@
 use crate::rules::{Issue, Severity};
-use serde::{Deserialize, Serialize};
+use clap::ValueEnum;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Scoring categories that rules contribute to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Category {
+    Security,
+    Performance,
+    Maintainability,
+}
+
+impl Category {
+    /// All categories, in display order
+    pub fn all() -> &'static [Category] {
+        &[Category::Security, Category::Performance, Category::Maintainability]
+    }
+
+    /// The category a rule's issues deduct from, if any
+    pub fn for_rule(rule_id: &str) -> Option<Category> {
+        Category::all()
+            .iter()
+            .copied()
+            .find(|category| category.rule_ids().iter().any(|id| id.eq_ignore_ascii_case(rule_id)))
+    }
+
+    /// Rule IDs that deduct from this category's score
+    pub fn rule_ids(&self) -> &'static [&'static str] {
+        match self {
+            Category::Security => &["DF001", "DF002", "DF006", "DF010", "DC002", "DC004", "DC005", "DC013", "DF029", "DC018", "DF035", "DC019", "DC021", "DF017", "DF018", "DF020", "DC006", "DC007", "DC008", "DC009", "DF022", "DF021", "DF023"],
+            Category::Performance => &["DF003", "DF004", "DF007", "DF008", "DF009", "DF011", "DF024", "DF032", "DF037", "DF013", "DF019", "DF041", "DF042"],
+            Category::Maintainability => &["DF005", "DC001", "DC003", "DC011", "DF025", "DC012", "DF026", "DF027", "DF028", "DC014", "DF030", "DF031", "DC015", "DF033", "DC016", "DF034", "DC017", "DF036", "DF012", "DF038", "DF014", "DC020", "DF015", "DF016", "DC022", "DF040", "DC023", "DC024", "DC025", "DC010"],
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Category::Security => write!(f, "Security"),
+            Category::Performance => write!(f, "Performance"),
+            Category::Maintainability => write!(f, "Maintainability"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Scores {
     pub performance: Score,
     pub security: Score,
     pub maintainability: Score,
     pub overall: Score,
+    /// Rule IDs that lowered each category's score; filled in by the detailed JSON output only
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub contributions: BTreeMap<Category, Vec<String>>,
+    /// Weights and penalties these scores were calculated with
+    #[serde(skip)]
+    pub config: ScoringConfig,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub struct Score {
     pub current: u8,
     pub potential: u8,
@@ -19,20 +73,106 @@ impl Score {
     pub fn display(&self) -> String {
         format!("{}/10 → {}/10", self.current, self.potential)
     }
+
+    /// Letter grade of the current score: A (9-10), B (7-8), C (5-6), D (3-4), F (0-2)
+    pub fn grade(&self) -> char {
+        match self.current {
+            9.. => 'A',
+            7..=8 => 'B',
+            5..=6 => 'C',
+            3..=4 => 'D',
+            _ => 'F',
+        }
+    }
+}
+
+/// Serialized with its grade; the grade is ignored when reading a score back
+impl Serialize for Score {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Score", 3)?;
+        state.serialize_field("current", &self.current)?;
+        state.serialize_field("potential", &self.potential)?;
+        state.serialize_field("grade", &self.grade())?;
+        state.end()
+    }
+}
+
+impl Scores {
+    /// Letter grade of the overall score
+    pub fn grade(&self) -> char {
+        self.overall.grade()
+    }
+}
+
+/// Relative weight of each category in the overall score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoringWeights {
+    pub security: u8,
+    pub performance: u8,
+    pub maintainability: u8,
+}
+
+impl Default for ScoringWeights {
+    fn default() -> Self {
+        Self {
+            security: 4,
+            performance: 3,
+            maintainability: 3,
+        }
+    }
+}
+
+/// Category weights and the points each issue deducts from its category, by severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoringConfig {
+    pub weights: ScoringWeights,
+    pub critical_penalty: u8,
+    pub warning_penalty: u8,
+    pub suggestion_penalty: u8,
+}
+
+impl ScoringConfig {
+    pub fn penalty(&self, severity: Severity) -> u8 {
+        match severity {
+            Severity::Critical => self.critical_penalty,
+            Severity::Warning => self.warning_penalty,
+            Severity::Suggestion => self.suggestion_penalty,
+        }
+    }
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        Self {
+            weights: ScoringWeights::default(),
+            critical_penalty: 3,
+            warning_penalty: 2,
+            suggestion_penalty: 1,
+        }
+    }
 }
 
 pub fn calculate_scores(issues: &[Issue]) -> Scores {
-    let critical_count = issues.iter().filter(|i| i.severity == Severity::Critical).count() as u8;
-    let warning_count = issues.iter().filter(|i| i.severity == Severity::Warning).count() as u8;
-    let suggestion_count = issues.iter().filter(|i| i.severity == Severity::Suggestion).count() as u8;
+    calculate_scores_with_config(issues, &ScoringConfig::default())
+}
 
+pub fn calculate_scores_with_weights(issues: &[Issue], weights: &ScoringWeights) -> Scores {
+    calculate_scores_with_config(issues, &ScoringConfig { weights: *weights, ..ScoringConfig::default() })
+}
+
+pub fn calculate_scores_with_config(issues: &[Issue], config: &ScoringConfig) -> Scores {
     // Calculate current scores (start at 10, deduct based on issues)
-    let security_current = calculate_category_score(issues, &["DF001", "DF002", "DF006", "DF010", "DC002", "DC004", "DC005"]);
-    let performance_current = calculate_category_score(issues, &["DF003", "DF004", "DF007", "DF008", "DF009"]);
-    let maintainability_current = calculate_category_score(issues, &["DF005", "DC001", "DC003"]);
+    let security_current = calculate_category_score(issues, Category::Security.rule_ids(), config);
+    let performance_current = calculate_category_score(issues, Category::Performance.rule_ids(), config);
+    let maintainability_current = calculate_category_score(issues, Category::Maintainability.rule_ids(), config);
+    let weights = &config.weights;
 
     // Overall is weighted average
-    let overall_current = (security_current.saturating_mul(4) + performance_current.saturating_mul(3) + maintainability_current.saturating_mul(3)) / 10;
+    let total_weight = weights.security as u32 + weights.performance as u32 + weights.maintainability as u32;
+    let weighted_sum = security_current as u32 * weights.security as u32
+        + performance_current as u32 * weights.performance as u32
+        + maintainability_current as u32 * weights.maintainability as u32;
+    let overall_current = weighted_sum.checked_div(total_weight).unwrap_or(10) as u8;
 
     Scores {
         performance: Score {
@@ -51,29 +191,56 @@ pub fn calculate_scores(issues: &[Issue]) -> Scores {
             current: overall_current,
             potential: 10,
         },
+        contributions: BTreeMap::new(),
+        config: *config,
     }
 }
 
-fn calculate_category_score(issues: &[Issue], rule_ids: &[&str]) -> u8 {
-    let mut score: i8 = 10;
+/// Distinct rule IDs deducting from each category, sorted; every category is present
+pub fn category_contributions(issues: &[Issue]) -> BTreeMap<Category, Vec<String>> {
+    Category::all()
+        .iter()
+        .map(|category| {
+            let mut rule_ids: Vec<String> = issues.iter()
+                .filter(|i| category.rule_ids().contains(&i.rule_id.as_str()))
+                .map(|i| i.rule_id.clone())
+                .collect();
+            rule_ids.sort();
+            rule_ids.dedup();
+            (*category, rule_ids)
+        })
+        .collect()
+}
+
+/// Overall score improvement gained by fixing every issue raised by `rule_id`
+pub fn overall_gain_if_fixed(issues: &[Issue], rule_id: &str, config: &ScoringConfig) -> u8 {
+    let remaining: Vec<Issue> = issues.iter()
+        .filter(|i| i.rule_id != rule_id)
+        .cloned()
+        .collect();
+    let before = calculate_scores_with_config(issues, config).overall.current;
+    let after = calculate_scores_with_config(&remaining, config).overall.current;
+    after.saturating_sub(before)
+}
+
+/// 10 minus one penalty per rule that fired, at the severity of its most severe issue,
+/// so a rule flagging several lines costs no more than a single hit
+fn calculate_category_score(issues: &[Issue], rule_ids: &[&str], config: &ScoringConfig) -> u8 {
+    let mut penalties: BTreeMap<&str, u8> = BTreeMap::new();
 
     for issue in issues {
         if rule_ids.contains(&issue.rule_id.as_str()) {
-            match issue.severity {
-                Severity::Critical => score -= 3,
-                Severity::Warning => score -= 2,
-                Severity::Suggestion => score -= 1,
-            }
+            let penalty = penalties.entry(issue.rule_id.as_str()).or_default();
+            *penalty = (*penalty).max(config.penalty(issue.severity));
         }
     }
 
-    score.max(0) as u8
+    penalties.values().fold(10, |score, penalty| score.saturating_sub(*penalty))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::rules::ImpactEstimate;
 
     #[test]
     fn test_perfect_score_no_issues() {
@@ -95,9 +262,106 @@ mod tests {
                 message: "Test".to_string(),
                 fix_suggestion: None,
                 impact: None,
+                params: Default::default(),
             },
         ];
         let scores = calculate_scores(&issues);
         assert_eq!(scores.security.current, 7); // 10 - 3 for critical
     }
+
+    fn critical(rule_id: &str) -> Issue {
+        Issue {
+            rule_id: rule_id.to_string(),
+            rule_name: "Test".to_string(),
+            severity: Severity::Critical,
+            line_number: None,
+            message: "Test".to_string(),
+            fix_suggestion: None,
+            impact: None,
+            params: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_grades() {
+        let scores = calculate_scores(&[critical("DF001")]);
+        assert_eq!(scores.security.grade(), 'B');
+        assert_eq!(scores.grade(), 'B');
+        assert_eq!(calculate_scores(&[]).grade(), 'A');
+
+        let grades: Vec<char> = (0..=10).map(|current| Score { current, potential: 10 }.grade()).collect();
+        assert_eq!(grades, vec!['F', 'F', 'F', 'D', 'D', 'C', 'C', 'B', 'B', 'A', 'A']);
+
+        let json = serde_json::to_value(scores.security).unwrap();
+        assert_eq!(json, serde_json::json!({"current": 7, "potential": 10, "grade": "B"}));
+        assert_eq!(serde_json::from_value::<Score>(json).unwrap(), scores.security);
+    }
+
+    #[test]
+    fn test_repeated_rule_deducts_once() {
+        let warning = |line: usize| Issue {
+            severity: Severity::Warning,
+            line_number: Some(line),
+            ..critical("DF007")
+        };
+        let single = calculate_scores(&[warning(2)]);
+        let repeated = calculate_scores(&[warning(2), warning(3), warning(4)]);
+        assert_eq!(single.performance.current, 8);
+        assert_eq!(repeated.performance.current, single.performance.current);
+        assert_eq!(repeated.overall, single.overall);
+
+        // Distinct rules still add up, and a mixed rule costs its most severe penalty
+        let mixed = calculate_scores(&[warning(2), critical("DF007"), critical("DF003")]);
+        assert_eq!(mixed.performance.current, 4);
+    }
+
+    #[test]
+    fn test_custom_penalties() {
+        let config = ScoringConfig { critical_penalty: 6, ..ScoringConfig::default() };
+        let scores = calculate_scores_with_config(&[critical("DF001")], &config);
+        assert_eq!(scores.security.current, 4);
+        assert_eq!(scores.security.grade(), 'D');
+
+        // Penalties larger than the score bottom out at zero
+        let config = ScoringConfig { critical_penalty: 200, ..ScoringConfig::default() };
+        let scores = calculate_scores_with_config(&[critical("DF001"), critical("DF002")], &config);
+        assert_eq!(scores.security.current, 0);
+    }
+
+    #[test]
+    fn test_gain_if_fixed_uses_config() {
+        let issues = [critical("DF001"), critical("DF003")];
+        assert_eq!(overall_gain_if_fixed(&issues, "DF001", &ScoringConfig::default()), 2);
+
+        let config = ScoringConfig { critical_penalty: 6, ..ScoringConfig::default() };
+        let scores = calculate_scores_with_config(&issues, &config);
+        assert_eq!(overall_gain_if_fixed(&issues, "DF001", &scores.config), 3);
+    }
+
+    #[test]
+    fn test_category_for_rule() {
+        assert_eq!(Category::for_rule("DF002"), Some(Category::Security));
+        assert_eq!(Category::for_rule("df004"), Some(Category::Performance));
+        assert_eq!(Category::for_rule("XX999"), None);
+    }
+
+    #[test]
+    fn test_contributions_list_firing_rules() {
+        let issue = |rule_id: &str| Issue {
+            rule_id: rule_id.to_string(),
+            rule_name: "Test".to_string(),
+            severity: Severity::Warning,
+            line_number: None,
+            message: "Test".to_string(),
+            fix_suggestion: None,
+            impact: None,
+            params: Default::default(),
+        };
+        let issues = vec![issue("DF006"), issue("DF002"), issue("DF006"), issue("DF005")];
+        assert!(calculate_scores(&issues).contributions.is_empty());
+        let contributions = category_contributions(&issues);
+        assert_eq!(contributions[&Category::Security], vec!["DF002", "DF006"]);
+        assert_eq!(contributions[&Category::Maintainability], vec!["DF005"]);
+        assert!(contributions[&Category::Performance].is_empty());
+    }
 }
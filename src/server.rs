@@ -0,0 +1,213 @@
+//! Minimal local HTTP server for editor and web integrations (`serve` feature).
+//!
+//! Endpoints:
+//! - `GET /health` returns `{"status":"ok"}`
+//! - `POST /analyze/dockerfile` and `POST /analyze/compose` take the file
+//!   content as the request body and return the JSON report
+
+use crate::analyzer::{Analyzer, AnalyzerError, Report};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// Default bind address; only reachable from the local machine
+pub const DEFAULT_ADDR: &str = "127.0.0.1:8787";
+
+/// Largest request body accepted, matching the parser's default file limit
+const MAX_BODY_BYTES: usize = 4 * 1024 * 1024;
+
+/// Longest request line or header line accepted
+const MAX_LINE_BYTES: usize = 8 * 1024;
+
+/// Largest total size of the request line and headers
+const MAX_HEAD_BYTES: usize = 64 * 1024;
+
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct Server {
+    listener: TcpListener,
+    analyzer: Analyzer,
+}
+
+/// Status code and JSON body of a response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: String) -> Self {
+        Self { status, body }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::json(status, serde_json::json!({ "error": message }).to_string())
+    }
+
+    fn report(result: Result<Report, AnalyzerError>) -> Self {
+        match result {
+            Ok(report) => match serde_json::to_string(&report) {
+                Ok(body) => Self::json(200, body),
+                Err(e) => Self::error(500, &e.to_string()),
+            },
+            Err(e) => Self::error(400, &e.to_string()),
+        }
+    }
+}
+
+impl Server {
+    pub fn bind<A: ToSocketAddrs>(addr: A, analyzer: Analyzer) -> io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(addr)?,
+            analyzer,
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Serve requests one at a time until the listener fails
+    pub fn run(&self) -> io::Result<()> {
+        for stream in self.listener.incoming() {
+            let stream = stream?;
+            // A misbehaving client must not take the server down
+            let _ = self.handle_connection(stream);
+        }
+        Ok(())
+    }
+
+    fn handle_connection(&self, mut stream: TcpStream) -> io::Result<()> {
+        stream.set_read_timeout(Some(READ_TIMEOUT))?;
+        let response = match read_request(&mut BufReader::new(&stream)) {
+            Ok((method, path, body)) => handle_request(&self.analyzer, &method, &path, &body),
+            Err(response) => response,
+        };
+        write_response(&mut stream, &response)
+    }
+}
+
+/// Route a parsed request to the analyzer
+pub fn handle_request(analyzer: &Analyzer, method: &str, path: &str, body: &[u8]) -> Response {
+    let path = path.split('?').next().unwrap_or(path);
+
+    match (method, path) {
+        ("GET", "/health") => Response::json(200, r#"{"status":"ok"}"#.to_string()),
+        ("POST", "/analyze/dockerfile") | ("POST", "/analyze/compose") => {
+            let Ok(content) = std::str::from_utf8(body) else {
+                return Response::error(400, "request body must be UTF-8 text");
+            };
+            if path.ends_with("dockerfile") {
+                Response::report(analyzer.analyze_dockerfile_str(content))
+            } else {
+                Response::report(analyzer.analyze_compose_str(content))
+            }
+        }
+        (_, "/health") | (_, "/analyze/dockerfile") | (_, "/analyze/compose") => {
+            Response::error(405, "method not allowed")
+        }
+        _ => Response::error(404, "not found"),
+    }
+}
+
+/// Read the request line, headers and body. Errors are returned as the response to send.
+fn read_request<R: BufRead>(reader: &mut R) -> Result<(String, String, Vec<u8>), Response> {
+    let bad_request = |_| Response::error(400, "malformed request");
+    let mut head_bytes = 0;
+
+    let request_line = read_line(reader, &mut head_bytes)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(Response::error(400, "malformed request"));
+    };
+
+    let mut content_length = 0usize;
+    loop {
+        let header = read_line(reader, &mut head_bytes)?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().map_err(|_| Response::error(400, "invalid Content-Length"))?;
+            }
+        }
+    }
+
+    if content_length > MAX_BODY_BYTES {
+        return Err(Response::error(413, "request body too large"));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(bad_request)?;
+
+    Ok((method.to_string(), path.to_string(), body))
+}
+
+/// Read one line of the request head, keeping it and the head as a whole within
+/// their limits so a client cannot grow memory with an endless line
+fn read_line<R: BufRead>(reader: &mut R, head_bytes: &mut usize) -> Result<String, Response> {
+    let mut line = String::new();
+    reader
+        .by_ref()
+        .take(MAX_LINE_BYTES as u64 + 1)
+        .read_line(&mut line)
+        .map_err(|_| Response::error(400, "malformed request"))?;
+    *head_bytes += line.len();
+    if line.len() > MAX_LINE_BYTES || *head_bytes > MAX_HEAD_BYTES {
+        return Err(Response::error(431, "request header too large"));
+    }
+    Ok(line)
+}
+
+fn write_response(stream: &mut TcpStream, response: &Response) -> io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        _ => "Internal Server Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_routes() {
+        let analyzer = Analyzer::new();
+        assert_eq!(handle_request(&analyzer, "GET", "/health", b"").status, 200);
+        assert_eq!(handle_request(&analyzer, "GET", "/analyze/dockerfile", b"").status, 405);
+        assert_eq!(handle_request(&analyzer, "GET", "/nope", b"").status, 404);
+        assert_eq!(handle_request(&analyzer, "POST", "/analyze/compose", b"services: [").status, 400);
+    }
+
+    #[test]
+    fn test_oversized_request_head_is_rejected() {
+        let request = b"POST /analyze/dockerfile HTTP/1.1\r\nContent-Length: 4\r\n\r\nFROM";
+        let (method, path, body) = read_request(&mut &request[..]).unwrap();
+        assert_eq!((method.as_str(), path.as_str(), body.as_slice()), ("POST", "/analyze/dockerfile", &b"FROM"[..]));
+
+        // One endless header line
+        let long_line = format!("GET /health HTTP/1.1\r\nX-Padding: {}", "a".repeat(1 << 20));
+        assert_eq!(read_request(&mut long_line.as_bytes()).unwrap_err().status, 431);
+
+        // Many lines that are each within the limit
+        let many_lines = format!("GET /health HTTP/1.1\r\n{}\r\n", "X-Padding: aaaa\r\n".repeat(10_000));
+        assert_eq!(read_request(&mut many_lines.as_bytes()).unwrap_err().status, 431);
+    }
+}
@@ -0,0 +1,432 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_docker-review"))
+        .args(args)
+        .output()
+        .expect("failed to run docker-review")
+}
+
+fn write_dockerfile(dir: &Path, content: &str) -> String {
+    let path = dir.join("Dockerfile");
+    fs::write(&path, content).unwrap();
+    fs::write(dir.join(".dockerignore"), ".git\n").unwrap();
+    path.display().to_string()
+}
+
+/// Only triggers DF009 (Suggestion): node without an alpine/slim variant
+const SUGGESTION_ONLY: &str = "FROM node:18.17.0\nLABEL org.opencontainers.image.source=https://example.com/app\nUSER node\nHEALTHCHECK CMD curl -f http://127.0.0.1/\nCMD [\"node\", \"app.js\"]\n";
+
+#[test]
+fn test_fail_on_rule_fails_for_suggestion() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_dockerfile(dir.path(), SUGGESTION_ONLY);
+
+    let output = run(&["analyze", &path, "--ci", "--fail-on", "critical", "--fail-on-rule", "DF006,DF009"]);
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_fail_on_rule_passes_when_rule_not_fired() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_dockerfile(dir.path(), SUGGESTION_ONLY);
+
+    let output = run(&["analyze", &path, "--ci", "--fail-on", "critical", "--fail-on-rule", "DF006"]);
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn test_only_runs_listed_rules() {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/bad_dockerfile");
+    let output = run(&["analyze", fixture, "--json", "--only", "DF001"]);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let issues = report["issues"].as_array().unwrap();
+    assert!(!issues.is_empty());
+    assert!(issues.iter().all(|i| i["rule_id"] == "DF001"));
+
+    let output = run(&["analyze", fixture, "--json", "--disable", "DF001,DF002"]);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let issues = report["issues"].as_array().unwrap();
+    assert!(!issues.is_empty());
+    assert!(!issues.iter().any(|i| i["rule_id"] == "DF001" || i["rule_id"] == "DF002"));
+
+    let output = run(&["analyze", fixture, "--only", "DF001", "--disable", "DF002"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_unknown_rule_ids_are_rejected() {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/bad_dockerfile");
+    let output = run(&["analyze", fixture, "--only", "DF999", "--ci", "--fail-on", "suggestion"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("'DF999'"));
+
+    let output = run(&["analyze", fixture, "--disable", "df001,DF0002"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("'DF0002'"));
+}
+
+#[test]
+fn test_unknown_fail_on_rule_id_is_rejected() {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/bad_dockerfile");
+    let output = run(&["analyze", fixture, "--fail-on-rule", "DF006,DF01"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown rule id 'DF01'"));
+}
+
+#[test]
+fn test_deterministic_output_is_stable() {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/bad_compose.yml");
+
+    let first = run(&["analyze", fixture, "--deterministic"]);
+    let second = run(&["analyze", fixture, "--deterministic"]);
+    assert!(!first.stdout.is_empty());
+    assert_eq!(first.stdout, second.stdout);
+    assert!(!String::from_utf8_lossy(&first.stdout).contains('\x1b'));
+}
+
+#[test]
+fn test_quiet_if_clean_prints_nothing() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_dockerfile(dir.path(), SUGGESTION_ONLY);
+
+    let output = run(&["analyze", &path, "--severity", "warning", "--quiet-if-clean", "--ci", "--fail-on", "warning"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stdout.is_empty());
+
+    let output = run(&["analyze", &path, "--quiet-if-clean"]);
+    assert!(!output.stdout.is_empty());
+}
+
+#[test]
+fn test_output_dir_writes_report_per_file() {
+    let dir = tempfile::tempdir().unwrap();
+    for name in ["api", "web"] {
+        let service_dir = dir.path().join(name);
+        fs::create_dir(&service_dir).unwrap();
+        write_dockerfile(&service_dir, SUGGESTION_ONLY);
+    }
+    let reports = dir.path().join("reports");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_docker-review"))
+        .current_dir(dir.path())
+        .args(["analyze", "api", "web", "--format", "json", "--output-dir", "reports"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stdout.is_empty());
+
+    let mut names: Vec<String> = fs::read_dir(&reports)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["api_Dockerfile.json", "web_Dockerfile.json"]);
+
+    let report = fs::read_to_string(reports.join("api_Dockerfile.json")).unwrap();
+    assert!(report.contains("\"DF009\""));
+}
+
+#[test]
+fn test_stats_summary_on_stderr() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_dockerfile(dir.path(), SUGGESTION_ONLY);
+    let compose = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/bad_compose.yml");
+
+    let output = run(&["analyze", &path, compose, "--stats", "--summary-only"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stats: serde_json::Value = serde_json::from_str(stderr.lines().last().unwrap()).unwrap();
+
+    assert_eq!(stats["files_analyzed"], 2);
+    assert!(stats["issues"]["suggestion"].as_u64().unwrap() >= 1);
+    for key in ["critical", "warning", "suggestion"] {
+        assert!(stats["issues"].get(key).is_some());
+    }
+    assert!(stats["rules_evaluated"].as_u64().unwrap() > 0);
+    assert!(stats.get("elapsed_ms").is_some());
+}
+
+#[test]
+fn test_category_filter_hides_other_categories() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_dockerfile(dir.path(), "FROM node:18.17.0-slim\nWORKDIR /app\nCOPY . .\nRUN npm install\nCMD [\"node\", \"app.js\"]\n");
+
+    let rule_ids = |args: &[&str]| -> Vec<String> {
+        let output = run(args);
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        report["issues"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|i| i["rule_id"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let all = rule_ids(&["analyze", &path, "--json"]);
+    assert!(all.contains(&"DF002".to_string()));
+    assert!(all.contains(&"DF004".to_string()));
+
+    let performance = rule_ids(&["analyze", &path, "--json", "--category", "performance"]);
+    assert!(!performance.contains(&"DF002".to_string()));
+    assert!(performance.contains(&"DF004".to_string()));
+}
+
+#[test]
+fn test_html_written_to_output_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_dockerfile(dir.path(), SUGGESTION_ONLY);
+    let target = dir.path().join("report.html");
+
+    let output = run(&["analyze", &path, "--html", "-o", target.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stdout.is_empty());
+
+    let html = fs::read_to_string(&target).unwrap();
+    assert!(html.contains("<html"));
+    assert!(html.contains("DF009"));
+}
+
+#[test]
+fn test_output_rejects_several_reports() {
+    let dir = tempfile::tempdir().unwrap();
+    let monorepo = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/monorepo");
+    let target = dir.path().join("out.json");
+
+    let output = run(&["analyze", monorepo, "--recursive", "--json", "-o", target.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--output-dir"));
+    assert!(!target.exists());
+}
+
+#[test]
+fn test_score_new_only_with_baseline() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_dockerfile(dir.path(), "FROM ubuntu:latest\nENV API_KEY=secret123\n");
+
+    let output = run(&["analyze", &path, "--json"]);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let baseline = dir.path().join("baseline.json");
+    fs::write(&baseline, report["issues"].to_string()).unwrap();
+    let full_score = report["scores"]["overall"]["current"].as_u64().unwrap();
+
+    let baseline = baseline.to_str().unwrap();
+    let output = run(&["analyze", &path, "--json", "--baseline", baseline]);
+    let filtered: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(filtered["issues"].as_array().unwrap().len(), 0);
+    assert_eq!(filtered["scores"]["overall"]["current"].as_u64().unwrap(), full_score);
+
+    let output = run(&["analyze", &path, "--json", "--baseline", baseline, "--score-new-only"]);
+    let new_only: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(new_only["scores"]["overall"]["current"].as_u64().unwrap() > full_score);
+}
+
+#[test]
+fn test_pdf_requires_output_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_dockerfile(dir.path(), SUGGESTION_ONLY);
+
+    let output = run(&["analyze", &path, "--pdf"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--output"));
+
+    let target = dir.path().join("report.pdf");
+    let output = run(&["analyze", &path, "--pdf", "-o", target.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(0));
+    let pdf = fs::read(&target).unwrap();
+    assert!(pdf.starts_with(b"%PDF"));
+}
+
+#[test]
+fn test_extract_embedded_reports_inline_dockerfile() {
+    let workflow = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/embedded_workflow.yml");
+    let output = run(&["analyze", workflow, "--extract-embedded", "--json"]);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(report["file_path"].as_str().unwrap().ends_with("embedded_workflow.yml:jobs.image.steps[1].run"));
+    assert!(report["issues"].as_array().unwrap().iter().any(|i| i["rule_id"] == "DF001"));
+}
+
+#[test]
+fn test_compare_json() {
+    let fixtures = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+    let output = run(&[
+        "compare",
+        &format!("{}/bad_dockerfile", fixtures),
+        &format!("{}/good_dockerfile", fixtures),
+        "--json",
+    ]);
+    assert_eq!(output.status.code(), Some(0));
+    let comparison: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let overall = |side: &str| comparison[side]["scores"]["overall"]["current"].as_u64().unwrap();
+    assert!(overall("right") > overall("left"));
+    assert!(comparison["right"]["issue_count"].as_u64() < comparison["left"]["issue_count"].as_u64());
+}
+
+#[test]
+fn test_relative_to_rewrites_file_path() {
+    let root = env!("CARGO_MANIFEST_DIR");
+    let fixture = format!("{}/tests/fixtures/monorepo/services/api/Dockerfile", root);
+
+    let output = run(&["analyze", &fixture, "--format", "json", "--relative-to", &format!("{}/tests/fixtures/monorepo", root)]);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["file_path"], "services/api/Dockerfile");
+
+    // Without a value the analyzed file's directory is the base
+    let output = run(&["analyze", &fixture, "--format", "json", "--relative-to"]);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["file_path"], "Dockerfile");
+}
+
+#[test]
+fn test_config_file_in_analyzed_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_dockerfile(dir.path(), SUGGESTION_ONLY);
+
+    fs::write(dir.path().join(".docker-review.toml"), "fail_on = \"suggestion\"\n").unwrap();
+    let output = run(&["analyze", &path, "--ci"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    fs::write(dir.path().join(".docker-review.toml"), "fail_on = \"suggestion\"\ndisable = [\"DF009\"]\n").unwrap();
+    let output = run(&["analyze", &path, "--ci"]);
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn test_rules_ids_lists_each_rule_once() {
+    let output = run(&["rules", "--ids"]);
+    assert_eq!(output.status.code(), Some(0));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let listed: Vec<&str> = stdout.lines().collect();
+    let expected: Vec<&str> = docker_review::rules::get_all_rules().iter().map(|r| r.id()).collect();
+    assert_eq!(listed, expected);
+    let unique: std::collections::HashSet<&str> = listed.iter().copied().collect();
+    assert_eq!(unique.len(), listed.len());
+    assert!(stdout.ends_with('\n'));
+}
+
+#[test]
+fn test_completions_bash() {
+    let output = run(&["completions", "bash"]);
+    assert_eq!(output.status.code(), Some(0));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!stdout.trim().is_empty());
+    assert!(stdout.contains("analyze"));
+}
+
+#[test]
+fn test_write_baseline_then_fail_only_on_new_issues() {
+    let dir = tempfile::tempdir().unwrap();
+    let legacy = "FROM ubuntu:latest\nENV API_KEY=secret123\n";
+    let path = write_dockerfile(dir.path(), legacy);
+    let baseline = dir.path().join("baseline.json");
+    let baseline = baseline.to_str().unwrap();
+
+    let output = run(&["analyze", &path, "--baseline", baseline, "--write-baseline"]);
+    assert_eq!(output.status.code(), Some(0));
+    let written: serde_json::Value = serde_json::from_str(&fs::read_to_string(baseline).unwrap()).unwrap();
+    assert!(written.as_array().unwrap().iter().all(|e| e["fingerprint"].is_string()));
+
+    // Known issues are hidden even though their lines moved
+    fs::write(&path, format!("# syntax=docker/dockerfile:1\n{}", legacy)).unwrap();
+    let output = run(&["analyze", &path, "--ci", "--fail-on", "warning", "--json", "--baseline", baseline]);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["issues"].as_array().unwrap().len(), 0);
+    assert_eq!(output.status.code(), Some(0));
+
+    // A new critical issue still surfaces and fails CI
+    fs::write(&path, format!("{}RUN curl https://example.com/install.sh | bash\n", legacy)).unwrap();
+    let output = run(&["analyze", &path, "--ci", "--json", "--baseline", baseline]);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let issues = report["issues"].as_array().unwrap();
+    assert!(issues.iter().any(|i| i["rule_id"] == "DF010"));
+    assert!(!issues.iter().any(|i| i["rule_id"] == "DF001"));
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_profile_gated_service_skipped_unless_requested() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("docker-compose.yml");
+    fs::write(
+        &path,
+        "services:\n  web:\n    image: nginx:1.25\n  debug:\n    image: busybox:1.36\n    privileged: true\n    profiles: [debug]\n",
+    )
+    .unwrap();
+    let path = path.to_str().unwrap();
+    let privileged = |output: &Output| {
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        report["issues"].as_array().unwrap().iter().any(|i| i["rule_id"] == "DC002")
+    };
+
+    // Without --profile every service is analyzed
+    assert!(privileged(&run(&["analyze", path, "--json"])));
+    assert!(!privileged(&run(&["analyze", path, "--json", "--profile", "prod"])));
+    assert!(privileged(&run(&["analyze", path, "--json", "--profile", "debug"])));
+}
+
+#[test]
+fn test_fail_on_score_drop() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_dockerfile(dir.path(), SUGGESTION_ONLY);
+    let scores = dir.path().join("scores.json");
+    let scores = scores.to_str().unwrap();
+    assert_eq!(run(&["analyze", &path, "--fail-on-score-drop", scores, "--write-baseline"]).status.code(), Some(0));
+
+    // Same scores pass, even though the file has issues
+    let output = run(&["analyze", &path, "--fail-on-score-drop", scores]);
+    assert_eq!(output.status.code(), Some(0));
+
+    // A lower security score fails the build
+    fs::write(&path, format!("{}ENV API_KEY=secret123\n", SUGGESTION_ONLY)).unwrap();
+    let output = run(&["analyze", &path, "--fail-on-score-drop", scores]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Security score dropped from"), "{}", stderr);
+
+    // Another spelling of the same path is still checked
+    let dotted = dir.path().join(".").join("Dockerfile");
+    let output = run(&["analyze", dotted.to_str().unwrap(), "--fail-on-score-drop", scores]);
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_baselined_issues_become_sarif_suppressions() {
+    let dir = tempfile::tempdir().unwrap();
+    let legacy = "FROM ubuntu:latest\nENV API_KEY=secret123\n";
+    let path = write_dockerfile(dir.path(), legacy);
+    let baseline = dir.path().join("baseline.json");
+    let baseline = baseline.to_str().unwrap();
+    assert_eq!(run(&["analyze", &path, "--baseline", baseline, "--write-baseline"]).status.code(), Some(0));
+
+    fs::write(&path, format!("{}RUN curl https://example.com/install.sh | bash\n", legacy)).unwrap();
+    let output = run(&["analyze", &path, "--sarif", "--baseline", baseline]);
+    let log: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let results = log["runs"][0]["results"].as_array().unwrap();
+
+    let (suppressed, active): (Vec<_>, Vec<_>) = results.iter().partition(|r| r.get("suppressions").is_some());
+    assert!(!active.is_empty());
+    assert!(active.iter().all(|r| r["ruleId"] == "DF010"));
+    assert!(suppressed.iter().any(|r| r["ruleId"] == "DF001"));
+    assert!(suppressed.iter().all(|r| r["suppressions"][0]["kind"] == "external"));
+}
+
+#[test]
+fn test_annotate_writes_commented_copy() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_dockerfile(dir.path(), "FROM ubuntu:latest\nUSER app\n");
+    let annotated = dir.path().join("Dockerfile.annotated");
+
+    let output = run(&["analyze", &path, "--annotate", "-o", annotated.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(0));
+
+    let content = fs::read_to_string(&annotated).unwrap();
+    let lines: Vec<&str> = content.lines().collect();
+    let from = lines.iter().position(|l| *l == "FROM ubuntu:latest").unwrap();
+    assert!(lines[from - 1].starts_with("# docker-review: [DF"));
+    assert!(lines[..from].iter().any(|l| l.starts_with("# docker-review: [DF001]")));
+    assert_eq!(lines.last(), Some(&"USER app"));
+}
@@ -0,0 +1,44 @@
+//! Runs the fuzzing seed corpus through the parsers and rules so inputs that
+//! once caused panics stay covered without cargo-fuzz installed.
+
+use docker_review::analyzer::Analyzer;
+use docker_review::parser::{ComposeParser, DockerfileParser};
+use std::fs;
+use std::path::Path;
+
+fn corpus(target: &str) -> Vec<(String, String)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/corpus").join(target);
+    let mut entries: Vec<(String, String)> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            let bytes = fs::read(&path).unwrap();
+            (name, String::from_utf8_lossy(&bytes).into_owned())
+        })
+        .collect();
+    entries.sort();
+    assert!(!entries.is_empty());
+    entries
+}
+
+#[test]
+fn test_dockerfile_corpus_does_not_panic() {
+    let analyzer = Analyzer::new();
+    for (name, content) in corpus("dockerfile_parser") {
+        let parser = DockerfileParser::parse_content(&content);
+        for instruction in &parser.instructions {
+            instruction.line_at(instruction.arguments.len());
+        }
+        assert!(analyzer.analyze_dockerfile_str(&content).is_ok(), "{}", name);
+    }
+}
+
+#[test]
+fn test_compose_corpus_does_not_panic() {
+    let analyzer = Analyzer::new();
+    for (_, content) in corpus("compose_parser") {
+        let _ = ComposeParser::parse_content(&content);
+        let _ = analyzer.analyze_compose_str(&content);
+    }
+}
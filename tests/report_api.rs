@@ -0,0 +1,69 @@
+//! Reports are persisted by library users (baselines, diffs), so their JSON
+//! form must round-trip and stay readable as fields are added.
+
+use docker_review::analyzer::{Analyzer, Report};
+use docker_review::rules::{ImpactEstimate, Issue, Severity};
+use docker_review::scoring::calculate_scores;
+use pretty_assertions::assert_eq;
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+#[test]
+fn test_analyzed_reports_round_trip() {
+    for name in ["bad_dockerfile", "bad_compose.yml"] {
+        let report = Analyzer::new().analyze(fixture(name)).unwrap();
+        assert!(!report.issues.is_empty());
+
+        let json = serde_json::to_string(&report).unwrap();
+        let reloaded: Report = serde_json::from_str(&json).unwrap();
+        assert_eq!(reloaded, report);
+    }
+}
+
+#[test]
+fn test_constructed_report_round_trips() {
+    let mut impact = ImpactEstimate::default();
+    impact.security_improvement = Some("High".to_string());
+
+    let issues = vec![
+        Issue::new("DF002", "Running as root", Severity::Warning, "No USER instruction")
+            .with_line_number(3)
+            .with_fix_suggestion("Add USER appuser")
+            .with_impact(impact),
+    ];
+    let report = Report::new("Dockerfile", issues.clone(), calculate_scores(&issues));
+
+    let json = serde_json::to_string_pretty(&report).unwrap();
+    assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), report);
+}
+
+#[test]
+fn test_older_report_without_optional_fields_loads() {
+    // Written before `contributions`, `impact` and `fix_suggestion` existed
+    let json = r#"{
+        "file_path": "Dockerfile",
+        "issues": [
+            {
+                "rule_id": "DF001",
+                "rule_name": "Latest tag",
+                "severity": "warning",
+                "line_number": 1,
+                "message": "Base image uses :latest"
+            }
+        ],
+        "scores": {
+            "performance": { "current": 10, "potential": 10 },
+            "security": { "current": 8, "potential": 10 },
+            "maintainability": { "current": 10, "potential": 10 },
+            "overall": { "current": 9, "potential": 10 }
+        }
+    }"#;
+
+    let report: Report = serde_json::from_str(json).unwrap();
+    assert_eq!(report.issues[0].fix_suggestion, None);
+    assert_eq!(report.issues[0].impact, None);
+    assert!(report.scores.contributions.is_empty());
+    assert_eq!(report.scores.security.current, 8);
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "serve")]
+
+use docker_review::analyzer::Analyzer;
+use docker_review::server::Server;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+
+fn start_server() -> SocketAddr {
+    let server = Server::bind("127.0.0.1:0", Analyzer::new()).unwrap();
+    let addr = server.local_addr().unwrap();
+    std::thread::spawn(move || server.run());
+    addr
+}
+
+fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+        method,
+        path,
+        body.len(),
+        body
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let status = response.split_whitespace().nth(1).unwrap().parse().unwrap();
+    let body = response.split_once("\r\n\r\n").unwrap().1.to_string();
+    (status, body)
+}
+
+#[test]
+fn test_health_endpoint() {
+    let addr = start_server();
+    let (status, body) = request(addr, "GET", "/health", "");
+    assert_eq!(status, 200);
+    assert_eq!(body, r#"{"status":"ok"}"#);
+}
+
+#[test]
+fn test_post_dockerfile_returns_report() {
+    let addr = start_server();
+    let (status, body) = request(addr, "POST", "/analyze/dockerfile", "FROM ubuntu:latest\nRUN apt-get update\n");
+    assert_eq!(status, 200);
+
+    let report: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let rule_ids: Vec<&str> = report["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|i| i["rule_id"].as_str().unwrap())
+        .collect();
+    assert!(rule_ids.contains(&"DF001"));
+    assert!(report["scores"]["overall"]["current"].is_number());
+}
+
+#[test]
+fn test_post_compose_returns_report() {
+    let addr = start_server();
+    let (status, body) = request(addr, "POST", "/analyze/compose", "services:\n  web:\n    image: nginx:latest\n    privileged: true\n");
+    assert_eq!(status, 200);
+    assert!(body.contains("\"DC002\""));
+}